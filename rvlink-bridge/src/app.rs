@@ -1,6 +1,7 @@
 use crate::config;
 use crate::{bluetooth::BluetoothManager, mqtt::MqttManager, rvlink::RVLink, *};
 use std::sync::Arc;
+use tokio::time::{sleep, Duration};
 
 #[derive(Debug, Deref)]
 pub struct App(Arc<AppInner>);
@@ -17,7 +18,10 @@ impl App {
         let bluetooth = BluetoothManager::new(config::DEVICE.clone()).await?;
         let rvlink = RVLink::new(bluetooth.clone()).await?;
         let mqtt = MqttManager::new(rvlink.clone()).await?;
-        rvlink.set_mqtt_manager(mqtt.clone()).await;
+        // A PID dump never connects to MQTT, leave RVLink without it so nothing gets published
+        if config::DUMP_PIDS.is_none() {
+            rvlink.set_mqtt_manager(mqtt.clone()).await;
+        }
         Ok(Self(Arc::new(AppInner {
             bluetooth,
             rvlink,
@@ -26,10 +30,36 @@ impl App {
     }
 
     pub async fn run(&self) -> Result<()> {
+        if let Some(device) = config::DUMP_PIDS.as_ref() {
+            return self.dump_pids(device).await;
+        }
         self.bluetooth.start().await?;
         self.rvlink.start().await?;
         self.mqtt.start().await?;
         tokio::signal::ctrl_c().await?;
         Ok(())
     }
+
+    /// Wait for the device to show up in the device tables, then dump its PIDs to stdout
+    async fn dump_pids(&self, device: &str) -> Result<()> {
+        self.bluetooth.start().await?;
+        self.rvlink.start().await?;
+        let mut attempts = 0;
+        let entry = loop {
+            if let Some(entry) = self.rvlink.find_device(device).await {
+                break entry;
+            }
+            attempts += 1;
+            if attempts > 60 {
+                return Err(AppError::Generic(format!(
+                    "Device {} was not found",
+                    device
+                )));
+            }
+            sleep(Duration::from_secs(1)).await;
+        };
+        let pids = self.rvlink.dump_device_pids(&entry).await?;
+        println!("{}", serde_json::to_string_pretty(&pids)?);
+        Ok(())
+    }
 }
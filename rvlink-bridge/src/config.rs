@@ -1,4 +1,5 @@
 pub use clap::Parser;
+use std::str::FromStr;
 
 lazy_static! {
     pub static ref ARGS: Args = Args::parse();
@@ -11,6 +12,72 @@ lazy_static! {
     pub static ref PASSWORD: &'static Option<String> = &ARGS.password;
     pub static ref BASE_TOPIC: &'static String = &ARGS.base_topic;
     pub static ref DISCOVERY_TOPIC: &'static String = &ARGS.discovery_topic;
+    pub static ref HOUR_METER_SERVICE_INTERVALS: &'static Vec<HourMeterServiceInterval> =
+        &ARGS.hour_meter_service_interval;
+    pub static ref DOOR_LOCK_CODE: &'static Option<String> = &ARGS.door_lock_code;
+    pub static ref AWNING_AUTO_RETRACT: bool = ARGS.awning_auto_retract;
+    pub static ref CLOCK_SYNC_THRESHOLD: Option<u32> = ARGS.clock_sync_threshold;
+    pub static ref DUMP_PIDS: &'static Option<String> = &ARGS.dump_pids;
+}
+
+/// Maintenance schedule for a single hour meter
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ServiceInterval {
+    pub interval_hours: u32,
+    pub last_service_hours: u32,
+}
+
+impl ServiceInterval {
+    pub fn next_service_hours(&self) -> u32 {
+        self.last_service_hours + self.interval_hours
+    }
+
+    pub fn is_due(&self, operating_hours: f32) -> bool {
+        operating_hours >= self.next_service_hours() as f32
+    }
+}
+
+/// Service interval for an hour meter, parsed from `<device>=<interval hours>[@<last service hours>]`
+/// where the device is either the display name or the `<device table>:<device id>` address
+#[derive(Debug, Clone)]
+pub struct HourMeterServiceInterval {
+    pub device: String,
+    pub interval: ServiceInterval,
+}
+
+impl HourMeterServiceInterval {
+    pub fn matches(&self, display_name: &str, address: Option<(u8, u8)>) -> bool {
+        self.device.eq_ignore_ascii_case(display_name)
+            || address
+                .map(|(table, id)| self.device == format!("{}:{}", table, id))
+                .unwrap_or(false)
+    }
+}
+
+impl FromStr for HourMeterServiceInterval {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (device, schedule) = s
+            .rsplit_once('=')
+            .ok_or_else(|| format!("Expected <device>=<hours>, got '{}'", s))?;
+        let (interval_hours, last_service_hours) = match schedule.split_once('@') {
+            Some((interval, last)) => (interval, last),
+            None => (schedule, "0"),
+        };
+        let parse_hours = |val: &str| {
+            val.trim()
+                .parse::<u32>()
+                .map_err(|e| format!("Invalid hours '{}': {}", val, e))
+        };
+        Ok(Self {
+            device: device.trim().to_string(),
+            interval: ServiceInterval {
+                interval_hours: parse_hours(interval_hours)?,
+                last_service_hours: parse_hours(last_service_hours)?,
+            },
+        })
+    }
 }
 
 /// Bridge for RVLink/Onecontrol devices to MQTT
@@ -66,4 +133,29 @@ pub struct Args {
         env = "RVLINK_BRIDGE_MQTT_DISCOVERY_TOPIC"
     )]
     pub discovery_topic: String,
+
+    /// Service interval for an hour meter as <device>=<interval hours>[@<last service hours>], may be repeated
+    #[clap(
+        long,
+        multiple_occurrences = true,
+        use_value_delimiter = true,
+        env = "RVLINK_BRIDGE_HOUR_METER_SERVICE_INTERVALS"
+    )]
+    pub hour_meter_service_interval: Vec<HourMeterServiceInterval>,
+
+    /// Code Home Assistant must provide before door locks are locked or unlocked
+    #[clap(long, env = "RVLINK_BRIDGE_DOOR_LOCK_CODE")]
+    pub door_lock_code: Option<String>,
+
+    /// Retract all awnings when an awning sensor reports a wind or vibration alarm
+    #[clap(long, env = "RVLINK_BRIDGE_AWNING_AUTO_RETRACT")]
+    pub awning_auto_retract: bool,
+
+    /// Automatically set the gateway clock when it drifts from the host clock by more than this many seconds
+    #[clap(long, env = "RVLINK_BRIDGE_CLOCK_SYNC_THRESHOLD")]
+    pub clock_sync_threshold: Option<u32>,
+
+    /// Print every readable PID of a device (unique ID, name or <device table>:<device id>) and exit
+    #[clap(long)]
+    pub dump_pids: Option<String>,
 }
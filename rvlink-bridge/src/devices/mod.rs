@@ -1,9 +1,13 @@
+use crate::config::{self, HourMeterServiceInterval, ServiceInterval};
 use atomic::Atomic;
 use hmac::{Hmac, Mac};
 use lockfree::map::Map;
 use rvlink_common::devices::DeviceEntityType;
 use rvlink_common::hass::*;
-use rvlink_proto::{Device, DeviceMetadata, DeviceMetadataFull, DeviceType, FunctionName};
+use rvlink_proto::{
+    Device, DeviceMetadata, DeviceMetadataFull, DeviceType, FunctionName, HvacFanMode,
+    HvacHeatMode, LockState, RgbLightMode, TEMPERATURE_UNIT,
+};
 use sha2::Sha256;
 use std::sync::atomic::*;
 use std::sync::Arc;
@@ -39,6 +43,10 @@ pub struct DeviceEntityInner {
     pub attributes: Map<String, String>,
     pub has_device_metadata: AtomicBool,
     pub has_device_info: AtomicBool,
+    /// Set from DeviceOnlineStatus, devices are assumed online until reported otherwise
+    pub offline: AtomicBool,
+    /// Set once the device reports through an accessory gateway, commands must then be routed through it
+    pub via_accessory_gateway: AtomicBool,
 }
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -54,11 +62,39 @@ pub enum DeviceEntitySource {
     },
 }
 
+/// An extra Home Assistant entity backed by one key of the parent device's JSON state or attributes
+#[derive(Debug, Clone)]
+pub struct DeviceSubEntity {
+    pub key: &'static str,
+    pub name: &'static str,
+    pub typ: HassDiscoveryType,
+    pub unit: Option<&'static str>,
+    /// Read the value from the attributes topic rather than the state, for values that aren't part of the state
+    pub from_attributes: bool,
+}
+
 #[derive(Debug, Default, Display, Clone, Copy)]
 pub enum SystemEntityType {
     #[default]
     #[display(fmt = "Battery")]
     Battery,
+    #[display(fmt = "Chassis")]
+    Chassis,
+    #[display(fmt = "Clock")]
+    Clock,
+    #[display(fmt = "Outdoor Temperature")]
+    OutdoorTemperature,
+}
+
+impl SystemEntityType {
+    pub fn device_entity_type(&self) -> DeviceEntityType {
+        match self {
+            SystemEntityType::Battery => DeviceEntityType::Battery,
+            SystemEntityType::Chassis => DeviceEntityType::Chassis,
+            SystemEntityType::Clock => DeviceEntityType::Clock,
+            SystemEntityType::OutdoorTemperature => DeviceEntityType::Temperature,
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -73,18 +109,12 @@ impl DeviceEntity {
     }
 
     pub async fn to_discovery(&self, base_topic: String) -> HassDiscoveryInfo {
-        HassDiscoveryInfo {
-            device: Some(HassDeviceInfo {
-                name: crate_name!().to_string().into(),
-                model: format!("{} {}", crate_name!(), crate_version!()).into(),
-                manufacturer: crate_authors!().to_string().into(),
-                sw_version: crate_version!().to_string().into(),
-                identifiers: MACHINEID.to_string().into(),
-                ..Default::default()
-            }),
+        let mut discovery = HassDiscoveryInfo {
+            device: Some(Self::hass_device_info()),
             state_topic: self.stat_topic("~").into(),
             json_attributes_topic: self.attr_topic("~").into(),
-            availability_topic: self.avty_topic("~").into(),
+            availability: self.availability("~").into(),
+            availability_mode: "all".to_string().into(),
             command_topic: self.command_topic("~"),
             base_topic: base_topic.into(),
             payload_on: "on".to_string().into(),
@@ -92,13 +122,219 @@ impl DeviceEntity {
             payload_open: "open".to_string().into(),
             payload_close: "close".to_string().into(),
             payload_stop: "stop".to_string().into(),
-            payload_available: "online".to_string().into(),
-            payload_not_available: "offline".to_string().into(),
             name: self.display_name().into(),
             icon: self.hass_icon().to_string().into(),
             unique_id: self.uniq_id().into(),
             device_class: self.hass_device_class(),
             ..Default::default()
+        };
+        if let Some(sensor) = self
+            .device_entity_type()
+            .sensor_definition(TEMPERATURE_UNIT)
+        {
+            discovery.unit_of_measurement = sensor.unit.map(|u| u.to_string());
+            discovery.state_class = sensor.state_class.map(|c| c.to_string());
+        }
+        match self.device_entity_type() {
+            DeviceEntityType::DimmableLight => {
+                discovery.state_value_template = "{{ value_json.state }}".to_string().into();
+                discovery.brightness_state_topic = self.stat_topic("~").into();
+                discovery.brightness_value_template =
+                    "{{ value_json.brightness }}".to_string().into();
+                discovery.brightness_command_topic = self.command_topic("~");
+                discovery.brightness_command_template = "brightness:{{ value }}".to_string().into();
+                discovery.on_command_type = "brightness".to_string().into();
+            }
+            DeviceEntityType::RgbLight => {
+                discovery.state_value_template = "{{ value_json.state }}".to_string().into();
+                discovery.rgb_state_topic = self.stat_topic("~").into();
+                discovery.rgb_value_template =
+                    "{{ value_json.rgb | join(',') }}".to_string().into();
+                discovery.rgb_command_topic = self.command_topic("~");
+                discovery.rgb_command_template =
+                    "rgb:{{ red }},{{ green }},{{ blue }}".to_string().into();
+                discovery.effect_state_topic = self.stat_topic("~").into();
+                discovery.effect_value_template = "{{ value_json.effect }}".to_string().into();
+                discovery.effect_command_topic = self.command_topic("~");
+                discovery.effect_command_template = "effect:{{ value }}".to_string().into();
+                // "none" matches the effect published for a solid color
+                discovery.effect_list = Some(
+                    std::iter::once("none".to_string())
+                        .chain(
+                            [
+                                RgbLightMode::Blink,
+                                RgbLightMode::Jump3,
+                                RgbLightMode::Jump7,
+                                RgbLightMode::Fade3,
+                                RgbLightMode::Fade7,
+                                RgbLightMode::Rainbow,
+                            ]
+                            .iter()
+                            .map(|m| m.to_string()),
+                        )
+                        .collect(),
+                );
+            }
+            DeviceEntityType::Thermostat => {
+                discovery.mode_state_topic = self.stat_topic("~").into();
+                discovery.mode_state_template = "{{ value_json.mode }}".to_string().into();
+                discovery.mode_command_topic = self.command_topic("~");
+                discovery.mode_command_template = "mode:{{ value }}".to_string().into();
+                discovery.modes = Some(
+                    [
+                        HvacHeatMode::Off,
+                        HvacHeatMode::Heating,
+                        HvacHeatMode::Cooling,
+                        HvacHeatMode::Both,
+                        HvacHeatMode::RunSchedule,
+                    ]
+                    .iter()
+                    .map(|m| m.to_string())
+                    .collect(),
+                );
+                discovery.fan_mode_state_topic = self.stat_topic("~").into();
+                discovery.fan_mode_state_template = "{{ value_json.fan_mode }}".to_string().into();
+                discovery.fan_mode_command_topic = self.command_topic("~");
+                discovery.fan_mode_command_template = "fan_mode:{{ value }}".to_string().into();
+                discovery.fan_modes = Some(
+                    [HvacFanMode::Auto, HvacFanMode::High, HvacFanMode::Low]
+                        .iter()
+                        .map(|m| m.to_string())
+                        .collect(),
+                );
+                discovery.temperature_state_topic = self.stat_topic("~").into();
+                discovery.temperature_state_template =
+                    "{{ value_json.temperature }}".to_string().into();
+                discovery.temperature_command_topic = self.command_topic("~");
+                discovery.temperature_command_template =
+                    "temperature:{{ value }}".to_string().into();
+                discovery.temperature_low_state_topic = self.stat_topic("~").into();
+                discovery.temperature_low_state_template =
+                    "{{ value_json.heat_setpoint }}".to_string().into();
+                discovery.temperature_low_command_topic = self.command_topic("~");
+                discovery.temperature_low_command_template =
+                    "heat_setpoint:{{ value }}".to_string().into();
+                discovery.temperature_high_state_topic = self.stat_topic("~").into();
+                discovery.temperature_high_state_template =
+                    "{{ value_json.cool_setpoint }}".to_string().into();
+                discovery.temperature_high_command_topic = self.command_topic("~");
+                discovery.temperature_high_command_template =
+                    "cool_setpoint:{{ value }}".to_string().into();
+                discovery.current_temperature_topic = self.stat_topic("~").into();
+                discovery.current_temperature_template =
+                    "{{ value_json.current_temperature }}".to_string().into();
+                discovery.action_topic = self.stat_topic("~").into();
+                discovery.action_template = "{{ value_json.action }}".to_string().into();
+                discovery.temperature_unit = "F".to_string().into();
+                discovery.min_temp = "50".to_string().into();
+                discovery.max_temp = "95".to_string().into();
+            }
+            DeviceEntityType::Awning | DeviceEntityType::Slide => {
+                discovery.state_opening = "opening".to_string().into();
+                discovery.state_closing = "closing".to_string().into();
+                discovery.state_stopped = "stopped".to_string().into();
+            }
+            DeviceEntityType::DoorLock => {
+                Self::lock_discovery(&mut discovery, config::DOOR_LOCK_CODE.as_deref())
+            }
+            DeviceEntityType::Generator => {
+                discovery.state_value_template = "{{ value_json.state }}".to_string().into();
+            }
+            DeviceEntityType::HourMeter => {
+                discovery.value_template = "{{ value_json.hours }}".to_string().into();
+            }
+            DeviceEntityType::AwningSensor => {
+                discovery.value_template = "{{ value_json.wind_speed }}".to_string().into();
+            }
+            DeviceEntityType::Leveler => {
+                discovery.value_template = "{{ value_json.mode }}".to_string().into();
+            }
+            DeviceEntityType::Brakes => {
+                discovery.value_template = "{{ value_json.output_level }}".to_string().into();
+            }
+            DeviceEntityType::CloudGateway => {
+                discovery.value_template = "{{ value_json.connected }}".to_string().into();
+            }
+            DeviceEntityType::BatteryMonitor | DeviceEntityType::PowerSystem => {
+                discovery.value_template = "{{ value_json.state_of_charge }}".to_string().into();
+            }
+            DeviceEntityType::TemperatureSensor => {
+                discovery.value_template = "{{ value_json.temperature }}".to_string().into();
+            }
+            DeviceEntityType::Clock => {
+                discovery.value_template = "{{ value_json.timestamp }}".to_string().into();
+            }
+            DeviceEntityType::Chassis => {
+                discovery.name = format!("{} Lockout Level", self.display_name()).into();
+                discovery.value_template = "{{ value_json.lockout_level }}".to_string().into();
+            }
+            _ => {}
+        }
+        discovery
+    }
+
+    /// Discovery info for an additional entity reading a single value out of this device's state
+    pub async fn to_sub_discovery(
+        &self,
+        base_topic: String,
+        sub_entity: &DeviceSubEntity,
+    ) -> HassDiscoveryInfo {
+        let mut discovery = HassDiscoveryInfo {
+            device: Some(Self::hass_device_info()),
+            state_topic: self.stat_topic("~").into(),
+            value_template: format!("{{{{ value_json.{} }}}}", sub_entity.key).into(),
+            json_attributes_topic: self.attr_topic("~").into(),
+            availability: self.availability("~").into(),
+            availability_mode: "all".to_string().into(),
+            base_topic: base_topic.into(),
+            payload_on: "on".to_string().into(),
+            payload_off: "off".to_string().into(),
+            name: format!("{} {}", self.display_name(), sub_entity.name).into(),
+            icon: sub_entity.typ.icon().to_string().into(),
+            unique_id: self.sub_uniq_id(sub_entity).into(),
+            device_class: sub_entity.typ.device_class(),
+            unit_of_measurement: sub_entity.unit.map(|u| u.to_string()),
+            state_class: match (&sub_entity.typ, sub_entity.unit) {
+                (HassDiscoveryType::Sensor(_), Some(_)) => {
+                    Some(HassStateClass::Measurement.to_string())
+                }
+                _ => None,
+            },
+            ..Default::default()
+        };
+        if sub_entity.from_attributes {
+            discovery.state_topic = self.attr_topic("~").into();
+        }
+        if let HassDiscoveryType::Button = sub_entity.typ {
+            discovery.state_topic = None;
+            discovery.value_template = None;
+            discovery.command_topic = self.device_command_topic("~").into();
+            discovery.payload_press = sub_entity.key.to_string().into();
+        }
+        discovery
+    }
+
+    /// Lock commands carry the code as `<command>:<code>` when one is expected
+    fn lock_discovery(discovery: &mut HassDiscoveryInfo, code: Option<&str>) {
+        discovery.payload_lock = "lock".to_string().into();
+        discovery.payload_unlock = "unlock".to_string().into();
+        discovery.state_locked = LockState::Locked.to_string().into();
+        discovery.state_unlocked = LockState::Unlocked.to_string().into();
+        discovery.state_jammed = LockState::Jammed.to_string().into();
+        if code.is_some() {
+            discovery.code_format = "^.+$".to_string().into();
+            discovery.command_template = "{{ value }}:{{ code }}".to_string().into();
+        }
+    }
+
+    fn hass_device_info() -> HassDeviceInfo {
+        HassDeviceInfo {
+            name: crate_name!().to_string().into(),
+            model: format!("{} {}", crate_name!(), crate_version!()).into(),
+            manufacturer: crate_authors!().to_string().into(),
+            sw_version: crate_version!().to_string().into(),
+            identifiers: MACHINEID.to_string().into(),
+            ..Default::default()
         }
     }
 
@@ -208,7 +444,15 @@ impl DeviceEntityInner {
     }
 
     pub fn hass_icon(&self) -> &'static str {
-        self.hass_device_type().icon()
+        match self.device_entity_type() {
+            // Tank levels have no device class to pick an icon from
+            DeviceEntityType::FreshTank
+            | DeviceEntityType::GreyTank
+            | DeviceEntityType::BlackTank
+            | DeviceEntityType::FuelTank
+            | DeviceEntityType::LPTank => HassIcons::GAUGE,
+            _ => self.hass_device_type().icon(),
+        }
     }
 
     pub fn display_name(&self) -> String {
@@ -233,33 +477,458 @@ impl DeviceEntityInner {
         }
     }
 
+    pub fn device_entity_type(&self) -> DeviceEntityType {
+        if let DeviceEntitySource::System { typ } = self.source.load(Ordering::Relaxed) {
+            return typ.device_entity_type();
+        }
+        match self.device_type.load(Ordering::Relaxed) {
+            DeviceType::DimmableLight => DeviceEntityType::DimmableLight,
+            DeviceType::RgbLight => DeviceEntityType::RgbLight,
+            DeviceType::HvacControl => DeviceEntityType::Thermostat,
+            DeviceType::GeneratorGenie => DeviceEntityType::Generator,
+            DeviceType::HourMeter => DeviceEntityType::HourMeter,
+            DeviceType::TemperatureSensor => DeviceEntityType::TemperatureSensor,
+            DeviceType::BatteryMonitor => DeviceEntityType::BatteryMonitor,
+            DeviceType::DoorLock => DeviceEntityType::DoorLock,
+            DeviceType::LevelerType1 | DeviceType::LevelerType3 | DeviceType::LevelerType4 => {
+                DeviceEntityType::Leveler
+            }
+            DeviceType::AwningSensor => DeviceEntityType::AwningSensor,
+            DeviceType::OnecontrolCloudGateway => DeviceEntityType::CloudGateway,
+            DeviceType::JaycoAusTbbGw => DeviceEntityType::PowerSystem,
+            DeviceType::BrakeController | DeviceType::EchoBrakeControl => DeviceEntityType::Brakes,
+            _ => self
+                .function_name
+                .load(Ordering::Relaxed)
+                .device_entity_type(),
+        }
+    }
+
     pub fn hass_device_type(&self) -> HassDiscoveryType {
-        match self
-            .function_name
-            .load(Ordering::Relaxed)
-            .device_entity_type()
-        {
-            DeviceEntityType::LightSwitch => HassDiscoveryType::Light,
+        let entity_type = self.device_entity_type();
+        if let Some(sensor) = entity_type.sensor_definition(TEMPERATURE_UNIT) {
+            return HassDiscoveryType::Sensor(sensor.class);
+        }
+        match entity_type {
+            DeviceEntityType::LightSwitch
+            | DeviceEntityType::DimmableLight
+            | DeviceEntityType::RgbLight => HassDiscoveryType::Light,
             DeviceEntityType::WaterHeater
             | DeviceEntityType::WaterPump
-            | DeviceEntityType::DoorLock
+            | DeviceEntityType::Generator
             | DeviceEntityType::Switch => HassDiscoveryType::Switch,
+            DeviceEntityType::DoorLock => HassDiscoveryType::Lock,
             DeviceEntityType::Awning => HassDiscoveryType::Cover(HassDiscoveryCoverClass::Awning),
             DeviceEntityType::Slide => HassDiscoveryType::Cover(HassDiscoveryCoverClass::Door),
-            DeviceEntityType::Battery
-            | DeviceEntityType::FreshTank
-            | DeviceEntityType::GreyTank
-            | DeviceEntityType::BlackTank
-            | DeviceEntityType::Sensor
-            | DeviceEntityType::FuelTank
-            | DeviceEntityType::Brakes
-            | DeviceEntityType::SignalLights
-            | DeviceEntityType::None
-            | DeviceEntityType::LPTank => HassDiscoveryType::Sensor(HassDiscoverySensorClass::None),
-            DeviceEntityType::Thermostat => HassDiscoveryType::Thermostat,
+            DeviceEntityType::Thermostat => HassDiscoveryType::Climate,
+            DeviceEntityType::CloudGateway => {
+                HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Connectivity)
+            }
+            _ => HassDiscoveryType::Sensor(HassDiscoverySensorClass::None),
         }
     }
 
+    pub fn sub_entities(&self) -> Vec<DeviceSubEntity> {
+        let mut sub_entities = match self.device_entity_type() {
+            DeviceEntityType::Generator => vec![
+                DeviceSubEntity {
+                    key: "status",
+                    name: "Status",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::None),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "battery_voltage",
+                    name: "Battery Voltage",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Voltage),
+                    unit: Some("V"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "temperature",
+                    name: "Temperature",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Temperature),
+                    unit: Some(TEMPERATURE_UNIT),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "run_hours",
+                    name: "Run Hours",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::None),
+                    unit: Some("h"),
+                    from_attributes: false,
+                },
+            ],
+            DeviceEntityType::HourMeter => {
+                let mut res = vec![
+                    DeviceSubEntity {
+                        key: "running",
+                        name: "Running",
+                        typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Power),
+                        unit: None,
+                        from_attributes: false,
+                    },
+                    DeviceSubEntity {
+                        key: "maintenance_due",
+                        name: "Maintenance Due",
+                        typ: HassDiscoveryType::BinarySensor(
+                            HassDiscoveryBinarySensorClass::Problem,
+                        ),
+                        unit: None,
+                        from_attributes: false,
+                    },
+                    DeviceSubEntity {
+                        key: "maintenance_past_due",
+                        name: "Maintenance Past Due",
+                        typ: HassDiscoveryType::BinarySensor(
+                            HassDiscoveryBinarySensorClass::Problem,
+                        ),
+                        unit: None,
+                        from_attributes: false,
+                    },
+                    DeviceSubEntity {
+                        key: "error",
+                        name: "Error",
+                        typ: HassDiscoveryType::BinarySensor(
+                            HassDiscoveryBinarySensorClass::Problem,
+                        ),
+                        unit: None,
+                        from_attributes: false,
+                    },
+                ];
+                if self
+                    .service_interval(&config::HOUR_METER_SERVICE_INTERVALS)
+                    .is_some()
+                {
+                    res.push(DeviceSubEntity {
+                        key: "service_due",
+                        name: "Service Due",
+                        typ: HassDiscoveryType::BinarySensor(
+                            HassDiscoveryBinarySensorClass::Problem,
+                        ),
+                        unit: None,
+                        from_attributes: false,
+                    });
+                    res.push(DeviceSubEntity {
+                        key: "hours_until_service",
+                        name: "Hours Until Service",
+                        typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Duration),
+                        unit: Some("h"),
+                        from_attributes: false,
+                    });
+                }
+                res
+            }
+            DeviceEntityType::Chassis => vec![
+                DeviceSubEntity {
+                    key: "park_brake",
+                    name: "Park Brake",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::None),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "ignition",
+                    name: "Ignition",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Power),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "towable_battery_voltage",
+                    name: "Towable Battery Voltage",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Voltage),
+                    unit: Some("V"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "towable_brake_voltage",
+                    name: "Towable Brake Voltage",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Voltage),
+                    unit: Some("V"),
+                    from_attributes: false,
+                },
+            ],
+            DeviceEntityType::AwningSensor => vec![
+                DeviceSubEntity {
+                    key: "vibration",
+                    name: "Vibration",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::None),
+                    unit: Some("%"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "wind_alarm",
+                    name: "Wind Alarm",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Safety),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "vibration_alarm",
+                    name: "Vibration Alarm",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Vibration),
+                    unit: None,
+                    from_attributes: false,
+                },
+            ],
+            DeviceEntityType::Leveler => vec![
+                DeviceSubEntity {
+                    key: "console_text",
+                    name: "Console",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::None),
+                    unit: None,
+                    from_attributes: true,
+                },
+                DeviceSubEntity {
+                    key: "level",
+                    name: "Level",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::None),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "jacks_extended",
+                    name: "Jacks Extended",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Opening),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "excess_angle",
+                    name: "Excess Angle",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Problem),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "fault",
+                    name: "Fault",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Problem),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "auto_level",
+                    name: "Auto Level",
+                    typ: HassDiscoveryType::Button,
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "retract_all",
+                    name: "Retract All",
+                    typ: HassDiscoveryType::Button,
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "stop",
+                    name: "Stop",
+                    typ: HassDiscoveryType::Button,
+                    unit: None,
+                    from_attributes: false,
+                },
+            ],
+            DeviceEntityType::Brakes => vec![
+                DeviceSubEntity {
+                    key: "gain",
+                    name: "Gain",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::None),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "trailer_connected",
+                    name: "Trailer Connected",
+                    typ: HassDiscoveryType::BinarySensor(
+                        HassDiscoveryBinarySensorClass::Connectivity,
+                    ),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "braking",
+                    name: "Braking",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::None),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "fault",
+                    name: "Fault",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Problem),
+                    unit: None,
+                    from_attributes: false,
+                },
+            ],
+            DeviceEntityType::BatteryMonitor => vec![
+                DeviceSubEntity {
+                    key: "voltage",
+                    name: "Voltage",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Voltage),
+                    unit: Some("V"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "current",
+                    name: "Current",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Current),
+                    unit: Some("A"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "power",
+                    name: "Power",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Power),
+                    unit: Some("W"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "time_remaining",
+                    name: "Time Remaining",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Duration),
+                    unit: Some("min"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "charging",
+                    name: "Charging",
+                    typ: HassDiscoveryType::BinarySensor(
+                        HassDiscoveryBinarySensorClass::BatteryCharging,
+                    ),
+                    unit: None,
+                    from_attributes: false,
+                },
+            ],
+            DeviceEntityType::CloudGateway => vec![DeviceSubEntity {
+                key: "registered",
+                name: "Registered",
+                typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::None),
+                unit: None,
+                from_attributes: false,
+            }],
+            DeviceEntityType::PowerSystem => vec![
+                DeviceSubEntity {
+                    key: "battery_voltage",
+                    name: "Battery Voltage",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Voltage),
+                    unit: Some("V"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "battery_current",
+                    name: "Battery Current",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Current),
+                    unit: Some("A"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "solar_current",
+                    name: "Solar Current",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Current),
+                    unit: Some("A"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "solar_power",
+                    name: "Solar Power",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Power),
+                    unit: Some("W"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "mains_voltage",
+                    name: "Mains Voltage",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Voltage),
+                    unit: Some("V"),
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "mains_connected",
+                    name: "Mains",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Plug),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "solar_charging",
+                    name: "Solar Charging",
+                    typ: HassDiscoveryType::BinarySensor(
+                        HassDiscoveryBinarySensorClass::BatteryCharging,
+                    ),
+                    unit: None,
+                    from_attributes: false,
+                },
+            ],
+            DeviceEntityType::TemperatureSensor => vec![
+                DeviceSubEntity {
+                    key: "cold",
+                    name: "Low Temperature Alarm",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Cold),
+                    unit: None,
+                    from_attributes: false,
+                },
+                DeviceSubEntity {
+                    key: "heat",
+                    name: "High Temperature Alarm",
+                    typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Heat),
+                    unit: None,
+                    from_attributes: false,
+                },
+            ],
+            DeviceEntityType::Clock => vec![
+                DeviceSubEntity {
+                    key: "drift",
+                    name: "Drift",
+                    typ: HassDiscoveryType::Sensor(HassDiscoverySensorClass::Duration),
+                    unit: Some("s"),
+                    from_attributes: false,
+                },
+                // Presses arrive as the clock's sync command
+                DeviceSubEntity {
+                    key: "sync",
+                    name: "Sync",
+                    typ: HassDiscoveryType::Button,
+                    unit: None,
+                    from_attributes: false,
+                },
+            ],
+            _ => vec![],
+        };
+        // Any CAN device can report diagnostic trouble codes
+        if let DeviceEntitySource::CAN { .. } = self.source.load(Ordering::Relaxed) {
+            sub_entities.push(DeviceSubEntity {
+                key: "dtc_problem",
+                name: "Problem",
+                typ: HassDiscoveryType::BinarySensor(HassDiscoveryBinarySensorClass::Problem),
+                unit: None,
+                from_attributes: true,
+            });
+        }
+        sub_entities
+    }
+
+    /// Looks up the maintenance schedule for this device among the configured ones, if any
+    pub fn service_interval(
+        &self,
+        schedules: &[HourMeterServiceInterval],
+    ) -> Option<ServiceInterval> {
+        let display_name = self.display_name();
+        let address = match self.source.load(Ordering::Relaxed) {
+            DeviceEntitySource::CAN {
+                device_table,
+                device_id,
+            } => Some((device_table, device_id)),
+            _ => None,
+        };
+        schedules
+            .iter()
+            .find(|i| i.matches(&display_name, address))
+            .map(|i| i.interval)
+    }
+
     pub fn uniq_id(&self) -> String {
         match self.source.load(Ordering::Relaxed) {
             DeviceEntitySource::None => "rvlink-bridge".into(),
@@ -272,17 +941,45 @@ impl DeviceEntityInner {
                 *MACHINEID,
                 self.function_name
                     .load(Ordering::Relaxed)
-                    .device_entity_type(),
+                    .uniq_id_entity_type(),
                 device_table,
                 device_id
             ),
         }
     }
 
+    pub fn sub_uniq_id(&self, sub_entity: &DeviceSubEntity) -> String {
+        format!("{}-{}", self.uniq_id(), sub_entity.key)
+    }
+
     pub fn avty_topic(&self, base_topic: &str) -> String {
         format!("{}avty", base_topic)
     }
 
+    pub fn device_avty_topic(&self, base_topic: &str) -> Option<String> {
+        match self.source.load(Ordering::Relaxed) {
+            DeviceEntitySource::CAN { .. } => {
+                Some(format!("{}{}/avty", base_topic, self.uniq_id()))
+            }
+            _ => None,
+        }
+    }
+
+    /// CAN devices are only available while both the bridge and the device itself are online
+    pub fn availability(&self, base_topic: &str) -> Vec<HassAvailability> {
+        let mut topics = vec![self.avty_topic(base_topic)];
+        topics.extend(self.device_avty_topic(base_topic));
+        topics
+            .into_iter()
+            .map(|topic| HassAvailability {
+                topic: topic.into(),
+                payload_available: "online".to_string().into(),
+                payload_not_available: "offline".to_string().into(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
     pub fn stat_topic(&self, base_topic: &str) -> String {
         format!("{}{}/stat", base_topic, self.uniq_id())
     }
@@ -298,12 +995,17 @@ impl DeviceEntityInner {
             | HassDiscoveryType::MediaPlayer
             | HassDiscoveryType::Switch
             | HassDiscoveryType::Light
-            | HassDiscoveryType::Thermostat => {
-                Some(format!("{}{}/cmd", base_topic, self.uniq_id()))
-            }
+            | HassDiscoveryType::Lock
+            | HassDiscoveryType::Button
+            | HassDiscoveryType::Climate => Some(self.device_command_topic(base_topic)),
         }
     }
 
+    /// The topic commands for this device are received on, even if the main entity is read only
+    pub fn device_command_topic(&self, base_topic: &str) -> String {
+        format!("{}{}/cmd", base_topic, self.uniq_id())
+    }
+
     pub fn config_topic(&self, config_base_topic: &str) -> String {
         format!(
             "{}{}/rvlink-bridge/{}/config",
@@ -312,4 +1014,391 @@ impl DeviceEntityInner {
             self.uniq_id()
         )
     }
+
+    pub fn sub_config_topic(
+        &self,
+        config_base_topic: &str,
+        sub_entity: &DeviceSubEntity,
+    ) -> String {
+        format!(
+            "{}{}/rvlink-bridge/{}/config",
+            config_base_topic,
+            sub_entity.typ,
+            self.sub_uniq_id(sub_entity)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A device entity as reported on the CAN bus at 1:2
+    pub(crate) fn can_entity(device_type: DeviceType) -> DeviceEntity {
+        let entity: DeviceEntity = Default::default();
+        entity.source.store(
+            DeviceEntitySource::CAN {
+                device_table: 1,
+                device_id: 2,
+            },
+            Ordering::Relaxed,
+        );
+        entity.device_type.store(device_type, Ordering::Relaxed);
+        entity.has_device_metadata.store(true, Ordering::Relaxed);
+        entity.has_device_info.store(true, Ordering::Relaxed);
+        entity
+    }
+
+    #[tokio::test]
+    /// Validates dimmable lights send and report brightness in the format run_command expects
+    async fn dimmable_light_discovery() {
+        let entity = can_entity(DeviceType::DimmableLight);
+        assert!(matches!(
+            entity.hass_device_type(),
+            HassDiscoveryType::Light
+        ));
+        let discovery = entity.to_discovery("rvlink-bridge/".into()).await;
+        assert_eq!(
+            discovery.brightness_command_template.as_deref(),
+            Some("brightness:{{ value }}")
+        );
+        assert_eq!(
+            discovery.brightness_value_template.as_deref(),
+            Some("{{ value_json.brightness }}")
+        );
+    }
+
+    #[tokio::test]
+    /// Validates RGB lights are discovered with the color and effect commands run_command accepts
+    async fn rgb_light_discovery() {
+        let entity = can_entity(DeviceType::RgbLight);
+        let discovery = entity.to_discovery("rvlink-bridge/".into()).await;
+        assert_eq!(
+            discovery.rgb_command_template.as_deref(),
+            Some("rgb:{{ red }},{{ green }},{{ blue }}")
+        );
+        assert_eq!(
+            discovery.effect_command_template.as_deref(),
+            Some("effect:{{ value }}")
+        );
+        assert_eq!(
+            discovery.effect_list,
+            Some(
+                ["none", "blink", "jump3", "jump7", "fade3", "fade7", "rainbow"]
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect()
+            )
+        );
+    }
+
+    #[tokio::test]
+    /// Validates thermostats are climate entities whose commands match what run_command parses
+    async fn thermostat_discovery() {
+        let entity = can_entity(DeviceType::HvacControl);
+        assert!(matches!(
+            entity.hass_device_type(),
+            HassDiscoveryType::Climate
+        ));
+        let discovery = entity.to_discovery("rvlink-bridge/".into()).await;
+        let strings = |values: &[&str]| Some(values.iter().map(|v| v.to_string()).collect());
+        assert_eq!(
+            discovery.modes,
+            strings(&["off", "heat", "cool", "heat_cool", "auto"])
+        );
+        assert_eq!(discovery.fan_modes, strings(&["auto", "high", "low"]));
+        assert_eq!(
+            discovery.mode_command_template.as_deref(),
+            Some("mode:{{ value }}")
+        );
+        assert_eq!(
+            discovery.temperature_low_command_template.as_deref(),
+            Some("heat_setpoint:{{ value }}")
+        );
+        assert_eq!(
+            discovery.temperature_high_command_template.as_deref(),
+            Some("cool_setpoint:{{ value }}")
+        );
+    }
+
+    #[test]
+    /// Validates unique ids keep the entity type segment existing HA entities were registered with
+    fn uniq_id_format() {
+        let entity = can_entity(DeviceType::HvacControl);
+        entity
+            .function_name
+            .store(FunctionName::GreyTank, Ordering::Relaxed);
+        assert_eq!(
+            entity.uniq_id(),
+            format!("{}-grey_tank-can-1-2", *MACHINEID)
+        );
+
+        // Climate zones, generators and the plain door lock were published before getting their
+        // own entity types
+        entity
+            .function_name
+            .store(FunctionName::MainClimateZone, Ordering::Relaxed);
+        assert_eq!(entity.uniq_id(), format!("{}-none-can-1-2", *MACHINEID));
+        entity
+            .function_name
+            .store(FunctionName::Generator, Ordering::Relaxed);
+        assert_eq!(entity.uniq_id(), format!("{}-none-can-1-2", *MACHINEID));
+        entity
+            .function_name
+            .store(FunctionName::DoorLock, Ordering::Relaxed);
+        assert_eq!(entity.uniq_id(), format!("{}-none-can-1-2", *MACHINEID));
+        entity
+            .function_name
+            .store(FunctionName::FrontDoorLock, Ordering::Relaxed);
+        assert_eq!(
+            entity.uniq_id(),
+            format!("{}-door_lock-can-1-2", *MACHINEID)
+        );
+    }
+
+    #[tokio::test]
+    /// Validates generators are switches with their telemetry published as sub entities
+    async fn generator_discovery() {
+        let entity = can_entity(DeviceType::GeneratorGenie);
+        assert!(matches!(
+            entity.hass_device_type(),
+            HassDiscoveryType::Switch
+        ));
+        let keys: Vec<&str> = entity.sub_entities().iter().map(|e| e.key).collect();
+        assert_eq!(
+            keys[..4],
+            ["status", "battery_voltage", "temperature", "run_hours"]
+        );
+    }
+
+    #[tokio::test]
+    /// Validates hour meters are duration sensors matched to a service schedule by name or address
+    async fn hour_meter_discovery() {
+        let entity = can_entity(DeviceType::HourMeter);
+        let discovery = entity.to_discovery("rvlink-bridge/".into()).await;
+        assert_eq!(discovery.device_class.as_deref(), Some("duration"));
+        assert_eq!(discovery.unit_of_measurement.as_deref(), Some("h"));
+
+        let schedules: Vec<HourMeterServiceInterval> = vec![
+            "Generator=200@1000".parse().unwrap(),
+            "1:2=50".parse().unwrap(),
+        ];
+        assert_eq!(entity.service_interval(&[]), None);
+        assert_eq!(entity.service_interval(&schedules[..1]), None);
+        assert_eq!(
+            entity.service_interval(&schedules),
+            Some(schedules[1].interval)
+        );
+
+        entity
+            .function_name
+            .store(FunctionName::Generator, Ordering::Relaxed);
+        assert_eq!(
+            entity.service_interval(&schedules),
+            Some(ServiceInterval {
+                interval_hours: 200,
+                last_service_hours: 1000,
+            })
+        );
+    }
+
+    #[tokio::test]
+    /// Validates slides and awnings are covers reporting the motion states published for H-bridges
+    async fn cover_discovery() {
+        let entity = can_entity(DeviceType::MomentaryRelayType2);
+        for (function_name, class) in [
+            (FunctionName::MainSlide, "door"),
+            (FunctionName::PatioAwning, "awning"),
+        ] {
+            entity.function_name.store(function_name, Ordering::Relaxed);
+            let discovery = entity.to_discovery("rvlink-bridge/".into()).await;
+            assert_eq!(discovery.device_class.as_deref(), Some(class));
+            assert_eq!(discovery.state_opening.as_deref(), Some("opening"));
+            assert_eq!(discovery.state_closing.as_deref(), Some("closing"));
+            assert_eq!(discovery.state_stopped.as_deref(), Some("stopped"));
+        }
+    }
+
+    #[tokio::test]
+    /// Validates the chassis is a lockout level sensor with the chassis inputs as sub entities
+    async fn chassis_discovery() {
+        let entity = DeviceEntity::new_system(SystemEntityType::Chassis).await;
+        let discovery = entity.to_discovery("rvlink-bridge/".into()).await;
+        assert_eq!(discovery.name.as_deref(), Some("Chassis Lockout Level"));
+        let keys: Vec<&str> = entity.sub_entities().iter().map(|e| e.key).collect();
+        assert_eq!(
+            keys,
+            [
+                "park_brake",
+                "ignition",
+                "towable_battery_voltage",
+                "towable_brake_voltage"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    /// Validates the gateway clock is a unitless timestamp sensor
+    async fn clock_discovery() {
+        let entity = DeviceEntity::new_system(SystemEntityType::Clock).await;
+        let discovery = entity.to_discovery("rvlink-bridge/".into()).await;
+        assert_eq!(discovery.device_class.as_deref(), Some("timestamp"));
+        assert_eq!(discovery.unit_of_measurement, None);
+
+        let sub_entities = entity.sub_entities();
+        let sync = sub_entities.iter().find(|e| e.key == "sync").unwrap();
+        let discovery = entity.to_sub_discovery("rvlink-bridge/".into(), sync).await;
+        assert_eq!(discovery.payload_press.as_deref(), Some("sync"));
+        assert_eq!(
+            discovery.command_topic,
+            Some(entity.device_command_topic("~"))
+        );
+    }
+
+    #[tokio::test]
+    /// Validates numeric sensors are discovered with their units and classes
+    async fn sensor_discovery() {
+        let battery = DeviceEntity::new_system(SystemEntityType::Battery).await;
+        let outdoor = DeviceEntity::new_system(SystemEntityType::OutdoorTemperature).await;
+        let tank = can_entity(DeviceType::TankSensor);
+        tank.function_name
+            .store(FunctionName::FreshTank, Ordering::Relaxed);
+        let chassis = DeviceEntity::new_system(SystemEntityType::Chassis).await;
+        assert_eq!(tank.hass_icon(), HassIcons::GAUGE);
+        for (entity, class, unit, state_class) in [
+            (battery, Some("voltage"), Some("V"), Some("measurement")),
+            (
+                outdoor,
+                Some("temperature"),
+                Some("°F"),
+                Some("measurement"),
+            ),
+            (tank, None, Some("%"), Some("measurement")),
+            (chassis, None, None, None),
+        ] {
+            assert!(matches!(
+                entity.hass_device_type(),
+                HassDiscoveryType::Sensor(_)
+            ));
+            let discovery = entity.to_discovery("rvlink-bridge/".into()).await;
+            assert_eq!(discovery.device_class.as_deref(), class);
+            assert_eq!(discovery.unit_of_measurement.as_deref(), unit);
+            assert_eq!(discovery.state_class.as_deref(), state_class);
+        }
+    }
+
+    #[tokio::test]
+    /// Validates temperature sensors report the temperature with the alarms as binary sensors
+    async fn temperature_sensor_discovery() {
+        let entity = can_entity(DeviceType::TemperatureSensor);
+        let discovery = entity.to_discovery("rvlink-bridge/".into()).await;
+        assert_eq!(discovery.device_class.as_deref(), Some("temperature"));
+        let keys: Vec<&str> = entity.sub_entities().iter().map(|e| e.key).collect();
+        assert_eq!(keys[..2], ["cold", "heat"]);
+    }
+
+    #[tokio::test]
+    /// Validates battery monitors report the state of charge with the shunt readings as sensors
+    async fn battery_monitor_discovery() {
+        let entity = can_entity(DeviceType::BatteryMonitor);
+        let discovery = entity.to_discovery("rvlink-bridge/".into()).await;
+        assert_eq!(discovery.device_class.as_deref(), Some("battery"));
+        let sub_entities = entity.sub_entities();
+        let power = sub_entities.iter().find(|e| e.key == "power").unwrap();
+        assert_eq!(power.unit, Some("W"));
+        assert!(matches!(
+            power.typ,
+            HassDiscoveryType::Sensor(HassDiscoverySensorClass::Power)
+        ));
+    }
+
+    #[tokio::test]
+    /// Validates door locks only ask for a code when one is expected on the lock commands
+    async fn door_lock_discovery() {
+        let entity = can_entity(DeviceType::DoorLock);
+        assert!(matches!(entity.hass_device_type(), HassDiscoveryType::Lock));
+
+        let mut discovery = HassDiscoveryInfo::default();
+        DeviceEntity::lock_discovery(&mut discovery, None);
+        assert_eq!(discovery.payload_lock.as_deref(), Some("lock"));
+        assert_eq!(discovery.state_jammed.as_deref(), Some("jammed"));
+        assert_eq!(discovery.code_format, None);
+        assert_eq!(discovery.command_template, None);
+
+        DeviceEntity::lock_discovery(&mut discovery, Some("1234"));
+        assert_eq!(discovery.code_format.as_deref(), Some("^.+$"));
+        assert_eq!(
+            discovery.command_template.as_deref(),
+            Some("{{ value }}:{{ code }}")
+        );
+    }
+
+    #[tokio::test]
+    /// Validates awning sensors report wind speed with the alarms as sub entities
+    async fn awning_sensor_discovery() {
+        let entity = can_entity(DeviceType::AwningSensor);
+        let discovery = entity.to_discovery("rvlink-bridge/".into()).await;
+        assert_eq!(discovery.device_class.as_deref(), Some("wind_speed"));
+        let keys: Vec<&str> = entity.sub_entities().iter().map(|e| e.key).collect();
+        assert_eq!(keys[..3], ["vibration", "wind_alarm", "vibration_alarm"]);
+    }
+
+    #[tokio::test]
+    /// Validates both brake controllers report the output level with the brake telemetry
+    async fn brakes_discovery() {
+        for device_type in [DeviceType::BrakeController, DeviceType::EchoBrakeControl] {
+            let entity = can_entity(device_type);
+            assert_eq!(entity.device_entity_type(), DeviceEntityType::Brakes);
+            let keys: Vec<&str> = entity.sub_entities().iter().map(|e| e.key).collect();
+            assert_eq!(keys[..4], ["gain", "trailer_connected", "braking", "fault"]);
+        }
+    }
+
+    #[tokio::test]
+    /// Validates every leveler generation gets the console text and buttons sending leveler commands
+    async fn leveler_discovery() {
+        for device_type in [
+            DeviceType::LevelerType1,
+            DeviceType::LevelerType3,
+            DeviceType::LevelerType4,
+        ] {
+            let entity = can_entity(device_type);
+            let sub_entities = entity.sub_entities();
+            let console = sub_entities
+                .iter()
+                .find(|e| e.key == "console_text")
+                .unwrap();
+            assert!(console.from_attributes);
+            let mut buttons = vec![];
+            for button in sub_entities
+                .iter()
+                .filter(|e| matches!(e.typ, HassDiscoveryType::Button))
+            {
+                // Button presses arrive as leveler commands
+                let discovery = entity
+                    .to_sub_discovery("rvlink-bridge/".into(), button)
+                    .await;
+                let payload = discovery.payload_press.unwrap();
+                assert!(payload.parse::<rvlink_proto::LevelerButton>().is_ok());
+                buttons.push(payload);
+            }
+            assert_eq!(buttons, ["auto_level", "retract_all", "stop"]);
+        }
+    }
+
+    #[tokio::test]
+    /// Validates the cloud gateway is a connectivity sensor and the TBB gateway a battery sensor
+    async fn gateway_discovery() {
+        let cloud = can_entity(DeviceType::OnecontrolCloudGateway);
+        let discovery = cloud.to_discovery("rvlink-bridge/".into()).await;
+        assert_eq!(discovery.device_class.as_deref(), Some("connectivity"));
+        let keys: Vec<&str> = cloud.sub_entities().iter().map(|e| e.key).collect();
+        assert_eq!(keys[0], "registered");
+
+        let tbb = can_entity(DeviceType::JaycoAusTbbGw);
+        let discovery = tbb.to_discovery("rvlink-bridge/".into()).await;
+        assert_eq!(discovery.device_class.as_deref(), Some("battery"));
+        let keys: Vec<&str> = tbb.sub_entities().iter().map(|e| e.key).collect();
+        assert!(keys.contains(&"solar_power"));
+    }
 }
@@ -4,6 +4,7 @@ use crate::rvlink::RVLink;
 use rumqttc::{AsyncClient, Event, MqttOptions, Packet};
 use rumqttc::{LastWill, QoS};
 use rvlink_common::error::*;
+use std::sync::atomic::Ordering;
 use std::{sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 use tokio::task;
@@ -53,6 +54,50 @@ impl MqttManager {
             true,
             QoS::AtLeastOnce,
         )
+        .await?;
+        for sub_entity in device.sub_entities() {
+            let discovery = device
+                .to_sub_discovery(self.base_topic.to_string(), &sub_entity)
+                .await;
+            let config_topic = device.sub_config_topic(&self.discovery_topic, &sub_entity);
+            self.send(
+                &config_topic,
+                serde_json::to_vec(&discovery)?,
+                true,
+                QoS::AtLeastOnce,
+            )
+            .await?;
+        }
+        self.publish_device_availability(device).await?;
+        self.publish_device_attributes(device).await
+    }
+
+    pub async fn publish_device_availability(&self, device: &DeviceEntity) -> Result<()> {
+        if let Some(avty_topic) = device.device_avty_topic(&self.base_topic) {
+            let payload = if device.offline.load(Ordering::Relaxed) {
+                "offline"
+            } else {
+                "online"
+            };
+            self.send(&avty_topic, payload, true, QoS::AtLeastOnce)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn publish_device_attributes(&self, device: &DeviceEntity) -> Result<()> {
+        let attr_topic = device.attr_topic(&self.base_topic);
+        let attributes: serde_json::Map<String, serde_json::Value> = device
+            .attributes
+            .iter()
+            .map(|attr| (attr.key().clone(), attr.val().clone().into()))
+            .collect();
+        self.send(
+            &attr_topic,
+            serde_json::to_vec(&attributes)?,
+            true,
+            QoS::AtLeastOnce,
+        )
         .await
     }
 
@@ -61,6 +106,34 @@ impl MqttManager {
         self.send(&state_topic, state, true, QoS::AtLeastOnce).await
     }
 
+    /// PID dumps are requested on demand for troubleshooting, so they are not retained
+    pub async fn publish_device_pids(
+        &self,
+        device: &DeviceEntity,
+        pids: &serde_json::Value,
+    ) -> Result<()> {
+        let pids_topic = format!("{}{}/pids", self.base_topic, device.uniq_id());
+        self.send(
+            &pids_topic,
+            serde_json::to_vec(pids)?,
+            false,
+            QoS::AtLeastOnce,
+        )
+        .await
+    }
+
+    /// Events are one-off notifications of something the bridge did, so they are not retained
+    pub async fn publish_event(&self, event: &serde_json::Value) -> Result<()> {
+        let event_topic = format!("{}events", self.base_topic);
+        self.send(
+            &event_topic,
+            serde_json::to_vec(event)?,
+            false,
+            QoS::AtLeastOnce,
+        )
+        .await
+    }
+
     async fn send<T: Into<Vec<u8>>>(
         &self,
         topic: &str,
@@ -1,22 +1,58 @@
 use crate::bluetooth::BluetoothManager;
+use crate::config::{self, ServiceInterval};
 use crate::devices::DeviceEntity;
 use crate::devices::SystemEntityType;
 use crate::mqtt::MqttManager;
 use atomic::Atomic;
-use fixed::{types::extra::U8, FixedU16};
+use chrono::{DateTime, Datelike, Local, NaiveDateTime, TimeZone, Timelike};
+use fixed::{types::extra::U8, FixedI16, FixedU16};
+use futures::StreamExt;
 use lockfree::map::Map;
 use rand::Rng;
+use rvlink_common::devices::DeviceEntityType;
 use rvlink_common::*;
 use rvlink_proto::{events, *};
 use std::sync::atomic::*;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::{mpsc, RwLock};
-use tokio::time::{interval, sleep, Duration};
+use tokio::time::{interval, sleep, timeout, Duration};
 
 #[derive(Debug, Deref, Clone)]
 pub struct RVLink(Arc<RVLinkInner>);
 
+#[derive(Debug, Default, Display, Clone, Copy, PartialEq)]
+pub enum CoverState {
+    #[default]
+    #[display(fmt = "stopped")]
+    Stopped,
+    #[display(fmt = "opening")]
+    Opening,
+    #[display(fmt = "closing")]
+    Closing,
+}
+
+impl CoverState {
+    /// The motion of an H-bridge relay driving a slide or awning
+    pub fn from_relay<R: RelayState>(relay: &R) -> Self {
+        if relay.is_forward_active() {
+            CoverState::Opening
+        } else if relay.is_reverse_active() {
+            CoverState::Closing
+        } else {
+            CoverState::Stopped
+        }
+    }
+}
+
+/// A console button command in the format of each leveler generation
+#[derive(Debug)]
+enum LevelerButtonPress {
+    Type1(Leveler1ButtonCommand),
+    Type3(Leveler3ButtonCommand),
+    Type4(Leveler4ButtonCommand),
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum DeviceState {
     #[default]
@@ -24,6 +60,100 @@ pub enum DeviceState {
     Switch(OnOff),
     Percentage(u8),
     Voltage(FixedU16<U8>),
+    Temperature(FixedI16<U8>),
+    AwningSensor {
+        wind_speed: Option<u8>,
+        vibration: Option<u8>,
+        wind_alarm: bool,
+        vibration_alarm: bool,
+    },
+    Leveler {
+        mode: LevelerMode,
+        screen: u8,
+        level: bool,
+        jacks_extended: bool,
+        excess_angle: bool,
+        fault: bool,
+    },
+    Brakes {
+        output_level: u8,
+        gain: f32,
+        trailer_connected: bool,
+        braking: bool,
+        open_circuit: bool,
+        short_circuit: bool,
+        overloaded: bool,
+    },
+    BatteryMonitor {
+        voltage: FixedU16<U8>,
+        current: f32,
+        state_of_charge: Option<u8>,
+        time_remaining: Option<u16>,
+        charging: bool,
+    },
+    CloudGateway {
+        connected: bool,
+        registered: bool,
+    },
+    PowerSystem {
+        battery_voltage: FixedU16<U8>,
+        battery_current: f32,
+        solar_current: f32,
+        state_of_charge: Option<u8>,
+        mains_voltage: Option<u16>,
+        mains_connected: bool,
+        solar_charging: bool,
+    },
+    TemperatureSensor {
+        temperature: Option<FixedI16<U8>>,
+        low_alarm: bool,
+        high_alarm: bool,
+    },
+    Cover(CoverState),
+    Lock(LockState),
+    Clock {
+        seconds_from_epoch: u32,
+        drift: i64,
+    },
+    Chassis {
+        lockout_level: u8,
+        park_brake: bool,
+        ignition: bool,
+        towable_battery_voltage: f32,
+        towable_brake_voltage: f32,
+    },
+    Dimmable {
+        state: OnOff,
+        brightness: u8,
+    },
+    Rgb {
+        mode: RgbLightMode,
+        red: u8,
+        green: u8,
+        blue: u8,
+    },
+    Hvac {
+        command: HvacCommand,
+        heat_setpoint: u8,
+        cool_setpoint: u8,
+        zone_status: HvacZoneStatus,
+        indoor_temperature: Option<FixedI16<U8>>,
+        outdoor_temperature: Option<FixedI16<U8>>,
+    },
+    Generator {
+        state: GeneratorState,
+        battery_voltage: FixedU16<U8>,
+        temperature: Option<FixedI16<U8>>,
+        operating_seconds: u32,
+    },
+    HourMeter {
+        operating_seconds: u32,
+        running: bool,
+        maintenance_due: bool,
+        maintenance_past_due: bool,
+        error: bool,
+        service_interval: Option<ServiceInterval>,
+    },
 }
 
 impl DeviceState {
@@ -31,8 +161,264 @@ impl DeviceState {
         match self {
             DeviceState::Unknown => "unknown".into(),
             DeviceState::Switch(onoff) => onoff.to_string(),
-            DeviceState::Percentage(pc) => format!("{}%", pc),
-            DeviceState::Voltage(v) => format!("{}V", v),
+            DeviceState::Percentage(pc) => pc.to_string(),
+            DeviceState::Voltage(v) => v.to_string(),
+            DeviceState::Temperature(t) => t.to_string(),
+            DeviceState::AwningSensor {
+                wind_speed,
+                vibration,
+                wind_alarm,
+                vibration_alarm,
+            } => serde_json::json!({
+                "wind_speed": wind_speed,
+                "vibration": vibration,
+                "wind_alarm": Self::on_off_str(*wind_alarm),
+                "vibration_alarm": Self::on_off_str(*vibration_alarm),
+            })
+            .to_string(),
+            DeviceState::Leveler {
+                mode,
+                level,
+                jacks_extended,
+                excess_angle,
+                fault,
+                ..
+            } => serde_json::json!({
+                "mode": mode.to_string(),
+                "level": Self::on_off_str(*level),
+                "jacks_extended": Self::on_off_str(*jacks_extended),
+                "excess_angle": Self::on_off_str(*excess_angle),
+                "fault": Self::on_off_str(*fault),
+            })
+            .to_string(),
+            DeviceState::Brakes {
+                output_level,
+                gain,
+                trailer_connected,
+                braking,
+                open_circuit,
+                short_circuit,
+                overloaded,
+            } => {
+                let faults: Vec<&str> = [
+                    (*open_circuit, "open_circuit"),
+                    (*short_circuit, "short_circuit"),
+                    (*overloaded, "overload"),
+                ]
+                .iter()
+                .filter(|(active, _)| *active)
+                .map(|(_, name)| *name)
+                .collect();
+                serde_json::json!({
+                    "output_level": output_level,
+                    "gain": gain,
+                    "trailer_connected": Self::on_off_str(*trailer_connected),
+                    "braking": Self::on_off_str(*braking),
+                    "fault": Self::on_off_str(!faults.is_empty()),
+                    "faults": faults,
+                })
+                .to_string()
+            }
+            DeviceState::BatteryMonitor {
+                voltage,
+                current,
+                state_of_charge,
+                time_remaining,
+                charging,
+            } => {
+                let voltage = voltage.to_num::<f32>();
+                serde_json::json!({
+                    "state_of_charge": state_of_charge,
+                    "voltage": voltage,
+                    "current": current,
+                    "power": voltage * current,
+                    "time_remaining": time_remaining,
+                    "charging": Self::on_off_str(*charging),
+                })
+                .to_string()
+            }
+            DeviceState::CloudGateway {
+                connected,
+                registered,
+            } => serde_json::json!({
+                "connected": Self::on_off_str(*connected),
+                "registered": Self::on_off_str(*registered),
+            })
+            .to_string(),
+            DeviceState::PowerSystem {
+                battery_voltage,
+                battery_current,
+                solar_current,
+                state_of_charge,
+                mains_voltage,
+                mains_connected,
+                solar_charging,
+            } => {
+                let battery_voltage = battery_voltage.to_num::<f32>();
+                serde_json::json!({
+                    "state_of_charge": state_of_charge,
+                    "battery_voltage": battery_voltage,
+                    "battery_current": battery_current,
+                    "solar_current": solar_current,
+                    "solar_power": battery_voltage * solar_current,
+                    "mains_voltage": mains_voltage,
+                    "mains_connected": Self::on_off_str(*mains_connected),
+                    "solar_charging": Self::on_off_str(*solar_charging),
+                })
+                .to_string()
+            }
+            DeviceState::TemperatureSensor {
+                temperature,
+                low_alarm,
+                high_alarm,
+            } => serde_json::json!({
+                "temperature": temperature.map(|t| t.to_num::<f32>()),
+                "cold": Self::on_off_str(*low_alarm),
+                "heat": Self::on_off_str(*high_alarm),
+            })
+            .to_string(),
+            DeviceState::Cover(state) => state.to_string(),
+            DeviceState::Lock(state) => state.to_string(),
+            DeviceState::Clock {
+                seconds_from_epoch,
+                drift,
+            } => {
+                // The gateway clock has no timezone, it is kept in local time
+                let timestamp = NaiveDateTime::from_timestamp_opt(*seconds_from_epoch as i64, 0)
+                    .and_then(|t| Local.from_local_datetime(&t).earliest())
+                    .map(|t| t.to_rfc3339());
+                serde_json::json!({
+                    "timestamp": timestamp,
+                    "drift": drift,
+                })
+                .to_string()
+            }
+            DeviceState::Chassis {
+                lockout_level,
+                park_brake,
+                ignition,
+                towable_battery_voltage,
+                towable_brake_voltage,
+            } => serde_json::json!({
+                "lockout_level": lockout_level,
+                "park_brake": Self::on_off_str(*park_brake),
+                "ignition": Self::on_off_str(*ignition),
+                "towable_battery_voltage": towable_battery_voltage,
+                "towable_brake_voltage": towable_brake_voltage,
+            })
+            .to_string(),
+            DeviceState::Dimmable { state, brightness } => serde_json::json!({
+                "state": state.to_string(),
+                "brightness": brightness,
+            })
+            .to_string(),
+            DeviceState::Rgb {
+                mode,
+                red,
+                green,
+                blue,
+            } => {
+                let state = match mode {
+                    RgbLightMode::Off => OnOff::Off,
+                    _ => OnOff::On,
+                };
+                let effect = match mode {
+                    RgbLightMode::Off | RgbLightMode::On | RgbLightMode::Restore => "none".into(),
+                    effect => effect.to_string(),
+                };
+                serde_json::json!({
+                    "state": state.to_string(),
+                    "rgb": [red, green, blue],
+                    "effect": effect,
+                })
+                .to_string()
+            }
+            DeviceState::Hvac {
+                command,
+                heat_setpoint,
+                cool_setpoint,
+                zone_status,
+                indoor_temperature,
+                outdoor_temperature,
+            } => {
+                let temperature = match command.heat_mode {
+                    HvacHeatMode::Heating => Some(heat_setpoint),
+                    HvacHeatMode::Cooling => Some(cool_setpoint),
+                    _ => None,
+                };
+                let action = match zone_status {
+                    HvacZoneStatus::Off => "off",
+                    HvacZoneStatus::Cooling => "cooling",
+                    HvacZoneStatus::HeatPump
+                    | HvacZoneStatus::ElectricFurnace
+                    | HvacZoneStatus::GasFurnace
+                    | HvacZoneStatus::GasOverride => "heating",
+                    HvacZoneStatus::Idle
+                    | HvacZoneStatus::DeadTime
+                    | HvacZoneStatus::LoadShedding => "idle",
+                };
+                serde_json::json!({
+                    "mode": command.heat_mode.to_string(),
+                    "fan_mode": command.fan_mode.to_string(),
+                    "heat_source": command.heat_source.to_string(),
+                    "temperature": temperature,
+                    "heat_setpoint": heat_setpoint,
+                    "cool_setpoint": cool_setpoint,
+                    "action": action,
+                    "zone_status": zone_status.to_string(),
+                    "current_temperature": indoor_temperature.map(|t| t.to_num::<f32>()),
+                    "outdoor_temperature": outdoor_temperature.map(|t| t.to_num::<f32>()),
+                })
+                .to_string()
+            }
+            DeviceState::Generator {
+                state,
+                battery_voltage,
+                temperature,
+                operating_seconds,
+            } => {
+                let on_off = match state {
+                    GeneratorState::Off => OnOff::Off,
+                    _ => OnOff::On,
+                };
+                serde_json::json!({
+                    "state": on_off.to_string(),
+                    "status": state.to_string(),
+                    "battery_voltage": battery_voltage.to_num::<f32>(),
+                    "temperature": temperature.map(|t| t.to_num::<f32>()),
+                    "run_hours": *operating_seconds as f32 / 3600f32,
+                })
+                .to_string()
+            }
+            DeviceState::HourMeter {
+                operating_seconds,
+                running,
+                maintenance_due,
+                maintenance_past_due,
+                error,
+                service_interval,
+            } => {
+                let hours = *operating_seconds as f32 / 3600f32;
+                serde_json::json!({
+                    "hours": hours,
+                    "running": Self::on_off_str(*running),
+                    "maintenance_due": Self::on_off_str(*maintenance_due),
+                    "maintenance_past_due": Self::on_off_str(*maintenance_past_due),
+                    "error": Self::on_off_str(*error),
+                    "service_due": service_interval.map(|i| Self::on_off_str(i.is_due(hours))),
+                    "hours_until_service": service_interval
+                        .map(|i| i.next_service_hours() as f32 - hours),
+                })
+                .to_string()
+            }
+        }
+    }
+
+    fn on_off_str(val: bool) -> &'static str {
+        if val {
+            "on"
+        } else {
+            "off"
         }
     }
 }
@@ -43,6 +429,45 @@ pub struct DeviceEntry {
     pub state: Atomic<DeviceState>,
     pub last_published: AtomicU64,
     pub last_published_state: Atomic<DeviceState>,
+    /// PIDs supported by the device and their flags, filled in the first time they are requested
+    pub pids: Map<u16, u8>,
+}
+
+impl DeviceEntry {
+    /// Entry for one of the entities the bridge publishes on its own
+    async fn new_system(typ: SystemEntityType) -> Arc<Self> {
+        Arc::new(Self {
+            entity: DeviceEntity::new_system(typ).await,
+            ..Default::default()
+        })
+    }
+
+    /// Adds the PIDs from one PID list response to the cache
+    fn cache_pid_list(&self, response: &GetDevicePidListResponse) {
+        if let GetDevicePidListResponse::Success(data) = response {
+            for pid in data.pids.iter() {
+                self.pids.insert(pid.pid, pid.flags);
+            }
+        }
+    }
+
+    fn has_cached_pids(&self) -> bool {
+        self.pids.iter().next().is_some()
+    }
+
+    /// The cached PIDs ordered by PID
+    fn cached_pids(&self) -> Vec<DevicePidEntry> {
+        let mut pids: Vec<DevicePidEntry> = self
+            .pids
+            .iter()
+            .map(|pid| DevicePidEntry {
+                pid: *pid.key(),
+                flags: *pid.val(),
+            })
+            .collect();
+        pids.sort_by_key(|pid| pid.pid);
+        pids
+    }
 }
 
 #[derive(Debug, Default)]
@@ -62,6 +487,10 @@ pub struct RVLinkInner {
     device_tables: Map<u8, Arc<DeviceTable>>,
     device_id_lookup: Map<String, Arc<DeviceEntry>>,
     battery: Arc<DeviceEntry>,
+    chassis: Arc<DeviceEntry>,
+    clock: Arc<DeviceEntry>,
+    outdoor_temperature: Arc<DeviceEntry>,
+    last_clock_sync: AtomicU64,
 }
 
 #[allow(dead_code)]
@@ -77,12 +506,12 @@ impl RVLink {
             device_tables: Default::default(),
             mqtt: Default::default(),
             device_id_lookup: Default::default(),
-            battery: Arc::new(DeviceEntry {
-                entity: DeviceEntity::new_system(SystemEntityType::Battery).await,
-                state: Default::default(),
-                last_published: Default::default(),
-                last_published_state: Default::default(),
-            }),
+            battery: DeviceEntry::new_system(SystemEntityType::Battery).await,
+            chassis: DeviceEntry::new_system(SystemEntityType::Chassis).await,
+            clock: DeviceEntry::new_system(SystemEntityType::Clock).await,
+            outdoor_temperature: DeviceEntry::new_system(SystemEntityType::OutdoorTemperature)
+                .await,
+            last_clock_sync: Default::default(),
         })))
     }
 
@@ -106,17 +535,15 @@ impl RVLink {
                 .last_published_state
                 .store(cur_state, Ordering::Relaxed);
             entry.last_published.store(cur_timestamp, Ordering::Relaxed);
-            let zelf = self.clone();
+            let mqtt = match self.get_mqtt().await {
+                Some(mqtt) => mqtt,
+                None => return,
+            };
             let device = entry.entity.clone();
             let state_str = cur_state.state_string();
             // Spawn a lightweight task so we don't block this one if MQTT is blocked
             tokio::task::spawn(async move {
-                match zelf
-                    .get_mqtt()
-                    .await
-                    .publish_device_state(&device, &state_str)
-                    .await
-                {
+                match mqtt.publish_device_state(&device, &state_str).await {
                     Ok(_) => {}
                     Err(e) => warn!("Could not update device state due to error! {:?}", e),
                 }
@@ -125,16 +552,69 @@ impl RVLink {
     }
 
     async fn publish_device_info(&self, device: DeviceEntity) {
-        let zelf = self.clone();
+        let mqtt = match self.get_mqtt().await {
+            Some(mqtt) => mqtt,
+            None => return,
+        };
         tokio::task::spawn(async move {
-            match zelf.get_mqtt().await.publish_device_info(&device).await {
+            match mqtt.publish_device_info(&device).await {
                 Ok(_) => {}
                 Err(e) => warn!("Could not update device state due to error! {:?}", e),
             }
         });
     }
 
+    async fn publish_device_attributes(&self, device: DeviceEntity) {
+        let mqtt = match self.get_mqtt().await {
+            Some(mqtt) => mqtt,
+            None => return,
+        };
+        tokio::task::spawn(async move {
+            match mqtt.publish_device_attributes(&device).await {
+                Ok(_) => {}
+                Err(e) => warn!("Could not update device attributes due to error! {:?}", e),
+            }
+        });
+    }
+
+    async fn publish_device_availability(&self, device: DeviceEntity) {
+        let mqtt = match self.get_mqtt().await {
+            Some(mqtt) => mqtt,
+            None => return,
+        };
+        tokio::task::spawn(async move {
+            match mqtt.publish_device_availability(&device).await {
+                Ok(_) => {}
+                Err(e) => warn!("Could not update device availability due to error! {:?}", e),
+            }
+        });
+    }
+
+    /// Update attributes on a device, publishing them again only if something changed
+    async fn set_device_attributes(&self, entry: &DeviceEntry, attributes: &[(&str, String)]) {
+        let mut changed = false;
+        for (key, val) in attributes {
+            let cur = entry.entity.attributes.get(*key).map(|v| v.val().clone());
+            if cur.as_ref() != Some(val) {
+                entry.entity.attributes.insert(key.to_string(), val.clone());
+                changed = true;
+            }
+        }
+        if changed && entry.entity.device_is_ready().await {
+            self.publish_device_attributes(entry.entity.clone()).await;
+        }
+    }
+
     pub async fn run_command(&self, uniq_id: &str, command: &str) -> Result<()> {
+        if uniq_id == self.clock.entity.uniq_id() {
+            return match command {
+                "sync" => self.sync_clock().await,
+                _ => Err(AppError::Generic(format!(
+                    "Unknown clock command: {}",
+                    command
+                ))),
+            };
+        }
         if let Some(device) = self.lookup_device(uniq_id).await {
             let device = device.clone();
             if let Some((device_table_id, device_id)) = device.entity.get_device_address().await {
@@ -142,6 +622,51 @@ impl RVLink {
                     "Processing command {} to {}:{}",
                     command, device_table_id, device_id
                 );
+                if command == "dump_pids" {
+                    let pids = self.dump_device_pids(&device).await?;
+                    return match self.get_mqtt().await {
+                        Some(mqtt) => mqtt.publish_device_pids(&device.entity, &pids).await,
+                        None => Ok(()),
+                    };
+                }
+                if device.entity.via_accessory_gateway.load(Ordering::Relaxed) {
+                    return self
+                        .run_accessory_gateway_command(device_table_id, device_id, command)
+                        .await;
+                }
+                match device.entity.device_entity_type() {
+                    DeviceEntityType::DimmableLight => {
+                        return self
+                            .run_dimmable_command(&device, device_table_id, device_id, command)
+                            .await
+                    }
+                    DeviceEntityType::RgbLight => {
+                        return self
+                            .run_rgb_command(&device, device_table_id, device_id, command)
+                            .await
+                    }
+                    DeviceEntityType::Generator => {
+                        return self
+                            .run_generator_command(device_table_id, device_id, command)
+                            .await
+                    }
+                    DeviceEntityType::Thermostat => {
+                        return self
+                            .run_hvac_command(&device, device_table_id, device_id, command)
+                            .await
+                    }
+                    DeviceEntityType::DoorLock => {
+                        return self
+                            .run_door_lock_command(device_table_id, device_id, command)
+                            .await
+                    }
+                    DeviceEntityType::Leveler => {
+                        return self
+                            .run_leveler_command(&device, device_table_id, device_id, command)
+                            .await
+                    }
+                    _ => {}
+                }
                 match command {
                     "on" => {
                         self.send(ActionSwitch {
@@ -197,18 +722,397 @@ impl RVLink {
         Ok(())
     }
 
+    async fn run_dimmable_command(
+        &self,
+        device: &DeviceEntry,
+        device_table_id: u8,
+        device_id: u8,
+        command: &str,
+    ) -> Result<()> {
+        let brightness = match (command, command.split_once(':')) {
+            ("on", _) => match device.state.load(Ordering::Relaxed) {
+                DeviceState::Dimmable { brightness, .. } if brightness > 0 => brightness,
+                _ => u8::MAX,
+            },
+            ("off", _) => 0,
+            (_, Some(("brightness", level))) => level.trim().parse::<u8>()?,
+            (cmd, _) => {
+                warn!("Unrecognized command: {}", cmd);
+                return Ok(());
+            }
+        };
+        let device_command = if brightness > 0 {
+            DimmableLightCommand::On
+        } else {
+            DimmableLightCommand::Off
+        };
+        self.send(ActionDimmable {
+            client_command_id: Default::default(),
+            device_table_id,
+            device_id,
+            device_command,
+            max_brightness: brightness,
+            duration: 0,
+            cycle_time_1: 0,
+            cycle_time_2: 0,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn run_rgb_command(
+        &self,
+        device: &DeviceEntry,
+        device_table_id: u8,
+        device_id: u8,
+        command: &str,
+    ) -> Result<()> {
+        let (red, green, blue) = match device.state.load(Ordering::Relaxed) {
+            DeviceState::Rgb {
+                red, green, blue, ..
+            } if (red, green, blue) != (0, 0, 0) => (red, green, blue),
+            _ => (u8::MAX, u8::MAX, u8::MAX),
+        };
+        let command = match (command, command.split_once(':')) {
+            // Clearing the effect in HA leaves the light on with its last color
+            ("on", _) | (_, Some(("effect", "none"))) => RgbLightCommand::Solid {
+                red,
+                green,
+                blue,
+                duration: 0,
+            },
+            ("off", _) => RgbLightCommand::Off,
+            (_, Some(("rgb", rgb))) => {
+                let rgb = rgb
+                    .split(',')
+                    .map(|c| c.trim().parse::<u8>())
+                    .collect::<std::result::Result<Vec<u8>, _>>()?;
+                match rgb[..] {
+                    [red, green, blue] => RgbLightCommand::Solid {
+                        red,
+                        green,
+                        blue,
+                        duration: 0,
+                    },
+                    _ => return Err(AppError::InvalidPayload),
+                }
+            }
+            (_, Some(("effect", "blink"))) => RgbLightCommand::Blink {
+                red,
+                green,
+                blue,
+                duration: 0,
+                on_interval: 10,
+                off_interval: 10,
+            },
+            (_, Some(("effect", effect))) => RgbLightCommand::Transition {
+                mode: match effect {
+                    "jump3" => RgbLightMode::Jump3,
+                    "jump7" => RgbLightMode::Jump7,
+                    "fade3" => RgbLightMode::Fade3,
+                    "fade7" => RgbLightMode::Fade7,
+                    "rainbow" => RgbLightMode::Rainbow,
+                    _ => {
+                        warn!("Unrecognized effect: {}", effect);
+                        return Ok(());
+                    }
+                },
+                duration: 0,
+                interval: 1000,
+            },
+            (cmd, _) => {
+                warn!("Unrecognized command: {}", cmd);
+                return Ok(());
+            }
+        };
+        self.send(ActionRgb {
+            client_command_id: Default::default(),
+            device_table_id,
+            device_id,
+            command,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn run_hvac_command(
+        &self,
+        device: &DeviceEntry,
+        device_table_id: u8,
+        device_id: u8,
+        command: &str,
+    ) -> Result<()> {
+        let (mut hvac_command, mut heat_setpoint, mut cool_setpoint) =
+            match device.state.load(Ordering::Relaxed) {
+                DeviceState::Hvac {
+                    command,
+                    heat_setpoint,
+                    cool_setpoint,
+                    ..
+                } => (command, heat_setpoint, cool_setpoint),
+                _ => {
+                    warn!("Cannot change HVAC settings before the current state is known!");
+                    return Ok(());
+                }
+            };
+        let parse_temperature =
+            |val: &str| -> Result<u8> { Ok(val.trim().parse::<f32>()?.round() as u8) };
+        match command.split_once(':') {
+            Some(("mode", mode)) => {
+                hvac_command.heat_mode = match mode {
+                    "off" => HvacHeatMode::Off,
+                    "heat" => HvacHeatMode::Heating,
+                    "cool" => HvacHeatMode::Cooling,
+                    "heat_cool" => HvacHeatMode::Both,
+                    "auto" => HvacHeatMode::RunSchedule,
+                    _ => {
+                        warn!("Unrecognized HVAC mode: {}", mode);
+                        return Ok(());
+                    }
+                }
+            }
+            Some(("fan_mode", fan_mode)) => {
+                hvac_command.fan_mode = match fan_mode {
+                    "auto" => HvacFanMode::Auto,
+                    "high" => HvacFanMode::High,
+                    "low" => HvacFanMode::Low,
+                    _ => {
+                        warn!("Unrecognized HVAC fan mode: {}", fan_mode);
+                        return Ok(());
+                    }
+                }
+            }
+            Some(("temperature", temp)) => match hvac_command.heat_mode {
+                HvacHeatMode::Cooling => cool_setpoint = parse_temperature(temp)?,
+                _ => heat_setpoint = parse_temperature(temp)?,
+            },
+            Some(("heat_setpoint", temp)) => heat_setpoint = parse_temperature(temp)?,
+            Some(("cool_setpoint", temp)) => cool_setpoint = parse_temperature(temp)?,
+            _ => {
+                warn!("Unrecognized command: {}", command);
+                return Ok(());
+            }
+        }
+        self.send(ActionHvac {
+            client_command_id: Default::default(),
+            device_table_id,
+            device_id,
+            command: hvac_command,
+            low_trip_temperature: heat_setpoint,
+            high_trip_temperature: cool_setpoint,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Maps a `<command>[:<code>]` lock command to the relay state, the code must match when one is configured
+    fn door_lock_action(command: &str, expected_code: Option<&str>) -> Result<Option<OnOff>> {
+        let (command, code) = match command.split_once(':') {
+            Some((command, code)) => (command, Some(code)),
+            None => (command, None),
+        };
+        if let Some(expected) = expected_code {
+            if code != Some(expected) {
+                return Err(AppError::Generic(
+                    "Incorrect code for door lock command".into(),
+                ));
+            }
+        }
+        Ok(match command {
+            "lock" => Some(OnOff::On),
+            "unlock" => Some(OnOff::Off),
+            _ => None,
+        })
+    }
+
+    async fn run_door_lock_command(
+        &self,
+        device_table_id: u8,
+        device_id: u8,
+        command: &str,
+    ) -> Result<()> {
+        let device_state = match Self::door_lock_action(command, config::DOOR_LOCK_CODE.as_deref())?
+        {
+            Some(device_state) => device_state,
+            None => {
+                warn!("Unrecognized command: {}", command);
+                return Ok(());
+            }
+        };
+        self.send(ActionSwitch {
+            client_command_id: Default::default(),
+            device_table_id,
+            device_state,
+            first_device_id: device_id,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn run_accessory_gateway_command(
+        &self,
+        device_table_id: u8,
+        device_id: u8,
+        command: &str,
+    ) -> Result<()> {
+        self.send(Self::accessory_gateway_action(
+            device_table_id,
+            device_id,
+            command,
+        )?)
+        .await?;
+        Ok(())
+    }
+
+    /// Devices behind an accessory gateway only understand the gateway's generic commands
+    fn accessory_gateway_action(
+        device_table_id: u8,
+        device_id: u8,
+        command: &str,
+    ) -> Result<ActionAccessoryGateway> {
+        let device_command: AccessoryGatewayCommand = command.parse()?;
+        Ok(ActionAccessoryGateway {
+            client_command_id: Default::default(),
+            device_table_id,
+            device_id,
+            device_command: device_command.into(),
+        })
+    }
+
+    /// Console buttons are momentary, so a press is the button followed by releasing all buttons again.
+    /// `None` for leveler types we can't send buttons to
+    fn leveler_button_presses(
+        device_type: DeviceType,
+        device_table_id: u8,
+        device_id: u8,
+        mode: LevelerMode,
+        screen: u8,
+        button: LevelerButton,
+    ) -> Option<Vec<LevelerButtonPress>> {
+        let press = |buttons: u8| match device_type {
+            DeviceType::LevelerType1 => Some(LevelerButtonPress::Type1(Leveler1ButtonCommand {
+                client_command_id: Default::default(),
+                device_table_id,
+                device_id,
+                button_state_1: buttons,
+                button_state_2: 0,
+            })),
+            DeviceType::LevelerType3 => Some(LevelerButtonPress::Type3(Leveler3ButtonCommand {
+                client_command_id: Default::default(),
+                device_table_id,
+                device_id,
+                screen_enum: screen,
+                button_state_1: buttons,
+                button_state_2: 0,
+            })),
+            DeviceType::LevelerType4 => Some(LevelerButtonPress::Type4(Leveler4ButtonCommand {
+                client_command_id: Default::default(),
+                device_table_id,
+                device_id,
+                device_mode: mode.into(),
+                ui_mode: screen,
+                ui_button_data_1: buttons,
+                ui_button_data_2: 0,
+                ui_button_data_3: 0,
+            })),
+            _ => None,
+        };
+        [button.into(), 0u8].into_iter().map(press).collect()
+    }
+
+    async fn run_leveler_command(
+        &self,
+        device: &DeviceEntry,
+        device_table_id: u8,
+        device_id: u8,
+        command: &str,
+    ) -> Result<()> {
+        let button: LevelerButton = command.parse()?;
+        let (mode, screen) = match device.state.load(Ordering::Relaxed) {
+            DeviceState::Leveler { mode, screen, .. } => (mode, screen),
+            _ => Default::default(),
+        };
+        let device_type = device.entity.device_type.load(Ordering::Relaxed);
+        let presses = match Self::leveler_button_presses(
+            device_type,
+            device_table_id,
+            device_id,
+            mode,
+            screen,
+            button,
+        ) {
+            Some(presses) => presses,
+            None => {
+                warn!("Leveler buttons are not supported for {}", device_type);
+                return Ok(());
+            }
+        };
+        for press in presses {
+            match press {
+                LevelerButtonPress::Type1(cmd) => {
+                    self.send(cmd).await?;
+                }
+                LevelerButtonPress::Type3(cmd) => {
+                    self.send(cmd).await?;
+                }
+                LevelerButtonPress::Type4(cmd) => {
+                    self.send(cmd).await?;
+                }
+            }
+            sleep(Duration::from_millis(250)).await;
+        }
+        Ok(())
+    }
+
+    /// Maps the generator switch commands to a Generator Genie start or stop
+    fn generator_command(command: &str) -> Option<GeneratorGenieCommand> {
+        match command {
+            "on" => Some(GeneratorGenieCommand::Start),
+            "off" => Some(GeneratorGenieCommand::Stop),
+            _ => None,
+        }
+    }
+
+    async fn run_generator_command(
+        &self,
+        device_table_id: u8,
+        device_id: u8,
+        command: &str,
+    ) -> Result<()> {
+        let device_command = match Self::generator_command(command) {
+            Some(device_command) => device_command,
+            None => {
+                warn!("Unrecognized command: {}", command);
+                return Ok(());
+            }
+        };
+        self.send(ActionGeneratorGenie {
+            client_command_id: Default::default(),
+            device_table_id,
+            device_id,
+            device_command,
+        })
+        .await?;
+        Ok(())
+    }
+
     pub async fn set_mqtt_manager(&self, mqtt: MqttManager) {
         *self.mqtt.write().await = Some(mqtt);
     }
 
-    async fn get_mqtt(&self) -> MqttManager {
-        self.mqtt.read().await.clone().unwrap()
+    /// The MQTT manager, absent when running one-shot CLI commands
+    async fn get_mqtt(&self) -> Option<MqttManager> {
+        self.mqtt.read().await.clone()
     }
 
     /// Start the main loop to process incoming commands from the device
     pub async fn start(&self) -> Result<()> {
         tokio::task::spawn(self.clone().run_loop());
-        tokio::task::spawn(self.clone().run_timers());
+        // The background tasks only exist to feed MQTT, without it just track the devices
+        if self.get_mqtt().await.is_some() {
+            tokio::task::spawn(self.clone().run_timers());
+            tokio::task::spawn(self.clone().run_dtc_poll());
+        }
         Ok(())
     }
 
@@ -225,6 +1129,15 @@ impl RVLink {
         if self.has_battery().await {
             result.push(self.battery.clone())
         }
+        if self.chassis.state.load(Ordering::Relaxed) != DeviceState::Unknown {
+            result.push(self.chassis.clone())
+        }
+        if self.clock.state.load(Ordering::Relaxed) != DeviceState::Unknown {
+            result.push(self.clock.clone())
+        }
+        if self.outdoor_temperature.state.load(Ordering::Relaxed) != DeviceState::Unknown {
+            result.push(self.outdoor_temperature.clone())
+        }
         for table in self.get_device_tables().await? {
             for device in table.devices.iter() {
                 result.push(device.val().clone());
@@ -432,6 +1345,153 @@ impl RVLink {
         }
     }
 
+    /// Background task polling every online device for diagnostic trouble codes
+    async fn run_dtc_poll(self) {
+        let mut t = interval(Duration::from_secs(300));
+        loop {
+            t.tick().await;
+            // Bounded well inside the poll interval so a slow bus can't make poll cycles pile up
+            match timeout(Duration::from_secs(120), self.poll_all_dtcs()).await {
+                Ok(Ok(_)) => debug!("DTC poll completed"),
+                Ok(Err(e)) => warn!("Error while polling DTCs! {:?}", e),
+                Err(_) => warn!("DTC poll did not complete in time, skipped the remaining devices"),
+            };
+        }
+    }
+
+    async fn poll_all_dtcs(&self) -> Result<()> {
+        let mut devices = vec![];
+        for table in self.get_device_tables().await? {
+            for device in table.devices.iter() {
+                let entity = &device.val().entity;
+                if entity.device_is_ready().await && !entity.offline.load(Ordering::Relaxed) {
+                    devices.push(device.val().clone());
+                }
+            }
+        }
+        // Keep a few requests in flight so one unresponsive device doesn't hold up the rest
+        futures::stream::iter(devices)
+            .for_each_concurrent(4, |device| async move {
+                if let Err(e) = self.poll_device_dtcs(&device).await {
+                    debug!("Could not read DTCs for {:?}: {:?}", device.entity, e);
+                }
+            })
+            .await;
+        Ok(())
+    }
+
+    /// Read the trouble codes stored on a device and publish them as attributes
+    pub async fn poll_device_dtcs(&self, entry: &DeviceEntry) -> Result<Vec<DtcValue>> {
+        let (device_table_id, device_id) = entry
+            .entity
+            .get_device_address()
+            .await
+            .ok_or_else(|| AppError::Generic("Device has no CAN address".into()))?;
+        let responses = self
+            .send(GetProductDtcValues {
+                client_command_id: Default::default(),
+                device_table_id,
+                device_id,
+                option: 0,
+                start_dtc: 0,
+                end_dtc: 0xFFFF,
+            })
+            .await?;
+        let mut dtcs = vec![];
+        for response in responses {
+            if let GetProductDtcValuesResponse::Success(data) = response {
+                dtcs.extend(
+                    data.dtcs
+                        .into_iter()
+                        .filter(|dtc| dtc.count > 0 || dtc.is_active()),
+                );
+            }
+        }
+        let previous = entry
+            .entity
+            .attributes
+            .get("active_dtcs")
+            .map(|v| v.val().clone());
+        let dtc_name = |dtc: &DtcValue| match dtc.dtc_id() {
+            Some(id) => id.to_string(),
+            None => format!("{:#06x}", dtc.dtc),
+        };
+        let active: Vec<&DtcValue> = dtcs.iter().filter(|dtc| dtc.is_active()).collect();
+        for dtc in active.iter() {
+            let known = previous
+                .as_ref()
+                .map(|p| p.split(", ").any(|v| v == dtc_name(dtc)))
+                .unwrap_or_default();
+            if !known {
+                Self::log_dtc(entry, dtc.dtc_id(), dtc.dtc);
+            }
+        }
+        let listing: Vec<String> = dtcs
+            .iter()
+            .map(|dtc| {
+                let state = if dtc.is_active() { "active" } else { "stored" };
+                format!(
+                    "{}: {}, {}x",
+                    Self::describe_dtc(dtc.dtc_id(), dtc.dtc),
+                    state,
+                    dtc.count
+                )
+            })
+            .collect();
+        self.set_device_attributes(
+            entry,
+            &[
+                ("dtcs", listing.join("; ")),
+                (
+                    "active_dtcs",
+                    active
+                        .iter()
+                        .map(|dtc| dtc_name(dtc))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+            ],
+        )
+        .await;
+        self.update_dtc_problem(entry).await;
+        Ok(dtcs)
+    }
+
+    /// Readable form of a trouble code, codes missing from the catalog only have their number
+    fn describe_dtc(id: Option<DTCID>, code: u16) -> String {
+        match id {
+            Some(id) => format!("{} ({} {})", id.description(), id.category(), id.severity()),
+            None => format!("Unknown DTC {:#06x}", code),
+        }
+    }
+
+    fn log_dtc(entry: &DeviceEntry, id: Option<DTCID>, code: u16) {
+        let name = entry.entity.display_name();
+        let description = Self::describe_dtc(id, code);
+        match id.map(|id| id.severity()) {
+            Some(DtcSeverity::Info) => info!("{} reported DTC {}", name, description),
+            _ => warn!("{} reported DTC {}", name, description),
+        }
+    }
+
+    /// The problem sensor is on while the device has an active DTC or its relay reports one
+    async fn update_dtc_problem(&self, entry: &DeviceEntry) {
+        let has_value = |key: &str| {
+            entry
+                .entity
+                .attributes
+                .get(key)
+                .map(|v| !v.val().is_empty())
+                .unwrap_or_default()
+        };
+        let problem = has_value("active_dtcs") || has_value("relay_dtc");
+        self.set_device_attributes(
+            entry,
+            &[("dtc_problem", DeviceState::on_off_str(problem).into())],
+        )
+        .await;
+    }
+
     /// This is the primary run loop for the rvlink manager
     async fn run_loop(self) {
         loop {
@@ -442,13 +1502,74 @@ impl RVLink {
                         self.handle_gateway_information(evt).await
                     }
                     Ok(Event::TankSensorStatus(evt)) => self.handle_tank_status_update(evt).await,
+                    Ok(Event::RelayBasicLatchingStatusType1(evt)) => {
+                        self.handle_relay_status(evt.device_table_id, &evt.relays)
+                            .await
+                    }
                     Ok(Event::RelayBasicLatchingStatusType2(evt)) => {
-                        self.handle_relay_type_2_status(evt).await
+                        self.handle_relay_status(evt.device_table_id, &evt.relays)
+                            .await
+                    }
+                    Ok(Event::RelayHBridgeMomentaryStatusType1(evt)) => {
+                        self.handle_hbridge_status(evt.device_table_id, &evt.relays)
+                            .await
+                    }
+                    Ok(Event::RelayHBridgeMomentaryStatusType2(evt)) => {
+                        self.handle_hbridge_status(evt.device_table_id, &evt.relays)
+                            .await
                     }
                     Ok(Event::RvStatus(evt)) => self.handle_rvstatus(evt).await,
-                    Ok(Event::RealTimeClock(_))
-                    | Ok(Event::DeviceSessionStatus(_))
-                    | Ok(Event::DeviceOnlineStatus(_)) => { /* Irrelevant for now */ }
+                    Ok(Event::DeviceLockStatus(evt)) => self.handle_device_lock_status(evt).await,
+                    Ok(Event::DimmableLightStatus(evt)) => {
+                        self.handle_dimmable_light_status(evt).await
+                    }
+                    Ok(Event::RgbLightStatus(evt)) => self.handle_rgb_light_status(evt).await,
+                    Ok(Event::HvacStatus(evt)) => self.handle_hvac_status(evt).await,
+                    Ok(Event::DoorLockStatus(evt)) => self.handle_door_lock_status(evt).await,
+                    Ok(Event::AwningSensorStatus(evt)) => {
+                        self.handle_awning_sensor_status(evt).await
+                    }
+                    Ok(Event::Leveler1DeviceStatus(evt)) => {
+                        self.handle_leveler_status(evt.device_table_id, &evt.levelers)
+                            .await
+                    }
+                    Ok(Event::Leveler3DeviceStatus(evt)) => {
+                        self.handle_leveler_status(evt.device_table_id, &evt.levelers)
+                            .await
+                    }
+                    Ok(Event::Leveler4DeviceStatus(evt)) => {
+                        self.handle_leveler_status(evt.device_table_id, &evt.levelers)
+                            .await
+                    }
+                    Ok(Event::LevelerConsoleText(evt)) => {
+                        self.handle_leveler_console_text(evt).await
+                    }
+                    Ok(Event::BrakingSystemStatus(evt)) => {
+                        self.handle_braking_system_status(evt).await
+                    }
+                    Ok(Event::BatteryMonitorStatus(evt)) => {
+                        self.handle_battery_monitor_status(evt).await
+                    }
+                    Ok(Event::TemperatureSensorStatus(evt)) => {
+                        self.handle_temperature_sensor_status(evt).await
+                    }
+                    Ok(Event::CloudGatewayStatus(evt)) => {
+                        self.handle_cloud_gateway_status(evt).await
+                    }
+                    Ok(Event::JaycoTbbStatus(evt)) => self.handle_jayco_tbb_status(evt).await,
+                    Ok(Event::MonitorPanelStatus(evt)) => {
+                        self.handle_monitor_panel_status(evt).await
+                    }
+                    Ok(Event::AccessoryGatewayStatus(evt)) => {
+                        self.handle_accessory_gateway_status(evt).await
+                    }
+                    Ok(Event::GeneratorGenieStatus(evt)) => self.handle_generator_status(evt).await,
+                    Ok(Event::HourMeterStatus(evt)) => self.handle_hour_meter_status(evt).await,
+                    Ok(Event::DeviceOnlineStatus(evt)) => {
+                        self.handle_device_online_status(evt).await
+                    }
+                    Ok(Event::RealTimeClock(evt)) => self.handle_real_time_clock(evt).await,
+                    Ok(Event::DeviceSessionStatus(_)) => { /* Irrelevant for now */ }
                     Ok(other) => info!("Received unhandled event: {:?}", other),
                     Err(e) => warn!("Failed to parse payload from bluetooth! {:?}", e),
                 },
@@ -485,6 +1606,33 @@ impl RVLink {
         }
     }
 
+    async fn handle_device_online_status(&self, status: DeviceOnlineStatus) {
+        let table = match self.device_tables.get(&status.device_table_id) {
+            Some(dt) => dt.val().clone(),
+            None => return,
+        };
+        for device in table.devices.iter() {
+            let device_id = *device.key();
+            if device_id >= status.device_count {
+                continue;
+            }
+            let online = match status.online_status.get_flag(device_id as usize) {
+                Ok(online) => online,
+                Err(_) => continue,
+            };
+            let entry = device.val();
+            let was_offline = entry.entity.offline.swap(!online, Ordering::Relaxed);
+            if was_offline == online && entry.entity.device_is_ready().await {
+                debug!(
+                    "Device {} is now {}",
+                    entry.entity.display_name(),
+                    if online { "online" } else { "offline" }
+                );
+                self.publish_device_availability(entry.entity.clone()).await;
+            }
+        }
+    }
+
     async fn handle_tank_status_update(&self, tank_status: TankSensorStatus) {
         let table_id = tank_status.device_table_id;
         for status in tank_status.tank_statuses.iter() {
@@ -498,29 +1646,834 @@ impl RVLink {
         }
     }
 
-    async fn handle_relay_type_2_status(&self, status: RelayBasicLatchingStatusType2) {
+    async fn handle_monitor_panel_status(&self, status: MonitorPanelStatus) {
         let table_id = status.device_table_id;
-        for relay in status.relays.iter() {
-            self.set_device_state(
-                table_id,
-                relay.device_id,
-                DeviceState::Switch(relay.on_off()),
+        for device in status.devices.iter() {
+            let (_, device_entry) = self.get_or_add_device(table_id, device.device_id).await;
+            let state = Self::monitor_panel_state(device);
+            self.set_device_attributes(
+                &device_entry,
+                &[("monitor_panel_control", device.control_type().to_string())],
             )
-            .await
-            .unwrap_or_default();
+            .await;
+            self.set_device_state(table_id, device.device_id, state)
+                .await
+                .unwrap_or_default();
         }
     }
 
-    async fn handle_rvstatus(&self, status: RvStatus) {
-        let bv = status.battery_voltage();
-        if bv.is_some() {
-            let newstate = DeviceState::Voltage(bv.unwrap());
-            self.battery.state.store(newstate, Ordering::Relaxed);
-            self.publish_device_state(&self.battery).await;
+    /// Tank channels report a level, everything else is a switch
+    fn monitor_panel_state(device: &MonitorPanelDeviceState) -> DeviceState {
+        if device.is_tank() {
+            DeviceState::Percentage(device.value)
+        } else if device.is_on() {
+            DeviceState::Switch(OnOff::On)
+        } else {
+            DeviceState::Switch(OnOff::Off)
         }
     }
 
-    pub async fn has_battery(&self) -> bool {
+    async fn handle_accessory_gateway_status(&self, status: AccessoryGatewayStatus) {
+        let table_id = status.device_table_id;
+        for device in status.devices.iter() {
+            let (_, device_entry) = self.get_or_add_device(table_id, device.device_id).await;
+            device_entry
+                .entity
+                .via_accessory_gateway
+                .store(true, Ordering::Relaxed);
+            let was_offline = device_entry
+                .entity
+                .offline
+                .swap(!device.is_online(), Ordering::Relaxed);
+            if was_offline == device.is_online() && device_entry.entity.device_is_ready().await {
+                self.publish_device_availability(device_entry.entity.clone())
+                    .await;
+            }
+            let state =
+                Self::accessory_gateway_state(device_entry.entity.device_entity_type(), device);
+            self.set_device_state(table_id, device.device_id, state)
+                .await
+                .unwrap_or_default();
+        }
+    }
+
+    /// Tanks behind the gateway report their level as the value, everything else is a switch
+    fn accessory_gateway_state(
+        entity_type: DeviceEntityType,
+        device: &AccessoryGatewayDeviceState,
+    ) -> DeviceState {
+        match (entity_type, device.value()) {
+            (
+                DeviceEntityType::FreshTank
+                | DeviceEntityType::GreyTank
+                | DeviceEntityType::BlackTank
+                | DeviceEntityType::FuelTank
+                | DeviceEntityType::LPTank,
+                Some(value),
+            ) => DeviceState::Percentage(value),
+            _ if device.is_on() => DeviceState::Switch(OnOff::On),
+            _ => DeviceState::Switch(OnOff::Off),
+        }
+    }
+
+    async fn handle_relay_status<R: RelayState>(&self, table_id: u8, relays: &[R]) {
+        for relay in relays.iter() {
+            let (_, device_entry) = self.get_or_add_device(table_id, relay.device_id()).await;
+            // Door locks are driven by a latching relay, report it as a lock state instead
+            let state = match device_entry.entity.device_entity_type() {
+                DeviceEntityType::DoorLock if relay.is_on() => DeviceState::Lock(LockState::Locked),
+                DeviceEntityType::DoorLock => DeviceState::Lock(LockState::Unlocked),
+                _ => DeviceState::Switch(relay.on_off()),
+            };
+            self.set_relay_dtc(&device_entry, relay).await;
+            self.set_device_state(table_id, relay.device_id(), state)
+                .await
+                .unwrap_or_default();
+        }
+    }
+
+    /// Relays report their current trouble code with every status frame
+    async fn set_relay_dtc<R: RelayState>(&self, entry: &DeviceEntry, relay: &R) {
+        let dtc = relay
+            .dtc()
+            .map(|dtc| Self::describe_dtc(Some(dtc), dtc.into()))
+            .unwrap_or_default();
+        let previous = entry
+            .entity
+            .attributes
+            .get("relay_dtc")
+            .map(|v| v.val().clone());
+        if !dtc.is_empty() && previous.as_deref() != Some(dtc.as_str()) {
+            if let Some(id) = relay.dtc() {
+                Self::log_dtc(entry, Some(id), id.into());
+            }
+        }
+        self.set_device_attributes(entry, &[("relay_dtc", dtc)])
+            .await;
+        self.update_dtc_problem(entry).await;
+    }
+
+    async fn handle_door_lock_status(&self, status: DoorLockStatus) {
+        let table_id = status.device_table_id;
+        for lock in status.locks.iter() {
+            self.set_device_state(table_id, lock.device_id, DeviceState::Lock(lock.state()))
+                .await
+                .unwrap_or_default();
+        }
+    }
+
+    async fn handle_hbridge_status<R: RelayState>(&self, table_id: u8, relays: &[R]) {
+        for relay in relays.iter() {
+            let state = CoverState::from_relay(relay);
+            let (_, device_entry) = self.get_or_add_device(table_id, relay.device_id()).await;
+            self.set_device_attributes(
+                &device_entry,
+                &[
+                    ("open_allowed", relay.is_forward_allowed().to_string()),
+                    ("close_allowed", relay.is_reverse_allowed().to_string()),
+                ],
+            )
+            .await;
+            self.set_relay_dtc(&device_entry, relay).await;
+            self.set_device_state(table_id, relay.device_id(), DeviceState::Cover(state))
+                .await
+                .unwrap_or_default();
+        }
+    }
+
+    async fn handle_dimmable_light_status(&self, status: DimmableLightStatus) {
+        let table_id = status.device_table_id;
+        for light in status.lights.iter() {
+            self.set_device_state(
+                table_id,
+                light.device_id,
+                DeviceState::Dimmable {
+                    state: light.on_off(),
+                    brightness: light.brightness,
+                },
+            )
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    async fn handle_rgb_light_status(&self, status: RgbLightStatus) {
+        let table_id = status.device_table_id;
+        for light in status.lights.iter() {
+            self.set_device_state(
+                table_id,
+                light.device_id,
+                DeviceState::Rgb {
+                    mode: light.mode(),
+                    red: light.red,
+                    green: light.green,
+                    blue: light.blue,
+                },
+            )
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    async fn handle_hvac_status(&self, status: HvacStatus) {
+        let table_id = status.device_table_id;
+        for zone in status.zones.iter() {
+            self.set_device_state(
+                table_id,
+                zone.device_id,
+                DeviceState::Hvac {
+                    command: zone.command,
+                    heat_setpoint: zone.low_trip_temperature,
+                    cool_setpoint: zone.high_trip_temperature,
+                    zone_status: zone.zone_status(),
+                    indoor_temperature: zone.indoor_temperature(),
+                    outdoor_temperature: zone.outdoor_temperature(),
+                },
+            )
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    async fn handle_generator_status(&self, status: GeneratorGenieStatus) {
+        let table_id = status.device_table_id;
+        for generator in status.generators.iter() {
+            self.set_device_state(
+                table_id,
+                generator.device_id,
+                DeviceState::Generator {
+                    state: generator.state(),
+                    battery_voltage: generator.battery_voltage,
+                    temperature: generator.temperature(),
+                    operating_seconds: generator.operating_seconds,
+                },
+            )
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    async fn handle_hour_meter_status(&self, status: HourMeterStatus) {
+        let table_id = status.device_table_id;
+        for meter in status.meters.iter() {
+            let (_, device_entry) = self.get_or_add_device(table_id, meter.device_id).await;
+            self.set_device_state(
+                table_id,
+                meter.device_id,
+                DeviceState::HourMeter {
+                    operating_seconds: meter.operating_seconds,
+                    running: meter.is_running(),
+                    maintenance_due: meter.is_maintenance_due(),
+                    maintenance_past_due: meter.is_maintenance_past_due(),
+                    error: meter.has_error(),
+                    service_interval: device_entry
+                        .entity
+                        .service_interval(&config::HOUR_METER_SERVICE_INTERVALS),
+                },
+            )
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    async fn handle_rvstatus(&self, status: RvStatus) {
+        let bv = status.battery_voltage();
+        if bv.is_some() {
+            let newstate = DeviceState::Voltage(bv.unwrap());
+            self.battery.state.store(newstate, Ordering::Relaxed);
+            self.publish_device_state(&self.battery).await;
+        }
+        if let Some(temperature) = status.external_temperature() {
+            let newstate = DeviceState::Temperature(temperature);
+            self.outdoor_temperature
+                .state
+                .store(newstate, Ordering::Relaxed);
+            self.publish_device_state(&self.outdoor_temperature).await;
+        }
+    }
+
+    async fn handle_device_lock_status(&self, status: DeviceLockStatus) {
+        let newstate = DeviceState::Chassis {
+            lockout_level: status.system_lockout_level,
+            park_brake: status.get_park_brake_engaged(),
+            ignition: status.get_ignition_on(),
+            towable_battery_voltage: status.get_battery_voltage(),
+            towable_brake_voltage: status.get_brake_voltage(),
+        };
+        self.chassis.state.store(newstate, Ordering::Relaxed);
+        self.publish_device_state(&self.chassis).await;
+
+        let table = match self.device_tables.get(&status.device_table_id) {
+            Some(dt) => dt.val().clone(),
+            None => return,
+        };
+        for device in table.devices.iter() {
+            let device_id = *device.key();
+            if device_id >= status.device_count {
+                continue;
+            }
+            if let Ok(locked_out) = status.lockout_status.get_flag(device_id as usize) {
+                self.set_device_attributes(device.val(), &[("locked_out", locked_out.to_string())])
+                    .await;
+            }
+        }
+    }
+
+    async fn handle_awning_sensor_status(&self, status: AwningSensorStatus) {
+        let table_id = status.device_table_id;
+        for sensor in status.sensors.iter() {
+            let (_, device_entry) = self.get_or_add_device(table_id, sensor.device_id).await;
+            let previous = device_entry.state.load(Ordering::Relaxed);
+            self.set_device_state(
+                table_id,
+                sensor.device_id,
+                DeviceState::AwningSensor {
+                    wind_speed: sensor.wind_speed(),
+                    vibration: sensor.vibration(),
+                    wind_alarm: sensor.is_wind_alarm(),
+                    vibration_alarm: sensor.is_vibration_alarm(),
+                },
+            )
+            .await
+            .unwrap_or_default();
+
+            if let Some(reason) =
+                Self::awning_retract_reason(previous, sensor, *config::AWNING_AUTO_RETRACT)
+            {
+                let zelf = self.clone();
+                let sensor_name = device_entry.entity.display_name();
+                tokio::task::spawn(async move {
+                    if let Err(e) = zelf.retract_awnings(&sensor_name, reason).await {
+                        warn!("Failed to retract awnings! {:?}", e);
+                    }
+                });
+            }
+        }
+    }
+
+    /// The alarm to retract the awnings for, only once when a sensor raises it and auto retract is enabled
+    fn awning_retract_reason(
+        previous: DeviceState,
+        sensor: &AwningSensorState,
+        auto_retract: bool,
+    ) -> Option<&'static str> {
+        let was_alarmed = match previous {
+            DeviceState::AwningSensor {
+                wind_alarm,
+                vibration_alarm,
+                ..
+            } => wind_alarm || vibration_alarm,
+            _ => false,
+        };
+        if !auto_retract || was_alarmed {
+            None
+        } else if sensor.is_wind_alarm() {
+            Some("wind")
+        } else if sensor.is_vibration_alarm() {
+            Some("vibration")
+        } else {
+            None
+        }
+    }
+
+    /// Retract every awning we know about and let MQTT subscribers know about it
+    async fn retract_awnings(&self, sensor_name: &str, reason: &str) -> Result<()> {
+        warn!(
+            "{} reported a {} alarm, retracting all awnings",
+            sensor_name, reason
+        );
+        let mut retracted = vec![];
+        for device in self.get_devices().await? {
+            if device.entity.device_entity_type() != DeviceEntityType::Awning {
+                continue;
+            }
+            if let Some((device_table_id, device_id)) = device.entity.get_device_address().await {
+                match self
+                    .send(ActionMovement {
+                        client_command_id: Default::default(),
+                        device_table_id,
+                        device_id,
+                        device_state: RelayDirection::Close,
+                    })
+                    .await
+                {
+                    Ok(_) => retracted.push(device.entity.display_name()),
+                    Err(e) => warn!(
+                        "Failed to retract {}: {:?}",
+                        device.entity.display_name(),
+                        e
+                    ),
+                }
+            }
+        }
+        match self.get_mqtt().await {
+            Some(mqtt) => {
+                mqtt.publish_event(&serde_json::json!({
+                    "event": "awning_auto_retract",
+                    "sensor": sensor_name,
+                    "reason": reason,
+                    "awnings": retracted,
+                }))
+                .await
+            }
+            None => Ok(()),
+        }
+    }
+
+    async fn handle_leveler_status<L: LevelerStatus>(&self, table_id: u8, levelers: &[L]) {
+        for leveler in levelers.iter() {
+            self.set_device_state(
+                table_id,
+                leveler.device_id(),
+                DeviceState::Leveler {
+                    mode: leveler.mode(),
+                    screen: leveler.screen(),
+                    level: leveler.is_level(),
+                    jacks_extended: leveler.jacks_extended(),
+                    excess_angle: leveler.is_excess_angle(),
+                    fault: leveler.has_fault(),
+                },
+            )
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    async fn handle_leveler_console_text(&self, text: LevelerConsoleText) {
+        let table = match self.device_tables.get(&text.device_table_id) {
+            Some(dt) => dt.val().clone(),
+            None => return,
+        };
+        let console_text = Self::leveler_console_text(&text.console_text);
+        for device in table.devices.iter() {
+            if device.val().entity.device_entity_type() == DeviceEntityType::Leveler {
+                self.set_device_attributes(device.val(), &[("console_text", console_text.clone())])
+                    .await;
+            }
+        }
+    }
+
+    /// Console lines are NUL separated, HA sensor states are limited to 255 characters
+    fn leveler_console_text(raw: &[u8]) -> String {
+        String::from_utf8_lossy(raw)
+            .split('\0')
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+            .chars()
+            .take(255)
+            .collect()
+    }
+
+    async fn handle_braking_system_status(&self, status: BrakingSystemStatus) {
+        let table_id = status.device_table_id;
+        for brake in status.brakes.iter() {
+            self.set_device_state(
+                table_id,
+                brake.device_id,
+                DeviceState::Brakes {
+                    output_level: brake.output_level,
+                    gain: brake.gain(),
+                    trailer_connected: brake.is_trailer_connected(),
+                    braking: brake.is_braking(),
+                    open_circuit: brake.is_open_circuit(),
+                    short_circuit: brake.is_short_circuit(),
+                    overloaded: brake.is_overloaded(),
+                },
+            )
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    async fn handle_battery_monitor_status(&self, status: BatteryMonitorStatus) {
+        let table_id = status.device_table_id;
+        for monitor in status.monitors.iter() {
+            self.set_device_state(
+                table_id,
+                monitor.device_id,
+                DeviceState::BatteryMonitor {
+                    voltage: monitor.voltage,
+                    current: monitor.current(),
+                    state_of_charge: monitor.state_of_charge(),
+                    time_remaining: monitor.time_remaining(),
+                    charging: monitor.is_charging(),
+                },
+            )
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    async fn handle_cloud_gateway_status(&self, status: CloudGatewayStatus) {
+        let table_id = status.device_table_id;
+        for gateway in status.gateways.iter() {
+            self.set_device_state(
+                table_id,
+                gateway.device_id,
+                DeviceState::CloudGateway {
+                    connected: gateway.is_connected(),
+                    registered: gateway.is_registered(),
+                },
+            )
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    async fn handle_jayco_tbb_status(&self, status: JaycoTbbStatus) {
+        let table_id = status.device_table_id;
+        for tbb in status.systems.iter() {
+            self.set_device_state(
+                table_id,
+                tbb.device_id,
+                DeviceState::PowerSystem {
+                    battery_voltage: tbb.battery_voltage,
+                    battery_current: tbb.battery_current(),
+                    solar_current: tbb.solar_current(),
+                    state_of_charge: tbb.state_of_charge(),
+                    mains_voltage: tbb.mains_voltage(),
+                    mains_connected: tbb.is_mains_connected(),
+                    solar_charging: tbb.is_solar_charging(),
+                },
+            )
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    async fn handle_temperature_sensor_status(&self, status: TemperatureSensorStatus) {
+        let table_id = status.device_table_id;
+        for sensor in status.sensors.iter() {
+            self.set_device_state(
+                table_id,
+                sensor.device_id,
+                DeviceState::TemperatureSensor {
+                    temperature: sensor.temperature(),
+                    low_alarm: sensor.is_low_alarm(),
+                    high_alarm: sensor.is_high_alarm(),
+                },
+            )
+            .await
+            .unwrap_or_default();
+        }
+    }
+
+    async fn handle_real_time_clock(&self, rtc: RealTimeClock) {
+        let now = Local::now();
+        let drift = match Self::clock_drift(rtc.seconds_from_epoch, &now) {
+            Some(drift) => drift,
+            None => {
+                warn!(
+                    "Gateway clock reads a time that doesn't exist locally: {}",
+                    rtc.seconds_from_epoch
+                );
+                return;
+            }
+        };
+        let host_time = now.timestamp();
+        self.clock.state.store(
+            DeviceState::Clock {
+                seconds_from_epoch: rtc.seconds_from_epoch,
+                drift,
+            },
+            Ordering::Relaxed,
+        );
+        self.publish_device_state(&self.clock).await;
+
+        let last_sync = self.last_clock_sync.load(Ordering::Relaxed);
+        if Self::clock_needs_sync(
+            drift,
+            *config::CLOCK_SYNC_THRESHOLD,
+            host_time as u64,
+            last_sync,
+        ) {
+            info!(
+                "Gateway clock is off by {}s, setting it to host time",
+                drift
+            );
+            self.last_clock_sync
+                .store(host_time as u64, Ordering::Relaxed);
+            let zelf = self.clone();
+            tokio::task::spawn(async move {
+                if let Err(e) = zelf.sync_clock().await {
+                    warn!("Failed to set gateway clock! {:?}", e);
+                }
+            });
+        }
+    }
+
+    /// Seconds the gateway clock is ahead of `now`. The gateway keeps wall clock time without a
+    /// timezone and is set from this host's local time, so its reading is placed in the timezone
+    /// of `now` before comparing the two instants
+    fn clock_drift<Tz: TimeZone>(seconds_from_epoch: u32, now: &DateTime<Tz>) -> Option<i64> {
+        let wall_time = NaiveDateTime::from_timestamp_opt(seconds_from_epoch as i64, 0)?;
+        let gateway_time = now.timezone().from_local_datetime(&wall_time).earliest()?;
+        Some(gateway_time.timestamp() - now.timestamp())
+    }
+
+    /// Whether the gateway clock drifted past the sync threshold, if one is configured
+    fn clock_needs_sync(
+        drift: i64,
+        threshold: Option<u32>,
+        host_time: u64,
+        last_sync: u64,
+    ) -> bool {
+        match threshold {
+            // Give the gateway a few minutes to apply the last update before trying again
+            Some(threshold) => {
+                drift.unsigned_abs() > threshold as u64 && host_time > last_sync + 300
+            }
+            None => false,
+        }
+    }
+
+    /// Set the gateway clock to the current local time of this host
+    pub async fn sync_clock(&self) -> Result<()> {
+        let now = Local::now();
+        self.send(SetRealTimeClock {
+            client_command_id: Default::default(),
+            month: now.month() as u8,
+            day: now.day() as u8,
+            year: now.year() as u16,
+            hour: now.hour() as u8,
+            minutes: now.minute() as u8,
+            seconds: now.second() as u8,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Read the raw value of a PID, PIDs that hold a table of values also need the address to read
+    pub async fn read_pid_raw(
+        &self,
+        device_table_id: u8,
+        device_id: u8,
+        pid: ParameterID,
+        address: Option<u16>,
+    ) -> Result<Vec<u8>> {
+        let value = match address {
+            None => self
+                .send(GetDevicePid {
+                    client_command_id: Default::default(),
+                    device_table_id,
+                    device_id,
+                    pid,
+                })
+                .await?
+                .into_iter()
+                .find_map(|rsp| match rsp {
+                    GetDevicePidResponse::Success(rsp) => Some(rsp.value),
+                    GetDevicePidResponse::SuccessComplete(rsp) => Some(rsp.value),
+                    _ => None,
+                }),
+            Some(pid_address) => self
+                .send(GetDevicePidWithAddress {
+                    client_command_id: Default::default(),
+                    device_table_id,
+                    device_id,
+                    pid,
+                    pid_address,
+                })
+                .await?
+                .into_iter()
+                .find_map(|rsp| match rsp {
+                    GetDevicePidWithAddressResponse::Success(rsp) => Some(rsp.value),
+                    GetDevicePidWithAddressResponse::SuccessComplete(rsp) => Some(rsp.value),
+                    _ => None,
+                }),
+        };
+        value.ok_or_else(|| {
+            AppError::Generic(format!(
+                "Device {}:{} did not return a value for {}",
+                device_table_id, device_id, pid
+            ))
+        })
+    }
+
+    /// Write the raw value of a PID, at most 6 bytes (or 4 bytes with an address)
+    pub async fn write_pid_raw(
+        &self,
+        device_table_id: u8,
+        device_id: u8,
+        pid: ParameterID,
+        address: Option<u16>,
+        value: Vec<u8>,
+    ) -> Result<()> {
+        let success = match address {
+            None => self
+                .send(SetDevicePid {
+                    client_command_id: Default::default(),
+                    device_table_id,
+                    device_id,
+                    pid,
+                    session_id: 0,
+                    value,
+                })
+                .await?
+                .iter()
+                .all(|rsp| rsp.success()),
+            Some(pid_address) => self
+                .send(SetDevicePidWithAddress {
+                    client_command_id: Default::default(),
+                    device_table_id,
+                    device_id,
+                    pid,
+                    session_id: 0,
+                    pid_address,
+                    value,
+                })
+                .await?
+                .iter()
+                .all(|rsp| rsp.success()),
+        };
+        if success {
+            Ok(())
+        } else {
+            Err(AppError::Generic(format!(
+                "Device {}:{} rejected the write to {}",
+                device_table_id, device_id, pid
+            )))
+        }
+    }
+
+    /// Read a PID as a specific type, values are big endian and may be sent in fewer bytes than the type holds
+    pub async fn read_pid<T: Encodable + Default>(
+        &self,
+        device_table_id: u8,
+        device_id: u8,
+        pid: ParameterID,
+    ) -> Result<T> {
+        let value = self
+            .read_pid_raw(device_table_id, device_id, pid, None)
+            .await?;
+        T::from_data(&Self::fit_pid_value(&value, T::default().data_size())?)
+    }
+
+    /// Sizes a big endian PID value to `size` bytes, longer values may only drop leading zeros
+    fn fit_pid_value(value: &[u8], size: usize) -> Result<Vec<u8>> {
+        let extra = value.len().saturating_sub(size);
+        if value[..extra].iter().any(|b| *b != 0) {
+            return Err(AppError::IncorrectDataSize);
+        }
+        let mut padded = vec![0; size.saturating_sub(value.len())];
+        padded.extend_from_slice(&value[extra..]);
+        Ok(padded)
+    }
+
+    pub async fn write_pid<T: Encodable>(
+        &self,
+        device_table_id: u8,
+        device_id: u8,
+        pid: ParameterID,
+        value: T,
+    ) -> Result<()> {
+        self.write_pid_raw(device_table_id, device_id, pid, None, value.to_data())
+            .await
+    }
+
+    /// Find a device by unique ID, display name or `<device table>:<device id>` address
+    pub async fn find_device(&self, name: &str) -> Option<Arc<DeviceEntry>> {
+        for device in self.get_devices().await.ok()? {
+            let address = device
+                .entity
+                .get_device_address()
+                .await
+                .map(|(table, id)| format!("{}:{}", table, id));
+            if device.entity.device_is_ready().await
+                && (device.entity.uniq_id() == name
+                    || device.entity.display_name().eq_ignore_ascii_case(name)
+                    || address.as_deref() == Some(name))
+            {
+                return Some(device);
+            }
+        }
+        None
+    }
+
+    /// The PIDs a device supports, only requested from the device the first time
+    pub async fn get_device_pids(&self, entry: &DeviceEntry) -> Result<Vec<DevicePidEntry>> {
+        if !entry.has_cached_pids() {
+            let (device_table_id, device_id) = entry
+                .entity
+                .get_device_address()
+                .await
+                .ok_or_else(|| AppError::Generic("Device has no CAN address".into()))?;
+            let responses = self
+                .send(GetDevicePidList {
+                    client_command_id: Default::default(),
+                    device_table_id,
+                    device_id,
+                    start_index: 0,
+                    end_index: 0xFFFF,
+                })
+                .await?;
+            for response in responses.iter() {
+                entry.cache_pid_list(response);
+            }
+        }
+        Ok(entry.cached_pids())
+    }
+
+    /// Read every readable PID on a device, for troubleshooting
+    pub async fn dump_device_pids(&self, entry: &DeviceEntry) -> Result<serde_json::Value> {
+        let (device_table_id, device_id) = entry
+            .entity
+            .get_device_address()
+            .await
+            .ok_or_else(|| AppError::Generic("Device has no CAN address".into()))?;
+        let mut result = serde_json::Map::new();
+        for entry in self.get_device_pids(entry).await? {
+            let pid = match entry.parameter_id() {
+                Some(pid) if entry.is_readable() => pid,
+                Some(_) => continue,
+                None => {
+                    debug!("Skipping unknown PID {:#06x}", entry.pid);
+                    continue;
+                }
+            };
+            let value = match self.read_pid_value(device_table_id, device_id, pid).await {
+                Ok(value) => serde_json::json!({
+                    "value": value.to_string(),
+                    "unit": pid.unit(),
+                    "writable": entry.is_writable(),
+                }),
+                Err(e) => serde_json::json!({ "error": e.to_string() }),
+            };
+            result.insert(pid.to_string(), value);
+        }
+        Ok(serde_json::Value::Object(result))
+    }
+
+    /// Read a PID and decode it according to its metadata
+    pub async fn read_pid_value(
+        &self,
+        device_table_id: u8,
+        device_id: u8,
+        pid: ParameterID,
+    ) -> Result<PidValue> {
+        let value = self
+            .read_pid_raw(device_table_id, device_id, pid, None)
+            .await?;
+        pid.decode(&value)
+    }
+
+    pub async fn write_pid_value(
+        &self,
+        device_table_id: u8,
+        device_id: u8,
+        pid: ParameterID,
+        value: &PidValue,
+    ) -> Result<()> {
+        if !pid.writable() {
+            return Err(AppError::Generic(format!("{} is read only", pid)));
+        }
+        self.write_pid_raw(device_table_id, device_id, pid, None, pid.encode(value)?)
+            .await
+    }
+
+    pub async fn has_battery(&self) -> bool {
         self.battery.state.load(Ordering::Relaxed) != DeviceState::Unknown
     }
 
@@ -576,3 +2529,473 @@ impl RVLink {
         rsp
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    /// Validates the climate state reports the active setpoint and what the zone is doing
+    fn hvac_state() {
+        let state = |heat_mode: HvacHeatMode, zone_status: HvacZoneStatus| {
+            let state = DeviceState::Hvac {
+                command: HvacCommand {
+                    heat_mode,
+                    ..Default::default()
+                },
+                heat_setpoint: 65,
+                cool_setpoint: 74,
+                zone_status,
+                indoor_temperature: Some(FixedI16::<U8>::from_num(72.5)),
+                outdoor_temperature: None,
+            };
+            serde_json::from_str::<serde_json::Value>(&state.state_string()).unwrap()
+        };
+        let cooling = state(HvacHeatMode::Cooling, HvacZoneStatus::Cooling);
+        assert_eq!(cooling["mode"], "cool");
+        assert_eq!(cooling["temperature"], 74);
+        assert_eq!(cooling["action"], "cooling");
+        assert_eq!(cooling["current_temperature"], 72.5);
+        assert!(cooling["outdoor_temperature"].is_null());
+
+        let heating = state(HvacHeatMode::Heating, HvacZoneStatus::GasFurnace);
+        assert_eq!(heating["temperature"], 65);
+        assert_eq!(heating["action"], "heating");
+
+        let both = state(HvacHeatMode::Both, HvacZoneStatus::DeadTime);
+        assert!(both["temperature"].is_null());
+        assert_eq!(both["action"], "idle");
+    }
+
+    #[test]
+    /// Validates the generator switch starts and stops the Generator Genie
+    fn generator_command() -> Result<()> {
+        assert_eq!(
+            RVLink::generator_command("on"),
+            Some(GeneratorGenieCommand::Start)
+        );
+        assert_eq!(
+            RVLink::generator_command("off"),
+            Some(GeneratorGenieCommand::Stop)
+        );
+        assert_eq!(RVLink::generator_command("prime"), None);
+
+        let payload = ActionGeneratorGenie {
+            client_command_id: 1,
+            device_table_id: 1,
+            device_id: 3,
+            device_command: GeneratorGenieCommand::Start,
+        }
+        .to_payload()?;
+        assert_eq!(payload, vec![0, 1, 66, 1, 3, 1]);
+        Ok(())
+    }
+
+    #[test]
+    /// Validates H-bridge relay status bits map to the cover motion states
+    fn hbridge_cover_state() {
+        let relay = |status: u8| RelayStateType2 {
+            device_id: 7,
+            status,
+            ..Default::default()
+        };
+        assert_eq!(CoverState::from_relay(&relay(0xC2)), CoverState::Opening);
+        assert_eq!(CoverState::from_relay(&relay(0xC3)), CoverState::Closing);
+        assert_eq!(CoverState::from_relay(&relay(0xC0)), CoverState::Stopped);
+        assert_eq!(
+            CoverState::from_relay(&RelayStateType1 {
+                device_id: 7,
+                status: 0x43,
+            }),
+            CoverState::Closing
+        );
+        assert_eq!(
+            DeviceState::Cover(CoverState::Opening).state_string(),
+            "opening"
+        );
+    }
+
+    #[test]
+    /// Validates the drift compares the gateway wall clock in the host timezone rather than as UTC
+    fn clock_drift() {
+        let tz = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        let now = tz.timestamp_opt(1_700_000_000, 0).unwrap();
+        let wall_time = now.naive_local().timestamp() as u32;
+        assert_eq!(RVLink::clock_drift(wall_time, &now), Some(0));
+        assert_eq!(RVLink::clock_drift(wall_time + 90, &now), Some(90));
+        assert_eq!(RVLink::clock_drift(wall_time - 30, &now), Some(-30));
+    }
+
+    #[test]
+    /// Validates the gateway clock is only set past the threshold and not while an update is pending
+    fn clock_sync_threshold() {
+        let now = 1_700_000_000u64;
+        assert!(!RVLink::clock_needs_sync(600, None, now, 0));
+        assert!(!RVLink::clock_needs_sync(30, Some(60), now, 0));
+        assert!(RVLink::clock_needs_sync(61, Some(60), now, 0));
+        assert!(RVLink::clock_needs_sync(-61, Some(60), now, 0));
+        assert!(!RVLink::clock_needs_sync(-61, Some(60), now, now - 300));
+        assert!(RVLink::clock_needs_sync(-61, Some(60), now, now - 301));
+    }
+
+    #[test]
+    /// Validates the battery monitor state carries every value its sub entities read
+    fn battery_monitor_state() {
+        let entity = DeviceEntity::default();
+        entity
+            .device_type
+            .store(DeviceType::BatteryMonitor, Ordering::Relaxed);
+        let state = DeviceState::BatteryMonitor {
+            voltage: FixedU16::<U8>::from_num(12.5),
+            current: -10.0,
+            state_of_charge: Some(80),
+            time_remaining: None,
+            charging: false,
+        };
+        let state: serde_json::Value = serde_json::from_str(&state.state_string()).unwrap();
+        assert_eq!(state["state_of_charge"], 80);
+        assert_eq!(state["power"], -125.0);
+        assert_eq!(state["charging"], "off");
+        for sub_entity in entity.sub_entities() {
+            assert!(
+                state.get(sub_entity.key).is_some(),
+                "Missing {}",
+                sub_entity.key
+            );
+        }
+    }
+
+    #[test]
+    /// Validates awnings are only retracted when enabled and an alarm is first raised
+    fn awning_auto_retract() {
+        let sensor = |status: u8| AwningSensorState {
+            device_id: 5,
+            status,
+            wind_speed: 32,
+            vibration: 10,
+        };
+        let alarmed = DeviceState::AwningSensor {
+            wind_speed: Some(32),
+            vibration: Some(10),
+            wind_alarm: true,
+            vibration_alarm: false,
+        };
+        let calm = DeviceState::AwningSensor {
+            wind_speed: Some(8),
+            vibration: Some(10),
+            wind_alarm: false,
+            vibration_alarm: false,
+        };
+        assert_eq!(
+            RVLink::awning_retract_reason(DeviceState::Unknown, &sensor(0x01), true),
+            Some("wind")
+        );
+        assert_eq!(
+            RVLink::awning_retract_reason(calm, &sensor(0x02), true),
+            Some("vibration")
+        );
+        assert_eq!(
+            RVLink::awning_retract_reason(calm, &sensor(0x00), true),
+            None
+        );
+        assert_eq!(
+            RVLink::awning_retract_reason(alarmed, &sensor(0x01), true),
+            None
+        );
+        assert_eq!(
+            RVLink::awning_retract_reason(calm, &sensor(0x01), false),
+            None
+        );
+    }
+
+    #[test]
+    /// Validates brake faults are summarized into a problem flag and a list of active faults
+    fn brakes_state() {
+        let state = |open_circuit: bool, short_circuit: bool, overloaded: bool| {
+            let state = DeviceState::Brakes {
+                output_level: 45,
+                gain: 6.5,
+                trailer_connected: true,
+                braking: true,
+                open_circuit,
+                short_circuit,
+                overloaded,
+            };
+            serde_json::from_str::<serde_json::Value>(&state.state_string()).unwrap()
+        };
+        let healthy = state(false, false, false);
+        assert_eq!(healthy["output_level"], 45);
+        assert_eq!(healthy["gain"], 6.5);
+        assert_eq!(healthy["trailer_connected"], "on");
+        assert_eq!(healthy["fault"], "off");
+        assert_eq!(healthy["faults"], serde_json::json!([]));
+
+        let faulted = state(false, true, true);
+        assert_eq!(faulted["fault"], "on");
+        assert_eq!(
+            faulted["faults"],
+            serde_json::json!(["short_circuit", "overload"])
+        );
+    }
+
+    #[test]
+    /// Validates leveler buttons are pressed and then released in each generation's format
+    fn leveler_button_presses() -> Result<()> {
+        let presses = RVLink::leveler_button_presses(
+            DeviceType::LevelerType4,
+            1,
+            9,
+            LevelerMode::Manual,
+            2,
+            "retract_all".parse()?,
+        )
+        .unwrap();
+        let buttons: Vec<(u8, u8, u8)> = presses
+            .iter()
+            .map(|press| match press {
+                LevelerButtonPress::Type4(cmd) => {
+                    (cmd.device_mode, cmd.ui_mode, cmd.ui_button_data_1)
+                }
+                other => panic!("Unexpected press: {:?}", other),
+            })
+            .collect();
+        assert_eq!(buttons, vec![(3, 2, 0x02), (3, 2, 0)]);
+
+        let presses = RVLink::leveler_button_presses(
+            DeviceType::LevelerType3,
+            1,
+            9,
+            LevelerMode::Idle,
+            4,
+            LevelerButton::AutoLevel,
+        )
+        .unwrap();
+        let buttons: Vec<(u8, u8)> = presses
+            .iter()
+            .map(|press| match press {
+                LevelerButtonPress::Type3(cmd) => (cmd.screen_enum, cmd.button_state_1),
+                other => panic!("Unexpected press: {:?}", other),
+            })
+            .collect();
+        assert_eq!(buttons, vec![(4, 0x01), (4, 0)]);
+
+        let presses = RVLink::leveler_button_presses(
+            DeviceType::LevelerType1,
+            1,
+            9,
+            LevelerMode::Idle,
+            0,
+            LevelerButton::Stop,
+        )
+        .unwrap();
+        match &presses[0] {
+            LevelerButtonPress::Type1(cmd) => assert_eq!(cmd.button_state_1, 0x04),
+            other => panic!("Unexpected press: {:?}", other),
+        }
+        assert_eq!(presses.len(), 2);
+
+        assert!(RVLink::leveler_button_presses(
+            DeviceType::HourMeter,
+            1,
+            9,
+            LevelerMode::Idle,
+            0,
+            LevelerButton::Stop,
+        )
+        .is_none());
+        assert!("jump".parse::<LevelerButton>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    /// Validates console text is split into lines and trimmed to fit a sensor state
+    fn leveler_console_text() {
+        assert_eq!(
+            RVLink::leveler_console_text(b"AUTO LEVEL \0\0 COMPLETE\0"),
+            "AUTO LEVEL\nCOMPLETE"
+        );
+        assert_eq!(RVLink::leveler_console_text(&[b'A'; 300]).len(), 255);
+    }
+
+    #[test]
+    /// Validates commands to devices behind an accessory gateway use the gateway's commands
+    fn accessory_gateway_command() -> Result<()> {
+        let payload = RVLink::accessory_gateway_action(1, 12, "open")?.to_payload()?;
+        assert_eq!(payload, vec![0, 0, 70, 1, 12, 2]);
+        for (command, device_command) in [("off", 0), ("on", 1), ("close", 3), ("stop", 4)] {
+            assert_eq!(
+                RVLink::accessory_gateway_action(1, 12, command)?.device_command,
+                device_command
+            );
+        }
+        assert!(RVLink::accessory_gateway_action(1, 12, "brightness:50").is_err());
+        Ok(())
+    }
+
+    #[test]
+    /// Validates monitor panel and accessory gateway channels become tank levels or switches
+    fn accessory_states() {
+        let panel = |control_type: MonitorPanelControlType, value: u8| MonitorPanelDeviceState {
+            device_id: 12,
+            control_type: control_type.into(),
+            value,
+        };
+        assert_eq!(
+            RVLink::monitor_panel_state(&panel(MonitorPanelControlType::WasteTank, 66)),
+            DeviceState::Percentage(66)
+        );
+        assert_eq!(
+            RVLink::monitor_panel_state(&panel(MonitorPanelControlType::LatchingSwitch, 1)),
+            DeviceState::Switch(OnOff::On)
+        );
+
+        let accessory = |status: u8, value: u8| AccessoryGatewayDeviceState {
+            device_id: 12,
+            status,
+            value,
+        };
+        assert_eq!(
+            RVLink::accessory_gateway_state(DeviceEntityType::GreyTank, &accessory(0x00, 40)),
+            DeviceState::Percentage(40)
+        );
+        assert_eq!(
+            RVLink::accessory_gateway_state(DeviceEntityType::GreyTank, &accessory(0x00, 0xFF)),
+            DeviceState::Switch(OnOff::Off)
+        );
+        assert_eq!(
+            RVLink::accessory_gateway_state(DeviceEntityType::WaterPump, &accessory(0x01, 0xFF)),
+            DeviceState::Switch(OnOff::On)
+        );
+    }
+
+    #[test]
+    /// Validates the power system state carries every value its sub entities read
+    fn power_system_state() {
+        let entity = DeviceEntity::default();
+        entity
+            .device_type
+            .store(DeviceType::JaycoAusTbbGw, Ordering::Relaxed);
+        let state = DeviceState::PowerSystem {
+            battery_voltage: FixedU16::<U8>::from_num(13.5),
+            battery_current: 4.0,
+            solar_current: 6.0,
+            state_of_charge: Some(92),
+            mains_voltage: None,
+            mains_connected: false,
+            solar_charging: true,
+        };
+        let state: serde_json::Value = serde_json::from_str(&state.state_string()).unwrap();
+        assert_eq!(state["solar_power"], 81.0);
+        assert_eq!(state["mains_connected"], "off");
+        assert_eq!(state["solar_charging"], "on");
+        for sub_entity in entity.sub_entities() {
+            assert!(
+                state.get(sub_entity.key).is_some(),
+                "Missing {}",
+                sub_entity.key
+            );
+        }
+
+        let state = DeviceState::CloudGateway {
+            connected: true,
+            registered: false,
+        };
+        assert_eq!(
+            state.state_string(),
+            r#"{"connected":"on","registered":"off"}"#
+        );
+    }
+
+    #[test]
+    /// Validates the remaining and overdue hours computed from a `<device>=<hours>@<last>` schedule
+    fn hour_meter_service_interval() {
+        let schedule: config::HourMeterServiceInterval = "Generator=200@1000".parse().unwrap();
+        assert!(schedule.matches("generator", None));
+        assert!(!schedule.matches("Water Heater", Some((1, 4))));
+        assert_eq!(schedule.interval.next_service_hours(), 1200);
+
+        let state = |hours: u32| {
+            let state = DeviceState::HourMeter {
+                operating_seconds: hours * 3600,
+                running: false,
+                maintenance_due: false,
+                maintenance_past_due: false,
+                error: false,
+                service_interval: Some(schedule.interval),
+            };
+            serde_json::from_str::<serde_json::Value>(&state.state_string()).unwrap()
+        };
+        let upcoming = state(1100);
+        assert_eq!(upcoming["service_due"], "off");
+        assert_eq!(upcoming["hours_until_service"], 100.0);
+        let overdue = state(1250);
+        assert_eq!(overdue["service_due"], "on");
+        assert_eq!(overdue["hours_until_service"], -50.0);
+
+        let schedule: config::HourMeterServiceInterval = "1:4=50".parse().unwrap();
+        assert!(schedule.matches("Water Heater", Some((1, 4))));
+        assert_eq!(schedule.interval.next_service_hours(), 50);
+        assert!("Generator"
+            .parse::<config::HourMeterServiceInterval>()
+            .is_err());
+    }
+
+    #[test]
+    /// Validates lock commands are only accepted with the configured code
+    fn door_lock_code() {
+        assert_eq!(
+            RVLink::door_lock_action("lock", None).unwrap(),
+            Some(OnOff::On)
+        );
+        assert_eq!(
+            RVLink::door_lock_action("unlock:1234", None).unwrap(),
+            Some(OnOff::Off)
+        );
+        assert_eq!(RVLink::door_lock_action("open", None).unwrap(), None);
+
+        let code = Some("1234");
+        assert_eq!(
+            RVLink::door_lock_action("lock:1234", code).unwrap(),
+            Some(OnOff::On)
+        );
+        assert_eq!(
+            RVLink::door_lock_action("unlock:1234", code).unwrap(),
+            Some(OnOff::Off)
+        );
+        assert!(RVLink::door_lock_action("lock", code).is_err());
+        assert!(RVLink::door_lock_action("unlock:4321", code).is_err());
+        assert!(RVLink::door_lock_action("unlock:", code).is_err());
+    }
+
+    #[test]
+    /// Validates PID values are padded to the requested type and oversized ones don't lose data
+    fn fit_pid_value() -> Result<()> {
+        assert_eq!(RVLink::fit_pid_value(&[0x12], 2)?, [0x00, 0x12]);
+        assert_eq!(RVLink::fit_pid_value(&[0x12, 0x34], 2)?, [0x12, 0x34]);
+        assert_eq!(RVLink::fit_pid_value(&[0, 0, 0x12, 0x34], 2)?, [0x12, 0x34]);
+        assert!(RVLink::fit_pid_value(&[0, 1, 0x12, 0x34], 2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    /// Validates that PID list responses are decoded into the per-device PID cache
+    fn cache_pid_list() -> Result<()> {
+        let entry = DeviceEntry::default();
+        assert!(!entry.has_cached_pids());
+
+        // FuseSetting1 readable and writable, then BatteryVoltage readable
+        let first = vec![2u8, 0, 1, 0x01, 1, 4, 0, 0, 0, 55, 3, 0, 43, 1];
+        entry.cache_pid_list(&GetDevicePidListResponse::from_payload(first)?);
+        let completed = vec![2u8, 0, 1, 0x81, 0, 2];
+        entry.cache_pid_list(&GetDevicePidListResponse::from_payload(completed)?);
+        assert!(entry.has_cached_pids());
+
+        let pids = entry.cached_pids();
+        assert_eq!(pids.len(), 2);
+        assert_eq!(pids[0].parameter_id(), Some(ParameterID::BatteryVoltage));
+        assert!(pids[0].is_readable() && !pids[0].is_writable());
+        assert_eq!(pids[1].parameter_id(), Some(ParameterID::FuseSetting1));
+        assert!(pids[1].is_writable());
+        Ok(())
+    }
+}
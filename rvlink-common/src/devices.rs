@@ -1,3 +1,5 @@
+use crate::hass::*;
+
 #[derive(Debug, Display, Default, PartialEq, Clone, Copy)]
 #[allow(dead_code)]
 pub enum DeviceEntityType {
@@ -8,16 +10,40 @@ pub enum DeviceEntityType {
     Switch,
     #[display(fmt = "light_switch")]
     LightSwitch,
+    #[display(fmt = "dimmable_light")]
+    DimmableLight,
+    #[display(fmt = "rgb_light")]
+    RgbLight,
     #[display(fmt = "water_heater")]
     WaterHeater,
     #[display(fmt = "water_pump")]
     WaterPump,
+    #[display(fmt = "generator")]
+    Generator,
+    #[display(fmt = "hour_meter")]
+    HourMeter,
+    #[display(fmt = "leveler")]
+    Leveler,
     #[display(fmt = "slide")]
     Slide,
     #[display(fmt = "awning")]
     Awning,
+    #[display(fmt = "awning_sensor")]
+    AwningSensor,
     #[display(fmt = "battery")]
     Battery,
+    #[display(fmt = "battery_monitor")]
+    BatteryMonitor,
+    #[display(fmt = "chassis")]
+    Chassis,
+    #[display(fmt = "clock")]
+    Clock,
+    #[display(fmt = "cloud_gateway")]
+    CloudGateway,
+    #[display(fmt = "temperature")]
+    Temperature,
+    #[display(fmt = "temperature_sensor")]
+    TemperatureSensor,
     #[display(fmt = "fresh_tank")]
     FreshTank,
     #[display(fmt = "grey_tank")]
@@ -30,6 +56,8 @@ pub enum DeviceEntityType {
     LPTank,
     #[display(fmt = "door_lock")]
     DoorLock,
+    #[display(fmt = "power_system")]
+    PowerSystem,
     #[display(fmt = "thermostat")]
     Thermostat,
     #[display(fmt = "brakes")]
@@ -39,3 +67,79 @@ pub enum DeviceEntityType {
     #[display(fmt = "sensor")]
     Sensor,
 }
+
+impl DeviceEntityType {
+    /// The HA sensor definition for types that are published as sensors, `None` for anything controllable or binary.
+    /// Temperatures are published in `temperature_unit`, which has to be the unit the values arrive in
+    pub fn sensor_definition(
+        &self,
+        temperature_unit: &'static str,
+    ) -> Option<HassSensorDefinition> {
+        let (class, unit, state_class) = match self {
+            DeviceEntityType::Battery => (
+                HassDiscoverySensorClass::Voltage,
+                Some("V"),
+                Some(HassStateClass::Measurement),
+            ),
+            DeviceEntityType::AwningSensor => (
+                HassDiscoverySensorClass::WindSpeed,
+                Some("mph"),
+                Some(HassStateClass::Measurement),
+            ),
+            DeviceEntityType::BatteryMonitor | DeviceEntityType::PowerSystem => (
+                HassDiscoverySensorClass::Battery,
+                Some("%"),
+                Some(HassStateClass::Measurement),
+            ),
+            // HA has no device class for a fill level, only for volumes
+            DeviceEntityType::FreshTank
+            | DeviceEntityType::GreyTank
+            | DeviceEntityType::BlackTank
+            | DeviceEntityType::FuelTank
+            | DeviceEntityType::LPTank => (
+                HassDiscoverySensorClass::None,
+                Some("%"),
+                Some(HassStateClass::Measurement),
+            ),
+            DeviceEntityType::Temperature | DeviceEntityType::TemperatureSensor => (
+                HassDiscoverySensorClass::Temperature,
+                Some(temperature_unit),
+                Some(HassStateClass::Measurement),
+            ),
+            DeviceEntityType::HourMeter => (
+                HassDiscoverySensorClass::Duration,
+                Some("h"),
+                Some(HassStateClass::TotalIncreasing),
+            ),
+            DeviceEntityType::Clock => (HassDiscoverySensorClass::Timestamp, None, None),
+            // The lockout level is a state, not something to keep statistics of
+            DeviceEntityType::Chassis => (HassDiscoverySensorClass::None, None, None),
+            DeviceEntityType::Brakes => (
+                HassDiscoverySensorClass::None,
+                Some("%"),
+                Some(HassStateClass::Measurement),
+            ),
+            DeviceEntityType::Leveler
+            | DeviceEntityType::SignalLights
+            | DeviceEntityType::Sensor
+            | DeviceEntityType::None => (HassDiscoverySensorClass::None, None, None),
+            DeviceEntityType::Switch
+            | DeviceEntityType::LightSwitch
+            | DeviceEntityType::DimmableLight
+            | DeviceEntityType::RgbLight
+            | DeviceEntityType::WaterHeater
+            | DeviceEntityType::WaterPump
+            | DeviceEntityType::Generator
+            | DeviceEntityType::Slide
+            | DeviceEntityType::Awning
+            | DeviceEntityType::DoorLock
+            | DeviceEntityType::CloudGateway
+            | DeviceEntityType::Thermostat => return None,
+        };
+        Some(HassSensorDefinition {
+            class,
+            unit,
+            state_class,
+        })
+    }
+}
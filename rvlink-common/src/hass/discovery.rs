@@ -31,6 +31,13 @@ HassPayload! {
         suggested_area:String => "sa" "suggested_area",
     }
 
+    HassAvailability {
+        topic:String => "t" "topic",
+        payload_available:String => "pl_avail" "payload_available",
+        payload_not_available:String => "pl_not_avail" "payload_not_available",
+        value_template:String => "val_tpl" "value_template",
+    }
+
     HassDiscoveryInfo {
         action_topic:String => "act_t" "action_topic",
         action_template:String => "act_tpl" "action_template",
@@ -39,7 +46,7 @@ HassPayload! {
         aux_state_template:String => "aux_stat_tpl" "aux_state_template",
         aux_state_topic:String => "aux_stat_t" "aux_state_topic",
         available_tones:String => "av_tones" "available_tones",
-        availability: String => "avty" "availability",
+        availability: Vec<HassAvailability> => "avty" "availability",
         availability_mode:String => "avty_mode" "availability_mode",
         availability_topic:String => "avty_t" "availability_topic",
         availability_template:String => "avty_tpl" "availability_template",
@@ -70,6 +77,7 @@ HassPayload! {
         command_topic:String => "cmd_t" "command_topic",
         command_template:String => "cmd_tpl" "command_template",
         code_arm_required:String => "cod_arm_req" "code_arm_required",
+        code_format:String => "cod_form" "code_format",
         code_disarm_required:String => "cod_dis_req" "code_disarm_required",
         code_trigger_required:String => "cod_trig_req" "code_trigger_required",
         current_temperature_topic:String => "curr_temp_t" "current_temperature_topic",
@@ -89,7 +97,7 @@ HassPayload! {
         flash_time_short:String => "flsh_tsht" "flash_time_short",
         effect_command_topic:String => "fx_cmd_t" "effect_command_topic",
         effect_command_template:String => "fx_cmd_tpl" "effect_command_template",
-        effect_list:String => "fx_list" "effect_list",
+        effect_list:Vec<String> => "fx_list" "effect_list",
         effect_state_topic:String => "fx_stat_t" "effect_state_topic",
         effect_template:String => "fx_tpl" "effect_template",
         effect_value_template:String => "fx_val_tpl" "effect_value_template",
@@ -98,6 +106,7 @@ HassPayload! {
         fan_mode_command_topic:String => "fan_mode_cmd_t" "fan_mode_command_topic",
         fan_mode_state_template:String => "fan_mode_stat_tpl" "fan_mode_state_template",
         fan_mode_state_topic:String => "fan_mode_stat_t" "fan_mode_state_topic",
+        fan_modes:Vec<String> => "fan_modes" "fan_modes",
         force_update:String => "frc_upd" "force_update",
         green_template:String => "g_tpl" "green_template",
         hold_command_template:String => "hold_cmd_tpl" "hold_command_template",
@@ -126,7 +135,7 @@ HassPayload! {
         mode_command_topic:String => "mode_cmd_t" "mode_command_topic",
         mode_state_template:String => "mode_stat_tpl" "mode_state_template",
         mode_state_topic:String => "mode_stat_t" "mode_state_topic",
-        modes:String => "modes" "modes",
+        modes:Vec<String> => "modes" "modes",
         name:String => "name" "name",
         object_id:String => "obj_id" "object_id",
         off_delay:String => "off_dly" "off_delay",
@@ -160,6 +169,7 @@ HassPayload! {
         payload_oscillation_off:String => "pl_osc_off" "payload_oscillation_off",
         payload_oscillation_on:String => "pl_osc_on" "payload_oscillation_on",
         payload_pause:String => "pl_paus" "payload_pause",
+        payload_press:String => "pl_prs" "payload_press",
         payload_stop:String => "pl_stop" "payload_stop",
         payload_start:String => "pl_strt" "payload_start",
         payload_start_pause:String => "pl_stpa" "payload_start_pause",
@@ -206,6 +216,7 @@ HassPayload! {
         state_open:String => "stat_open" "state_open",
         state_opening:String => "stat_opening" "state_opening",
         state_stopped:String => "stat_stopped" "state_stopped",
+        state_jammed:String => "stat_jam" "state_jammed",
         state_locked:String => "stat_locked" "state_locked",
         state_unlocked:String => "stat_unlocked" "state_unlocked",
         state_topic:String => "stat_t" "state_topic",
@@ -16,10 +16,14 @@ pub enum HassDiscoveryType {
     Switch,
     #[display(fmt = "light")]
     Light,
-    #[display(fmt = "thermostat")]
-    Thermostat,
+    #[display(fmt = "climate")]
+    Climate,
     #[display(fmt = "cover")]
     Cover(HassDiscoveryCoverClass),
+    #[display(fmt = "lock")]
+    Lock,
+    #[display(fmt = "button")]
+    Button,
 }
 
 #[derive(Clone, Debug, Display, Default)]
@@ -44,6 +48,8 @@ pub enum HassDiscoverySensorClass {
     Pressure,
     #[display(fmt = "timestamp")]
     Timestamp,
+    #[display(fmt = "duration")]
+    Duration,
     #[display(fmt = "current")]
     Current,
     #[display(fmt = "energy")]
@@ -52,6 +58,8 @@ pub enum HassDiscoverySensorClass {
     PowerFactor,
     #[display(fmt = "voltage")]
     Voltage,
+    #[display(fmt = "wind_speed")]
+    WindSpeed,
 }
 
 #[derive(Clone, Debug, Display, Default)]
@@ -110,6 +118,25 @@ pub enum HassDiscoveryBinarySensorClass {
     Window, // on means open, off means closed
 }
 
+#[derive(Clone, Copy, Debug, Display, PartialEq)]
+#[allow(dead_code)]
+pub enum HassStateClass {
+    #[display(fmt = "measurement")]
+    Measurement,
+    #[display(fmt = "total")]
+    Total,
+    #[display(fmt = "total_increasing")]
+    TotalIncreasing,
+}
+
+/// Everything Home Assistant needs to know to treat an entity's state as a sensor value
+#[derive(Clone, Debug)]
+pub struct HassSensorDefinition {
+    pub class: HassDiscoverySensorClass,
+    pub unit: Option<&'static str>,
+    pub state_class: Option<HassStateClass>,
+}
+
 #[derive(Clone, Debug, Display, Default)]
 pub enum HassDiscoveryCoverClass {
     #[display(fmt = "none")]
@@ -150,18 +177,25 @@ impl HassIcons {
     pub const BLUETOOTH_WAVE: &'static str = "mdi:bluetooth-audio";
     pub const GARAGE: &'static str = "mdi:garage";
     pub const FLASH: &'static str = "mdi:flash";
+    pub const TIMER: &'static str = "mdi:timer-outline";
+    pub const LOCK: &'static str = "mdi:lock";
+    pub const WIND: &'static str = "mdi:weather-windy";
+    pub const GESTURE_TAP: &'static str = "mdi:gesture-tap-button";
+    pub const GAUGE: &'static str = "mdi:gauge";
 }
 
 impl HassDiscoveryType {
     pub fn icon(&self) -> &'static str {
         match self {
-            Self::Thermostat => HassIcons::THERMOMETER,
+            Self::Climate => HassIcons::THERMOMETER,
             Self::Light => HassIcons::LIGHT,
             Self::Switch => HassIcons::POWER,
             Self::MediaPlayer => HassIcons::TELEVISION,
             Self::Sensor(c) => c.icon(),
             Self::BinarySensor(c) => c.icon(),
             Self::Cover(c) => c.icon(),
+            Self::Lock => HassIcons::LOCK,
+            Self::Button => HassIcons::GESTURE_TAP,
         }
     }
 
@@ -207,10 +241,12 @@ impl HassDiscoverySensorClass {
             Self::Power => HassIcons::POWER,
             Self::Pressure => HassIcons::EYE,
             Self::Timestamp => HassIcons::EYE,
+            Self::Duration => HassIcons::TIMER,
             Self::Current => HassIcons::FLASH,
             Self::Energy => HassIcons::FLASH,
             Self::PowerFactor => HassIcons::FLASH,
             Self::Voltage => HassIcons::FLASH,
+            Self::WindSpeed => HassIcons::WIND,
             Self::None => HassIcons::EYE,
         }
     }
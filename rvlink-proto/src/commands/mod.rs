@@ -52,6 +52,7 @@ macro_rules! commands {
     (*RESPONSE $msgrsp:ident ($rspmin:literal .. $rspmax:literal) $success:literal $complete:literal {
         $( $rspname:ident : $rsptype:ty [ $rspindex:literal ] , )*
         $( << $repname:ident : $reptype:ty [ $repindex:literal ] ,)*
+        $( .. $tailname:ident [ $tailindex:literal ] ,)?
     }) => {
         #[derive(Debug, Default)]
         #[allow(dead_code)]
@@ -60,6 +61,7 @@ macro_rules! commands {
             pub client_command_id: u16,
             $( pub $rspname: $rsptype, )*
             $( pub $repname: Vec<$reptype>, )*
+            $( pub $tailname: Vec<u8>, )?
         }
 
         #[allow(dead_code)]
@@ -76,12 +78,14 @@ macro_rules! commands {
                 let client_command_id = <u16>::from_data(bytes[1..].try_into()?)?;
                 $( let $rspname = <$rsptype>::from_data(bytes[$rspindex..].try_into()?)?; )*
                 $( let $repname = <$reptype>::decode_buffer(&bytes[$repindex..])?; )*
+                $( let $tailname = bytes.get($tailindex..).unwrap_or_default().to_vec(); )?
                 let data = bytes;
                 Ok(Self{
                     data,
                     client_command_id,
                     $( $rspname, )*
                     $( $repname, )*
+                    $( $tailname, )?
                 })
             }
         }
@@ -89,6 +93,7 @@ macro_rules! commands {
     ($(
         $msgname:ident ($command_type:literal ; $min:literal .. $max:literal) {
             $( $name:ident : $type:ty [ $index:literal ] , )*
+            $( .. $tailname:ident [ $tailindex:literal ] , )?
         } -> $rsp_name:ident :
 
         + $rsp_suc_name:ident ($rsp_suc_min:literal .. $rsp_suc_max:literal) { $( $rsp_suc_content:tt )* }
@@ -159,6 +164,7 @@ macro_rules! commands {
             pub struct $msgname {
                 pub client_command_id: u16,
                 $( pub $name: $type, )*
+                $( pub $tailname: Vec<u8>, )?
             }
 
             #[allow(dead_code)]
@@ -177,6 +183,9 @@ macro_rules! commands {
                     $({
                         res.append(&mut self.$name.to_data());
                     })*
+                    $({
+                        res.extend_from_slice(&self.$tailname);
+                    })?
                     if res.len() > $max || res.len() < $min {
                         Err(AppError::InvalidPayload)
                     } else {
@@ -341,7 +350,11 @@ commands! {
         start_dtc: u16 [6],
         end_dtc: u16 [8],
     } -> GetProductDtcValuesResponse:
-    + GetProductDtcValuesResponseSuccess (4..384) {}
+    + GetProductDtcValuesResponseSuccess (6..384) {
+        device_table_id: u8 [4],
+        device_id: u8 [5],
+        << dtcs: DtcValue [6],
+    }
     - GetProductDtcValuesResponseFailure (4..384) {}
     &+ GetProductDtcValuesResponseSuccessCompleted (4..384) {}
     &- GetProductDtcValuesResponseFailureCompleted (4..384) {}
@@ -352,9 +365,16 @@ commands! {
         start_index: u16 [5],
         end_index: u16 [7],
     } -> GetDevicePidListResponse:
-    + GetDevicePidListResponseSuccess (4..384) {}
+    + GetDevicePidListResponseSuccess (8..384) {
+        device_table_id: u8 [4],
+        device_id: u8 [5],
+        start_index: u16 [6],
+        << pids: DevicePidEntry [8],
+    }
     - GetDevicePidListResponseFailure (4..384) {}
-    &+ GetDevicePidListResponseSuccessCompleted (4..384) {}
+    &+ GetDevicePidListResponseSuccessCompleted (6..384) {
+        pid_count: u16 [4],
+    }
     &- GetDevicePidListResponseFailureCompleted (4..384) {}
 
     GetDevicePid (18; 7..7) {
@@ -362,9 +382,19 @@ commands! {
         device_id: u8 [4],
         pid: ParameterID [5],
     } -> GetDevicePidResponse:
-    + GetDevicePidResponseSuccess (4..384) {}
+    + GetDevicePidResponseSuccess (8..14) {
+        device_table_id: u8 [4],
+        device_id: u8 [5],
+        pid: ParameterID [6],
+        .. value [8],
+    }
     - GetDevicePidResponseFailure (4..384) {}
-    &+ GetDevicePidResponseSuccessCompleted (4..384) {}
+    &+ GetDevicePidResponseSuccessCompleted (8..14) {
+        device_table_id: u8 [4],
+        device_id: u8 [5],
+        pid: ParameterID [6],
+        .. value [8],
+    }
     &- GetDevicePidResponseFailureCompleted (4..384) {}
 
     SetDevicePid (19; 9..15) {
@@ -372,7 +402,7 @@ commands! {
         device_id: u8 [4],
         pid: ParameterID [5],
         session_id: u16 [7],
-        // Value @ 9, variable size 0-6
+        .. value [9], // 0-6 bytes
     } -> SetDevicePidResponse:
     + SetDevicePidResponseSuccess (4..384) {}
     - SetDevicePidResponseFailure (4..384) {}
@@ -385,9 +415,21 @@ commands! {
         pid: ParameterID [5],
         pid_address: u16 [7],
     } -> GetDevicePidWithAddressResponse:
-    + GetDevicePidWithAddressResponseSuccess (4..384) {}
+    + GetDevicePidWithAddressResponseSuccess (10..14) {
+        device_table_id: u8 [4],
+        device_id: u8 [5],
+        pid: ParameterID [6],
+        pid_address: u16 [8],
+        .. value [10],
+    }
     - GetDevicePidWithAddressResponseFailure (4..384) {}
-    &+ GetDevicePidWithAddressResponseSuccessCompleted (4..384) {}
+    &+ GetDevicePidWithAddressResponseSuccessCompleted (10..14) {
+        device_table_id: u8 [4],
+        device_id: u8 [5],
+        pid: ParameterID [6],
+        pid_address: u16 [8],
+        .. value [10],
+    }
     &- GetDevicePidWithAddressResponseFailureCompleted (4..384) {}
 
     SetDevicePidWithAddress (21; 11..15) {
@@ -396,7 +438,7 @@ commands! {
         pid: ParameterID [5],
         session_id: u16 [7],
         pid_address: u16 [9],
-        // Value @ 11, variable size 0-4
+        .. value [11], // 0-4 bytes
     } -> SetDevicePidWithAddressResponse:
     + SetDevicePidWithAddressResponseSuccess (4..384) {}
     - SetDevicePidWithAddressResponseFailure (4..384) {}
@@ -492,7 +534,7 @@ commands! {
     ActionGeneratorGenie (66; 6..6) {
         device_table_id: u8 [3],
         device_id: u8 [4],
-        device_command: u8 [5],
+        device_command: GeneratorGenieCommand [5],
     } -> ActionGeneratorGenieResponse:
     + ActionGeneratorGenieResponseSuccess (4..384) {}
     - ActionGeneratorGenieResponseFailure (4..384) {}
@@ -502,7 +544,11 @@ commands! {
     ActionDimmable (67; 6..12) {
         device_table_id: u8 [3],
         device_id: u8 [4],
-        device_command: u8 [5],
+        device_command: DimmableLightCommand [5],
+        max_brightness: u8 [6],
+        duration: u8 [7],
+        cycle_time_1: u16 [8],
+        cycle_time_2: u16 [10],
     } -> ActionDimmableResponse:
     + ActionDimmableResponseSuccess (4..384) {}
     - ActionDimmableResponseFailure (4..384) {}
@@ -512,8 +558,7 @@ commands! {
     ActionRgb (68; 6..12) {
         device_table_id: u8 [3],
         device_id: u8 [4],
-        device_command: u8 [5],
-        // Alternate modes to be enumed
+        command: RgbLightCommand [5],
     } -> ActionRgbResponse:
     + ActionRgbResponseSuccess (4..384) {}
     - ActionRgbResponseFailure (4..384) {}
@@ -523,8 +568,9 @@ commands! {
     ActionHvac (69; 8..8) {
         device_table_id: u8 [3],
         device_id: u8 [4],
-        device_command: u8 [5],
-        // ?
+        command: HvacCommand [5],
+        low_trip_temperature: u8 [6],
+        high_trip_temperature: u8 [7],
     } -> ActionHvacResponse:
     + ActionHvacResponseSuccess (4..384) {}
     - ActionHvacResponseFailure (4..384) {}
@@ -599,3 +645,220 @@ commands! {
     &+ DiagnosticsResponseSuccessCompleted (4..384) {}
     &- DiagnosticsResponseFailureCompleted (4..384) {}
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    /// Validates that every RGB command mode fits in the ActionRgb size limits
+    fn rgb_command_payloads() -> Result<()> {
+        let commands = [
+            (RgbLightCommand::Off, 6),
+            (RgbLightCommand::Restore, 6),
+            (
+                RgbLightCommand::Solid {
+                    red: 255,
+                    green: 128,
+                    blue: 0,
+                    duration: 0,
+                },
+                10,
+            ),
+            (
+                RgbLightCommand::Blink {
+                    red: 255,
+                    green: 128,
+                    blue: 0,
+                    duration: 0,
+                    on_interval: 10,
+                    off_interval: 20,
+                },
+                12,
+            ),
+            (
+                RgbLightCommand::Transition {
+                    mode: RgbLightMode::Rainbow,
+                    duration: 0,
+                    interval: 1000,
+                },
+                9,
+            ),
+        ];
+        for (command, expected_len) in commands {
+            let payload = ActionRgb {
+                client_command_id: 1,
+                device_table_id: 1,
+                device_id: 4,
+                command,
+            }
+            .to_payload()?;
+            assert_eq!(payload.len(), expected_len);
+            assert_eq!(RgbLightCommand::from_data(&payload[5..])?, command);
+        }
+        Ok(())
+    }
+
+    #[test]
+    /// Validates that trailing PID values are written and read back whole
+    fn pid_value_payloads() -> Result<()> {
+        let payload = SetDevicePid {
+            client_command_id: 1,
+            device_table_id: 1,
+            device_id: 4,
+            pid: ParameterID::BatteryVoltage,
+            session_id: 0,
+            value: vec![0, 0, 12, 128],
+        }
+        .to_payload()?;
+        assert_eq!(payload, vec![0, 1, 19, 1, 4, 0, 43, 0, 0, 0, 0, 12, 128]);
+
+        let too_long = SetDevicePid {
+            value: vec![0; 7],
+            ..Default::default()
+        };
+        assert!(too_long.to_payload().is_err());
+
+        let response = vec![2u8, 0, 1, 0x81, 1, 4, 0, 43, 0, 0, 12, 128];
+        match GetDevicePidResponse::from_payload(response)? {
+            GetDevicePidResponse::SuccessComplete(rsp) => {
+                assert_eq!(rsp.pid, ParameterID::BatteryVoltage);
+                assert_eq!(rsp.value, vec![0, 0, 12, 128]);
+                assert_eq!(rsp.pid.decode(&rsp.value)?, PidValue::Fixed(12.5));
+            }
+            other => panic!("Unexpected response: {:?}", other),
+        }
+
+        // More significant bytes than the PID is wide can't be decoded without losing them
+        let response = vec![2u8, 0, 1, 0x81, 1, 4, 0, 43, 0, 1, 12, 128];
+        match GetDevicePidResponse::from_payload(response)? {
+            GetDevicePidResponse::SuccessComplete(rsp) => {
+                assert!(rsp.pid.decode(&rsp.value).is_err());
+            }
+            other => panic!("Unexpected response: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dtc_values_response() -> Result<()> {
+        // BatteryVoltageLow active 3 times, then a stored code we have no name for
+        let response = vec![2u8, 0, 1, 0x01, 1, 4, 0, 5, 0x01, 3, 0x7F, 0xFF, 0x02, 1];
+        match GetProductDtcValuesResponse::from_payload(response)? {
+            GetProductDtcValuesResponse::Success(rsp) => {
+                assert_eq!(rsp.dtcs.len(), 2);
+                assert_eq!(rsp.dtcs[0].dtc_id(), Some(DTCID::BatteryVoltageLow));
+                assert!(rsp.dtcs[0].is_active());
+                assert_eq!(rsp.dtcs[0].to_string(), "BatteryVoltageLow (active, 3x)");
+                assert_eq!(rsp.dtcs[1].dtc_id(), None);
+                assert_eq!(rsp.dtcs[1].to_string(), "DTC 0x7fff (stored, 1x)");
+            }
+            other => panic!("Unexpected response: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn pid_list_response() -> Result<()> {
+        // BatteryVoltage readable, FuseSetting1 readable and writable, then an unknown PID
+        let response = vec![
+            2u8, 0, 1, 0x01, 1, 4, 0, 0, 0, 43, 1, 0, 55, 3, 0x7F, 0xFF, 1,
+        ];
+        match GetDevicePidListResponse::from_payload(response)? {
+            GetDevicePidListResponse::Success(rsp) => {
+                assert_eq!(rsp.pids.len(), 3);
+                assert_eq!(
+                    rsp.pids[0].parameter_id(),
+                    Some(ParameterID::BatteryVoltage)
+                );
+                assert!(!rsp.pids[0].is_writable());
+                assert!(rsp.pids[1].is_readable() && rsp.pids[1].is_writable());
+                assert_eq!(rsp.pids[2].parameter_id(), None);
+            }
+            other => panic!("Unexpected response: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    /// Validates that PID values decode and encode using the PID metadata
+    fn pid_value_metadata() -> Result<()> {
+        let voltage = ParameterID::BatteryVoltage;
+        assert_eq!(voltage.unit(), "V");
+        assert!(!voltage.writable());
+        assert_eq!(voltage.decode(&[0, 0, 12, 128])?, PidValue::Fixed(12.5));
+
+        let angle = ParameterID::TiltAxis1Angle;
+        assert_eq!(angle.decode(&[0xFE, 0x80])?, PidValue::Fixed(-1.5));
+        assert_eq!(angle.encode(&PidValue::Fixed(-1.5))?, vec![0xFE, 0x80]);
+
+        let rssi = ParameterID::WifiRssi;
+        assert_eq!(rssi.decode(&[0xC4])?, PidValue::Signed(-60));
+
+        let fuse = ParameterID::FuseSetting1;
+        assert_eq!(fuse.decode(&[0, 0, 0, 0, 0, 15])?, PidValue::Unsigned(15));
+        assert_eq!(fuse.encode(&PidValue::Unsigned(20))?, vec![20]);
+        assert!(fuse.encode(&PidValue::Unsigned(300)).is_err());
+
+        let mac = ParameterID::CanAdapterMac;
+        let value = mac.decode(&[0, 1, 2, 3, 4, 5])?;
+        assert_eq!(value.to_string(), "000102030405");
+        Ok(())
+    }
+
+    #[test]
+    /// Validates the DTC metadata keeps lining up with the catalog codes
+    fn dtc_metadata() {
+        let dtc = DTCID::from(5u16);
+        assert_eq!(dtc, DTCID::BatteryVoltageLow);
+        assert_eq!(dtc.description(), "Battery voltage low");
+        assert_eq!(dtc.severity(), DtcSeverity::Warning);
+        assert_eq!(dtc.category(), DtcCategory::Power);
+
+        let dtc = DTCID::HallEffectSignalShortToGnd;
+        assert_eq!(dtc.description(), "Hall effect signal short to ground");
+        assert_eq!(dtc.severity(), DtcSeverity::Fault);
+        assert_eq!(dtc.to_string(), "HallEffectSignalShortToGnd");
+
+        // Wiring faults are power faults whatever the circuit is for
+        for dtc in [
+            DTCID::HallEffectSignalShortToGnd,
+            DTCID::HallEffectSignalShortToBatt,
+            DTCID::CalSwitchShortToBatt,
+            DTCID::FloatSwitchShortToBatt,
+            DTCID::InputShortToBatt,
+            DTCID::OutdoorTempSensorOpenCircuit,
+        ] {
+            assert_eq!(dtc.category(), DtcCategory::Power, "{}", dtc);
+        }
+
+        // Communication faults are comms faults whatever the other end is
+        for dtc in [
+            DTCID::BleSubsystemCommError,
+            DTCID::RemoteSensorCommFailure,
+            DTCID::FrontRemoteSensorCommFailure,
+            DTCID::RearRemoteSensorCommFailure,
+            DTCID::WindSensor1CommFailure,
+        ] {
+            assert_eq!(dtc.category(), DtcCategory::Comms, "{}", dtc);
+            assert_eq!(dtc.severity(), DtcSeverity::Fault, "{}", dtc);
+        }
+
+        assert_eq!(
+            DTCID::CalSwitchSwitchStuck.description(),
+            "Calibration switch stuck"
+        );
+        assert_eq!(DTCID::CalSwitchSwitchStuck.category(), DtcCategory::Sensor);
+        assert_eq!(
+            DTCID::FloatSwitchSwitchStuck.description(),
+            "Float switch stuck"
+        );
+        assert_eq!(DTCID::TiltSensorMalfunction.category(), DtcCategory::Sensor);
+        assert_eq!(DTCID::JackLfOutOfStroke.category(), DtcCategory::Motor);
+        assert_eq!(DTCID::JackLfOutOfStroke.severity(), DtcSeverity::Warning);
+        assert_eq!(
+            DTCID::UserAutoOperationComplete.severity(),
+            DtcSeverity::Info
+        );
+    }
+}
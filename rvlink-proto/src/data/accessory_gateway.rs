@@ -0,0 +1,63 @@
+use super::*;
+
+/// Commands understood by devices behind an accessory gateway
+#[derive(Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum AccessoryGatewayCommand {
+    #[display(fmt = "off")]
+    Off = 0,
+    #[display(fmt = "on")]
+    On = 1,
+    #[display(fmt = "open")]
+    Open = 2,
+    #[display(fmt = "close")]
+    Close = 3,
+    #[display(fmt = "stop")]
+    Stop = 4,
+}
+
+impl std::str::FromStr for AccessoryGatewayCommand {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "off" => Ok(AccessoryGatewayCommand::Off),
+            "on" => Ok(AccessoryGatewayCommand::On),
+            "open" => Ok(AccessoryGatewayCommand::Open),
+            "close" => Ok(AccessoryGatewayCommand::Close),
+            "stop" => Ok(AccessoryGatewayCommand::Stop),
+            _ => Err(AppError::Generic(format!(
+                "Unknown accessory gateway command: {}",
+                s
+            ))),
+        }
+    }
+}
+
+define_encodable_struct! {
+    AccessoryGatewayDeviceState [3] {
+        device_id: u8 [0],
+        status: u8 [1],
+        value: u8 [2],
+    }
+}
+
+#[allow(dead_code)]
+impl AccessoryGatewayDeviceState {
+    pub fn is_on(&self) -> bool {
+        (self.status & 0x01) == 0x01
+    }
+
+    pub fn is_online(&self) -> bool {
+        (self.status & 0x80) == 0x00
+    }
+
+    /// Level or percentage reported by the accessory, if it has one
+    pub fn value(&self) -> Option<u8> {
+        if self.value == 0xFF {
+            None
+        } else {
+            Some(self.value)
+        }
+    }
+}
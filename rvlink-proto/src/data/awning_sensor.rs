@@ -0,0 +1,39 @@
+use super::*;
+
+define_encodable_struct! {
+    AwningSensorState [4] {
+        device_id: u8 [0],
+        status: u8 [1],
+        wind_speed: u8 [2],
+        vibration: u8 [3],
+    }
+}
+
+#[allow(dead_code)]
+impl AwningSensorState {
+    pub fn is_wind_alarm(&self) -> bool {
+        (self.status & 0x01) == 0x01
+    }
+
+    pub fn is_vibration_alarm(&self) -> bool {
+        (self.status & 0x02) == 0x02
+    }
+
+    /// Wind speed in mph, 0xFF when the sensor has no reading
+    pub fn wind_speed(&self) -> Option<u8> {
+        if self.wind_speed == 0xFF {
+            None
+        } else {
+            Some(self.wind_speed)
+        }
+    }
+
+    /// Vibration level in percent of the alarm threshold
+    pub fn vibration(&self) -> Option<u8> {
+        if self.vibration == 0xFF {
+            None
+        } else {
+            Some(self.vibration)
+        }
+    }
+}
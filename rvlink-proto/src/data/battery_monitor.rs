@@ -0,0 +1,47 @@
+use super::*;
+use fixed::{types::extra::U8, FixedU16};
+
+define_encodable_struct! {
+    BatteryMonitorState [9] {
+        device_id: u8 [0],
+        status: u8 [1],
+        state_of_charge: u8 [2],
+        voltage: FixedU16<U8> [3],
+        current: u16 [5],
+        time_remaining: u16 [7],
+    }
+}
+
+#[allow(dead_code)]
+impl BatteryMonitorState {
+    pub fn is_charging(&self) -> bool {
+        (self.status & 0x01) == 0x01
+    }
+
+    /// State of charge in percent, the shunt reports 0xFF until it has been synchronized
+    pub fn state_of_charge(&self) -> Option<u8> {
+        if self.state_of_charge > 100 {
+            None
+        } else {
+            Some(self.state_of_charge)
+        }
+    }
+
+    /// Current in amps, signed with positive values while charging
+    pub fn current(&self) -> f32 {
+        f32::from(self.current as i16) / 10f32
+    }
+
+    pub fn power(&self) -> f32 {
+        self.voltage.to_num::<f32>() * self.current()
+    }
+
+    /// Minutes until empty (or full while charging)
+    pub fn time_remaining(&self) -> Option<u16> {
+        if self.time_remaining == 0xFFFF {
+            None
+        } else {
+            Some(self.time_remaining)
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+define_encodable_struct! {
+    BrakingSystemState [4] {
+        device_id: u8 [0],
+        status: u8 [1],
+        output_level: u8 [2],
+        gain: u8 [3],
+    }
+}
+
+#[allow(dead_code)]
+impl BrakingSystemState {
+    pub fn is_trailer_connected(&self) -> bool {
+        (self.status & 0x01) == 0x01
+    }
+
+    pub fn is_braking(&self) -> bool {
+        (self.status & 0x02) == 0x02
+    }
+
+    pub fn is_open_circuit(&self) -> bool {
+        (self.status & 0x10) == 0x10
+    }
+
+    pub fn is_short_circuit(&self) -> bool {
+        (self.status & 0x20) == 0x20
+    }
+
+    pub fn is_overloaded(&self) -> bool {
+        (self.status & 0x40) == 0x40
+    }
+
+    pub fn has_fault(&self) -> bool {
+        (self.status & 0x70) != 0
+    }
+
+    /// Gain setting, reported in tenths
+    pub fn gain(&self) -> f32 {
+        f32::from(self.gain) / 10f32
+    }
+}
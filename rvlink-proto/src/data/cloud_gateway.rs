@@ -0,0 +1,21 @@
+use super::*;
+
+define_encodable_struct! {
+    CloudGatewayState [2] {
+        device_id: u8 [0],
+        status: u8 [1],
+    }
+}
+
+#[allow(dead_code)]
+impl CloudGatewayState {
+    /// The gateway currently has a connection to the cloud service
+    pub fn is_connected(&self) -> bool {
+        (self.status & 0x01) == 0x01
+    }
+
+    /// The gateway has been registered to an account
+    pub fn is_registered(&self) -> bool {
+        (self.status & 0x02) == 0x02
+    }
+}
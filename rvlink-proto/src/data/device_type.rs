@@ -1,5 +1,3 @@
-use super::*;
-
 enum_with_metadata! {
     DeviceType: u8; name:&'static str:0 {
         Unknown = 0 {"Unknown"},
@@ -0,0 +1,32 @@
+use super::*;
+
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum LockState {
+    #[default]
+    #[display(fmt = "unlocked")]
+    Unlocked = 0,
+    #[display(fmt = "locked")]
+    Locked = 1,
+    #[display(fmt = "jammed")]
+    Jammed = 2,
+}
+
+define_encodable_struct! {
+    DoorLockState [2] {
+        device_id: u8 [0],
+        status: u8 [1],
+    }
+}
+
+#[allow(dead_code)]
+impl DoorLockState {
+    pub fn state(&self) -> LockState {
+        // Converting would map the reserved value to the default (unlocked), treat it as jammed instead
+        match self.status & 0x03 {
+            0x00 => LockState::Unlocked,
+            0x01 => LockState::Locked,
+            _ => LockState::Jammed,
+        }
+    }
+}
@@ -1,1859 +1,1891 @@
-use super::*;
+#[derive(Default, Debug, Display, PartialEq, PartialOrd, Clone, Copy)]
+pub enum DtcSeverity {
+    #[default]
+    #[display(fmt = "info")]
+    Info,
+    #[display(fmt = "warning")]
+    Warning,
+    #[display(fmt = "fault")]
+    Fault,
+}
 
-#[allow(dead_code)]
-#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
-#[repr(u16)]
-pub enum DTCID {
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy)]
+pub enum DtcCategory {
     #[default]
-    Unknown,
-    DtcStorageFailure,
-    EcuIsDefective,
-    NvmFailure,
-    ConfigurationFailure,
-    BatteryVoltageLow,
-    BatteryVoltageHigh,
-    BleSubsystemCommError,
-    ModuleOverCurrent,
-    EthernetCommError,
-    TiltSensorMalfunction,
-    LevelerZeroPointNotConfigured,
-    FuseConfigurationInvalid,
-    OutdoorTempSensorOpenCircuit,
-    OutdoorTempSensorShortCircuit,
-    TouchPadCommFailure,
-    RemoteSensorCommFailure,
-    RemoteSensorPowerShortToGround,
-    RemoteSensorFailure,
-    FrontRemoteSensorCommFailure,
-    FrontRemoteSensorPowerShortToGround,
-    FrontRemoteSensorFailure,
-    RearRemoteSensorCommFailure,
-    RearRemoteSensorPowerShortToGround,
-    RearRemoteSensorFailure,
-    HallEffectSignalShortToGnd,
-    HallEffectSignalShortToBatt,
-    HallEffectSignalFault,
-    HallEffectSignalCircuitOpen,
-    CalSwitchShortToGnd,
-    CalSwitchShortToBatt,
-    CalSwitchSwitchStuck,
-    CalSwitchFault,
-    CalSwitchCircuitOpen,
-    FloatSwitchShortToGnd,
-    FloatSwitchShortToBatt,
-    FloatSwitchSwitchStuck,
-    FloatSwitchFault,
-    FloatSwitchCircuitOpen,
-    ClimateZone1TempSensorOpenCircuit,
-    ClimateZone1TempSensorShortCircuit,
-    ClimateZone1FreeSensorOpenCircuit,
-    ClimateZone1FreeSensorShortCircuit,
-    ClimateZone2TempSensorOpenCircuit,
-    ClimateZone2TempSensorShortCircuit,
-    ClimateZone2FreeSensorOpenCircuit,
-    ClimateZone2FreeSensorShortCircuit,
-    ClimateZone1PowerMissing,
-    ClimateZone2PowerMissing,
-    ClimateZone1DipSwitchInvalid,
-    ClimateZone2DipSwitchInvalid,
-    GeneratorFailedToStart,
-    GeneratorFailedToStop,
-    InputShortToGnd,
-    InputShortToBatt,
-    InputSwitchStuck,
-    InputSwitchFault,
-    InputCircuitOpen,
-    Input1ShortToGnd,
-    Input1ShortToBatt,
-    Input1SwitchStuck,
-    Input1SwitchFault,
-    Input1CircuitOpen,
-    Input2ShortToGnd,
-    Input2ShortToBatt,
-    Input2SwitchStuck,
-    Input2SwitchFault,
-    Input2CircuitOpen,
-    Input3ShortToGnd,
-    Input3ShortToBatt,
-    Input3SwitchStuck,
-    Input3SwitchFault,
-    Input3CircuitOpen,
-    Input4ShortToGnd,
-    Input4ShortToBatt,
-    Input4SwitchStuck,
-    Input4SwitchFault,
-    Input4CircuitOpen,
-    Input5ShortToGnd,
-    Input5ShortToBatt,
-    Input5SwitchStuck,
-    Input5SwitchFault,
-    Input5CircuitOpen,
-    Input6ShortToGnd,
-    Input6ShortToBatt,
-    Input6SwitchStuck,
-    Input6SwitchFault,
-    Input6CircuitOpen,
-    Input7ShortToGnd,
-    Input7ShortToBatt,
-    Input7SwitchStuck,
-    Input7SwitchFault,
-    Input7CircuitOpen,
-    Input8ShortToGnd,
-    Input8ShortToBatt,
-    Input8SwitchStuck,
-    Input8SwitchFault,
-    Input8CircuitOpen,
-    Input9ShortToGnd,
-    Input9ShortToBatt,
-    Input9SwitchStuck,
-    Input9SwitchFault,
-    Input9CircuitOpen,
-    Input10ShortToGnd,
-    Input10ShortToBatt,
-    Input10SwitchStuck,
-    Input10SwitchFault,
-    Input10CircuitOpen,
-    Input11ShortToGnd,
-    Input11ShortToBatt,
-    Input11SwitchStuck,
-    Input11SwitchFault,
-    Input11CircuitOpen,
-    Input12ShortToGnd,
-    Input12ShortToBatt,
-    Input12SwitchStuck,
-    Input12SwitchFault,
-    Input12CircuitOpen,
-    Input13ShortToGnd,
-    Input13ShortToBatt,
-    Input13SwitchStuck,
-    Input13SwitchFault,
-    Input13CircuitOpen,
-    Input14ShortToGnd,
-    Input14ShortToBatt,
-    Input14SwitchStuck,
-    Input14SwitchFault,
-    Input14CircuitOpen,
-    Input15ShortToGnd,
-    Input15ShortToBatt,
-    Input15SwitchStuck,
-    Input15SwitchFault,
-    Input15CircuitOpen,
-    Input16ShortToGnd,
-    Input16ShortToBatt,
-    Input16SwitchStuck,
-    Input16SwitchFault,
-    Input16CircuitOpen,
-    OutputShortToBatt,
-    OutputShortToGnd,
-    OutputPlusShortToBatt,
-    OutputPlusShortToGnd,
-    OutputMinusShortToBatt,
-    OutputMinusShortToGnd,
-    OutputCircuitFailure,
-    OutputOpen,
-    OutputShort,
-    OutputOverCurrent,
-    OutputUnderCurrent,
-    Output1ShortToBatt,
-    Output1ShortToGnd,
-    Output1PlusShortToBatt,
-    Output1PlusShortToGnd,
-    Output1MinusShortToBatt,
-    Output1MinusShortToGnd,
-    Output1CircuitFailure,
-    Output1Open,
-    Output1Short,
-    Output1OverCurrent,
-    Output1UnderCurrent,
-    Output2ShortToBatt,
-    Output2ShortToGnd,
-    Output2PlusShortToBatt,
-    Output2PlusShortToGnd,
-    Output2MinusShortToBatt,
-    Output2MinusShortToGnd,
-    Output2CircuitFailure,
-    Output2Open,
-    Output2Short,
-    Output2OverCurrent,
-    Output2UnderCurrent,
-    Output3ShortToBatt,
-    Output3ShortToGnd,
-    Output3PlusShortToBatt,
-    Output3PlusShortToGnd,
-    Output3MinusShortToBatt,
-    Output3MinusShortToGnd,
-    Output3CircuitFailure,
-    Output3Open,
-    Output3Short,
-    Output3OverCurrent,
-    Output3UnderCurrent,
-    Output4ShortToBatt,
-    Output4ShortToGnd,
-    Output4PlusShortToBatt,
-    Output4PlusShortToGnd,
-    Output4MinusShortToBatt,
-    Output4MinusShortToGnd,
-    Output4CircuitFailure,
-    Output4Open,
-    Output4Short,
-    Output4OverCurrent,
-    Output4UnderCurrent,
-    Output5ShortToBatt,
-    Output5ShortToGnd,
-    Output5PlusShortToBatt,
-    Output5PlusShortToGnd,
-    Output5MinusShortToBatt,
-    Output5MinusShortToGnd,
-    Output5CircuitFailure,
-    Output5Open,
-    Output5Short,
-    Output5OverCurrent,
-    Output5UnderCurrent,
-    Output6ShortToBatt,
-    Output6ShortToGnd,
-    Output6PlusShortToBatt,
-    Output6PlusShortToGnd,
-    Output6MinusShortToBatt,
-    Output6MinusShortToGnd,
-    Output6CircuitFailure,
-    Output6Open,
-    Output6Short,
-    Output6OverCurrent,
-    Output6UnderCurrent,
-    Output7ShortToBatt,
-    Output7ShortToGnd,
-    Output7PlusShortToBatt,
-    Output7PlusShortToGnd,
-    Output7MinusShortToBatt,
-    Output7MinusShortToGnd,
-    Output7CircuitFailure,
-    Output7Open,
-    Output7Short,
-    Output7OverCurrent,
-    Output7UnderCurrent,
-    Output8ShortToBatt,
-    Output8ShortToGnd,
-    Output8PlusShortToBatt,
-    Output8PlusShortToGnd,
-    Output8MinusShortToBatt,
-    Output8MinusShortToGnd,
-    Output8CircuitFailure,
-    Output8Open,
-    Output8Short,
-    Output8OverCurrent,
-    Output8UnderCurrent,
-    Output9ShortToBatt,
-    Output9ShortToGnd,
-    Output9PlusShortToBatt,
-    Output9PlusShortToGnd,
-    Output9MinusShortToBatt,
-    Output9MinusShortToGnd,
-    Output9CircuitFailure,
-    Output9Open,
-    Output9Short,
-    Output9OverCurrent,
-    Output9UnderCurrent,
-    Output10ShortToBatt,
-    Output10ShortToGnd,
-    Output10PlusShortToBatt,
-    Output10PlusShortToGnd,
-    Output10MinusShortToBatt,
-    Output10MinusShortToGnd,
-    Output10CircuitFailure,
-    Output10Open,
-    Output10Short,
-    Output10OverCurrent,
-    Output10UnderCurrent,
-    Output11ShortToBatt,
-    Output11ShortToGnd,
-    Output11PlusShortToBatt,
-    Output11PlusShortToGnd,
-    Output11MinusShortToBatt,
-    Output11MinusShortToGnd,
-    Output11CircuitFailure,
-    Output11Open,
-    Output11Short,
-    Output11OverCurrent,
-    Output11UnderCurrent,
-    Output12ShortToBatt,
-    Output12ShortToGnd,
-    Output12PlusShortToBatt,
-    Output12PlusShortToGnd,
-    Output12MinusShortToBatt,
-    Output12MinusShortToGnd,
-    Output12CircuitFailure,
-    Output12Open,
-    Output12Short,
-    Output12OverCurrent,
-    Output12UnderCurrent,
-    Output13ShortToBatt,
-    Output13ShortToGnd,
-    Output13PlusShortToBatt,
-    Output13PlusShortToGnd,
-    Output13MinusShortToBatt,
-    Output13MinusShortToGnd,
-    Output13CircuitFailure,
-    Output13Open,
-    Output13Short,
-    Output13OverCurrent,
-    Output13UnderCurrent,
-    Output14ShortToBatt,
-    Output14ShortToGnd,
-    Output14PlusShortToBatt,
-    Output14PlusShortToGnd,
-    Output14MinusShortToBatt,
-    Output14MinusShortToGnd,
-    Output14CircuitFailure,
-    Output14Open,
-    Output14Short,
-    Output14OverCurrent,
-    Output14UnderCurrent,
-    Output15ShortToBatt,
-    Output15ShortToGnd,
-    Output15PlusShortToBatt,
-    Output15PlusShortToGnd,
-    Output15MinusShortToBatt,
-    Output15MinusShortToGnd,
-    Output15CircuitFailure,
-    Output15Open,
-    Output15Short,
-    Output15OverCurrent,
-    Output15UnderCurrent,
-    Output16ShortToBatt,
-    Output16ShortToGnd,
-    Output16PlusShortToBatt,
-    Output16PlusShortToGnd,
-    Output16MinusShortToBatt,
-    Output16MinusShortToGnd,
-    Output16CircuitFailure,
-    Output16Open,
-    Output16Short,
-    Output16OverCurrent,
-    Output16UnderCurrent,
-    LightOutputShortToBatt,
-    LightOutputShortToGnd,
-    LightOutputPlusShortToBatt,
-    LightOutputPlusShortToGnd,
-    LightOutputMinusShortToBatt,
-    LightOutputMinusShortToGnd,
-    LightOutputCircuitFailure,
-    LightOutputOpen,
-    LightOutputShort,
-    LightOutputOverCurrent,
-    LightOutputUnderCurrent,
-    LightOutput1ShortToBatt,
-    LightOutput1ShortToGnd,
-    LightOutput1PlusShortToBatt,
-    LightOutput1PlusShortToGnd,
-    LightOutput1MinusShortToBatt,
-    LightOutput1MinusShortToGnd,
-    LightOutput1CircuitFailure,
-    LightOutput1Open,
-    LightOutput1Short,
-    LightOutput1OverCurrent,
-    LightOutput1UnderCurrent,
-    LightOutput2ShortToBatt,
-    LightOutput2ShortToGnd,
-    LightOutput2PlusShortToBatt,
-    LightOutput2PlusShortToGnd,
-    LightOutput2MinusShortToBatt,
-    LightOutput2MinusShortToGnd,
-    LightOutput2CircuitFailure,
-    LightOutput2Open,
-    LightOutput2Short,
-    LightOutput2OverCurrent,
-    LightOutput2UnderCurrent,
-    LightOutput3ShortToBatt,
-    LightOutput3ShortToGnd,
-    LightOutput3PlusShortToBatt,
-    LightOutput3PlusShortToGnd,
-    LightOutput3MinusShortToBatt,
-    LightOutput3MinusShortToGnd,
-    LightOutput3CircuitFailure,
-    LightOutput3Open,
-    LightOutput3Short,
-    LightOutput3OverCurrent,
-    LightOutput3UnderCurrent,
-    LightOutput4ShortToBatt,
-    LightOutput4ShortToGnd,
-    LightOutput4PlusShortToBatt,
-    LightOutput4PlusShortToGnd,
-    LightOutput4MinusShortToBatt,
-    LightOutput4MinusShortToGnd,
-    LightOutput4CircuitFailure,
-    LightOutput4Open,
-    LightOutput4Short,
-    LightOutput4OverCurrent,
-    LightOutput4UnderCurrent,
-    LightOutput5ShortToBatt,
-    LightOutput5ShortToGnd,
-    LightOutput5PlusShortToBatt,
-    LightOutput5PlusShortToGnd,
-    LightOutput5MinusShortToBatt,
-    LightOutput5MinusShortToGnd,
-    LightOutput5CircuitFailure,
-    LightOutput5Open,
-    LightOutput5Short,
-    LightOutput5OverCurrent,
-    LightOutput5UnderCurrent,
-    LightOutput6ShortToBatt,
-    LightOutput6ShortToGnd,
-    LightOutput6PlusShortToBatt,
-    LightOutput6PlusShortToGnd,
-    LightOutput6MinusShortToBatt,
-    LightOutput6MinusShortToGnd,
-    LightOutput6CircuitFailure,
-    LightOutput6Open,
-    LightOutput6Short,
-    LightOutput6OverCurrent,
-    LightOutput6UnderCurrent,
-    LightOutput7ShortToBatt,
-    LightOutput7ShortToGnd,
-    LightOutput7PlusShortToBatt,
-    LightOutput7PlusShortToGnd,
-    LightOutput7MinusShortToBatt,
-    LightOutput7MinusShortToGnd,
-    LightOutput7CircuitFailure,
-    LightOutput7Open,
-    LightOutput7Short,
-    LightOutput7OverCurrent,
-    LightOutput7UnderCurrent,
-    LightOutput8ShortToBatt,
-    LightOutput8ShortToGnd,
-    LightOutput8PlusShortToBatt,
-    LightOutput8PlusShortToGnd,
-    LightOutput8MinusShortToBatt,
-    LightOutput8MinusShortToGnd,
-    LightOutput8CircuitFailure,
-    LightOutput8Open,
-    LightOutput8Short,
-    LightOutput8OverCurrent,
-    LightOutput8UnderCurrent,
-    LightOutput9ShortToBatt,
-    LightOutput9ShortToGnd,
-    LightOutput9PlusShortToBatt,
-    LightOutput9PlusShortToGnd,
-    LightOutput9MinusShortToBatt,
-    LightOutput9MinusShortToGnd,
-    LightOutput9CircuitFailure,
-    LightOutput9Open,
-    LightOutput9Short,
-    LightOutput9OverCurrent,
-    LightOutput9UnderCurrent,
-    LightOutput10ShortToBatt,
-    LightOutput10ShortToGnd,
-    LightOutput10PlusShortToBatt,
-    LightOutput10PlusShortToGnd,
-    LightOutput10MinusShortToBatt,
-    LightOutput10MinusShortToGnd,
-    LightOutput10CircuitFailure,
-    LightOutput10Open,
-    LightOutput10Short,
-    LightOutput10OverCurrent,
-    LightOutput10UnderCurrent,
-    LightOutput11ShortToBatt,
-    LightOutput11ShortToGnd,
-    LightOutput11PlusShortToBatt,
-    LightOutput11PlusShortToGnd,
-    LightOutput11MinusShortToBatt,
-    LightOutput11MinusShortToGnd,
-    LightOutput11CircuitFailure,
-    LightOutput11Open,
-    LightOutput11Short,
-    LightOutput11OverCurrent,
-    LightOutput11UnderCurrent,
-    LightOutput12ShortToBatt,
-    LightOutput12ShortToGnd,
-    LightOutput12PlusShortToBatt,
-    LightOutput12PlusShortToGnd,
-    LightOutput12MinusShortToBatt,
-    LightOutput12MinusShortToGnd,
-    LightOutput12CircuitFailure,
-    LightOutput12Open,
-    LightOutput12Short,
-    LightOutput12OverCurrent,
-    LightOutput12UnderCurrent,
-    LightOutput13ShortToBatt,
-    LightOutput13ShortToGnd,
-    LightOutput13PlusShortToBatt,
-    LightOutput13PlusShortToGnd,
-    LightOutput13MinusShortToBatt,
-    LightOutput13MinusShortToGnd,
-    LightOutput13CircuitFailure,
-    LightOutput13Open,
-    LightOutput13Short,
-    LightOutput13OverCurrent,
-    LightOutput13UnderCurrent,
-    LightOutput14ShortToBatt,
-    LightOutput14ShortToGnd,
-    LightOutput14PlusShortToBatt,
-    LightOutput14PlusShortToGnd,
-    LightOutput14MinusShortToBatt,
-    LightOutput14MinusShortToGnd,
-    LightOutput14CircuitFailure,
-    LightOutput14Open,
-    LightOutput14Short,
-    LightOutput14OverCurrent,
-    LightOutput14UnderCurrent,
-    LightOutput15ShortToBatt,
-    LightOutput15ShortToGnd,
-    LightOutput15PlusShortToBatt,
-    LightOutput15PlusShortToGnd,
-    LightOutput15MinusShortToBatt,
-    LightOutput15MinusShortToGnd,
-    LightOutput15CircuitFailure,
-    LightOutput15Open,
-    LightOutput15Short,
-    LightOutput15OverCurrent,
-    LightOutput15UnderCurrent,
-    LightOutput16ShortToBatt,
-    LightOutput16ShortToGnd,
-    LightOutput16PlusShortToBatt,
-    LightOutput16PlusShortToGnd,
-    LightOutput16MinusShortToBatt,
-    LightOutput16MinusShortToGnd,
-    LightOutput16CircuitFailure,
-    LightOutput16Open,
-    LightOutput16Short,
-    LightOutput16OverCurrent,
-    LightOutput16UnderCurrent,
-    RgbOutputRShortToBatt,
-    RgbOutputRShortToGnd,
-    RgbOutputRPlusShortToBatt,
-    RgbOutputRPlusShortToGnd,
-    RgbOutputRMinusShortToBatt,
-    RgbOutputRMinusShortToGnd,
-    RgbOutputRCircuitFailure,
-    RgbOutputROpen,
-    RgbOutputRShort,
-    RgbOutputROverCurrent,
-    RgbOutputRUnderCurrent,
-    RgbOutputGShortToBatt,
-    RgbOutputGShortToGnd,
-    RgbOutputGPlusShortToBatt,
-    RgbOutputGPlusShortToGnd,
-    RgbOutputGMinusShortToBatt,
-    RgbOutputGMinusShortToGnd,
-    RgbOutputGCircuitFailure,
-    RgbOutputGOpen,
-    RgbOutputGShort,
-    RgbOutputGOverCurrent,
-    RgbOutputGUnderCurrent,
-    RgbOutputBShortToBatt,
-    RgbOutputBShortToGnd,
-    RgbOutputBPlusShortToBatt,
-    RgbOutputBPlusShortToGnd,
-    RgbOutputBMinusShortToBatt,
-    RgbOutputBMinusShortToGnd,
-    RgbOutputBCircuitFailure,
-    RgbOutputBOpen,
-    RgbOutputBShort,
-    RgbOutputBOverCurrent,
-    RgbOutputBUnderCurrent,
-    RgbOutputR1ShortToBatt,
-    RgbOutputR1ShortToGnd,
-    RgbOutputR1PlusShortToBatt,
-    RgbOutputR1PlusShortToGnd,
-    RgbOutputR1MinusShortToBatt,
-    RgbOutputR1MinusShortToGnd,
-    RgbOutputR1CircuitFailure,
-    RgbOutputR1Open,
-    RgbOutputR1Short,
-    RgbOutputR1OverCurrent,
-    RgbOutputR1UnderCurrent,
-    RgbOutputG1ShortToBatt,
-    RgbOutputG1ShortToGnd,
-    RgbOutputG1PlusShortToBatt,
-    RgbOutputG1PlusShortToGnd,
-    RgbOutputG1MinusShortToBatt,
-    RgbOutputG1MinusShortToGnd,
-    RgbOutputG1CircuitFailure,
-    RgbOutputG1Open,
-    RgbOutputG1Short,
-    RgbOutputG1OverCurrent,
-    RgbOutputG1UnderCurrent,
-    RgbOutputB1ShortToBatt,
-    RgbOutputB1ShortToGnd,
-    RgbOutputB1PlusShortToBatt,
-    RgbOutputB1PlusShortToGnd,
-    RgbOutputB1MinusShortToBatt,
-    RgbOutputB1MinusShortToGnd,
-    RgbOutputB1CircuitFailure,
-    RgbOutputB1Open,
-    RgbOutputB1Short,
-    RgbOutputB1OverCurrent,
-    RgbOutputB1UnderCurrent,
-    RgbOutputR2ShortToBatt,
-    RgbOutputR2ShortToGnd,
-    RgbOutputR2PlusShortToBatt,
-    RgbOutputR2PlusShortToGnd,
-    RgbOutputR2MinusShortToBatt,
-    RgbOutputR2MinusShortToGnd,
-    RgbOutputR2CircuitFailure,
-    RgbOutputR2Open,
-    RgbOutputR2Short,
-    RgbOutputR2OverCurrent,
-    RgbOutputR2UnderCurrent,
-    RgbOutputG2ShortToBatt,
-    RgbOutputG2ShortToGnd,
-    RgbOutputG2PlusShortToBatt,
-    RgbOutputG2PlusShortToGnd,
-    RgbOutputG2MinusShortToBatt,
-    RgbOutputG2MinusShortToGnd,
-    RgbOutputG2CircuitFailure,
-    RgbOutputG2Open,
-    RgbOutputG2Short,
-    RgbOutputG2OverCurrent,
-    RgbOutputG2UnderCurrent,
-    RgbOutputB2ShortToBatt,
-    RgbOutputB2ShortToGnd,
-    RgbOutputB2PlusShortToBatt,
-    RgbOutputB2PlusShortToGnd,
-    RgbOutputB2MinusShortToBatt,
-    RgbOutputB2MinusShortToGnd,
-    RgbOutputB2CircuitFailure,
-    RgbOutputB2Open,
-    RgbOutputB2Short,
-    RgbOutputB2OverCurrent,
-    RgbOutputB2UnderCurrent,
-    RgbOutputR3ShortToBatt,
-    RgbOutputR3ShortToGnd,
-    RgbOutputR3PlusShortToBatt,
-    RgbOutputR3PlusShortToGnd,
-    RgbOutputR3MinusShortToBatt,
-    RgbOutputR3MinusShortToGnd,
-    RgbOutputR3CircuitFailure,
-    RgbOutputR3Open,
-    RgbOutputR3Short,
-    RgbOutputR3OverCurrent,
-    RgbOutputR3UnderCurrent,
-    RgbOutputG3ShortToBatt,
-    RgbOutputG3ShortToGnd,
-    RgbOutputG3PlusShortToBatt,
-    RgbOutputG3PlusShortToGnd,
-    RgbOutputG3MinusShortToBatt,
-    RgbOutputG3MinusShortToGnd,
-    RgbOutputG3CircuitFailure,
-    RgbOutputG3Open,
-    RgbOutputG3Short,
-    RgbOutputG3OverCurrent,
-    RgbOutputG3UnderCurrent,
-    RgbOutputB3ShortToBatt,
-    RgbOutputB3ShortToGnd,
-    RgbOutputB3PlusShortToBatt,
-    RgbOutputB3PlusShortToGnd,
-    RgbOutputB3MinusShortToBatt,
-    RgbOutputB3MinusShortToGnd,
-    RgbOutputB3CircuitFailure,
-    RgbOutputB3Open,
-    RgbOutputB3Short,
-    RgbOutputB3OverCurrent,
-    RgbOutputB3UnderCurrent,
-    RgbOutputR4ShortToBatt,
-    RgbOutputR4ShortToGnd,
-    RgbOutputR4PlusShortToBatt,
-    RgbOutputR4PlusShortToGnd,
-    RgbOutputR4MinusShortToBatt,
-    RgbOutputR4MinusShortToGnd,
-    RgbOutputR4CircuitFailure,
-    RgbOutputR4Open,
-    RgbOutputR4Short,
-    RgbOutputR4OverCurrent,
-    RgbOutputR4UnderCurrent,
-    RgbOutputG4ShortToBatt,
-    RgbOutputG4ShortToGnd,
-    RgbOutputG4PlusShortToBatt,
-    RgbOutputG4PlusShortToGnd,
-    RgbOutputG4MinusShortToBatt,
-    RgbOutputG4MinusShortToGnd,
-    RgbOutputG4CircuitFailure,
-    RgbOutputG4Open,
-    RgbOutputG4Short,
-    RgbOutputG4OverCurrent,
-    RgbOutputG4UnderCurrent,
-    RgbOutputB4ShortToBatt,
-    RgbOutputB4ShortToGnd,
-    RgbOutputB4PlusShortToBatt,
-    RgbOutputB4PlusShortToGnd,
-    RgbOutputB4MinusShortToBatt,
-    RgbOutputB4MinusShortToGnd,
-    RgbOutputB4CircuitFailure,
-    RgbOutputB4Open,
-    RgbOutputB4Short,
-    RgbOutputB4OverCurrent,
-    RgbOutputB4UnderCurrent,
-    RgbOutputR5ShortToBatt,
-    RgbOutputR5ShortToGnd,
-    RgbOutputR5PlusShortToBatt,
-    RgbOutputR5PlusShortToGnd,
-    RgbOutputR5MinusShortToBatt,
-    RgbOutputR5MinusShortToGnd,
-    RgbOutputR5CircuitFailure,
-    RgbOutputR5Open,
-    RgbOutputR5Short,
-    RgbOutputR5OverCurrent,
-    RgbOutputR5UnderCurrent,
-    RgbOutputG5ShortToBatt,
-    RgbOutputG5ShortToGnd,
-    RgbOutputG5PlusShortToBatt,
-    RgbOutputG5PlusShortToGnd,
-    RgbOutputG5MinusShortToBatt,
-    RgbOutputG5MinusShortToGnd,
-    RgbOutputG5CircuitFailure,
-    RgbOutputG5Open,
-    RgbOutputG5Short,
-    RgbOutputG5OverCurrent,
-    RgbOutputG5UnderCurrent,
-    RgbOutputB5ShortToBatt,
-    RgbOutputB5ShortToGnd,
-    RgbOutputB5PlusShortToBatt,
-    RgbOutputB5PlusShortToGnd,
-    RgbOutputB5MinusShortToBatt,
-    RgbOutputB5MinusShortToGnd,
-    RgbOutputB5CircuitFailure,
-    RgbOutputB5Open,
-    RgbOutputB5Short,
-    RgbOutputB5OverCurrent,
-    RgbOutputB5UnderCurrent,
-    RgbOutputR6ShortToBatt,
-    RgbOutputR6ShortToGnd,
-    RgbOutputR6PlusShortToBatt,
-    RgbOutputR6PlusShortToGnd,
-    RgbOutputR6MinusShortToBatt,
-    RgbOutputR6MinusShortToGnd,
-    RgbOutputR6CircuitFailure,
-    RgbOutputR6Open,
-    RgbOutputR6Short,
-    RgbOutputR6OverCurrent,
-    RgbOutputR6UnderCurrent,
-    RgbOutputG6ShortToBatt,
-    RgbOutputG6ShortToGnd,
-    RgbOutputG6PlusShortToBatt,
-    RgbOutputG6PlusShortToGnd,
-    RgbOutputG6MinusShortToBatt,
-    RgbOutputG6MinusShortToGnd,
-    RgbOutputG6CircuitFailure,
-    RgbOutputG6Open,
-    RgbOutputG6Short,
-    RgbOutputG6OverCurrent,
-    RgbOutputG6UnderCurrent,
-    RgbOutputB6ShortToBatt,
-    RgbOutputB6ShortToGnd,
-    RgbOutputB6PlusShortToBatt,
-    RgbOutputB6PlusShortToGnd,
-    RgbOutputB6MinusShortToBatt,
-    RgbOutputB6MinusShortToGnd,
-    RgbOutputB6CircuitFailure,
-    RgbOutputB6Open,
-    RgbOutputB6Short,
-    RgbOutputB6OverCurrent,
-    RgbOutputB6UnderCurrent,
-    RgbOutputR7ShortToBatt,
-    RgbOutputR7ShortToGnd,
-    RgbOutputR7PlusShortToBatt,
-    RgbOutputR7PlusShortToGnd,
-    RgbOutputR7MinusShortToBatt,
-    RgbOutputR7MinusShortToGnd,
-    RgbOutputR7CircuitFailure,
-    RgbOutputR7Open,
-    RgbOutputR7Short,
-    RgbOutputR7OverCurrent,
-    RgbOutputR7UnderCurrent,
-    RgbOutputG7ShortToBatt,
-    RgbOutputG7ShortToGnd,
-    RgbOutputG7PlusShortToBatt,
-    RgbOutputG7PlusShortToGnd,
-    RgbOutputG7MinusShortToBatt,
-    RgbOutputG7MinusShortToGnd,
-    RgbOutputG7CircuitFailure,
-    RgbOutputG7Open,
-    RgbOutputG7Short,
-    RgbOutputG7OverCurrent,
-    RgbOutputG7UnderCurrent,
-    RgbOutputB7ShortToBatt,
-    RgbOutputB7ShortToGnd,
-    RgbOutputB7PlusShortToBatt,
-    RgbOutputB7PlusShortToGnd,
-    RgbOutputB7MinusShortToBatt,
-    RgbOutputB7MinusShortToGnd,
-    RgbOutputB7CircuitFailure,
-    RgbOutputB7Open,
-    RgbOutputB7Short,
-    RgbOutputB7OverCurrent,
-    RgbOutputB7UnderCurrent,
-    RgbOutputR8ShortToBatt,
-    RgbOutputR8ShortToGnd,
-    RgbOutputR8PlusShortToBatt,
-    RgbOutputR8PlusShortToGnd,
-    RgbOutputR8MinusShortToBatt,
-    RgbOutputR8MinusShortToGnd,
-    RgbOutputR8CircuitFailure,
-    RgbOutputR8Open,
-    RgbOutputR8Short,
-    RgbOutputR8OverCurrent,
-    RgbOutputR8UnderCurrent,
-    RgbOutputG8ShortToBatt,
-    RgbOutputG8ShortToGnd,
-    RgbOutputG8PlusShortToBatt,
-    RgbOutputG8PlusShortToGnd,
-    RgbOutputG8MinusShortToBatt,
-    RgbOutputG8MinusShortToGnd,
-    RgbOutputG8CircuitFailure,
-    RgbOutputG8Open,
-    RgbOutputG8Short,
-    RgbOutputG8OverCurrent,
-    RgbOutputG8UnderCurrent,
-    RgbOutputB8ShortToBatt,
-    RgbOutputB8ShortToGnd,
-    RgbOutputB8PlusShortToBatt,
-    RgbOutputB8PlusShortToGnd,
-    RgbOutputB8MinusShortToBatt,
-    RgbOutputB8MinusShortToGnd,
-    RgbOutputB8CircuitFailure,
-    RgbOutputB8Open,
-    RgbOutputB8Short,
-    RgbOutputB8OverCurrent,
-    RgbOutputB8UnderCurrent,
-    RgbOutputR9ShortToBatt,
-    RgbOutputR9ShortToGnd,
-    RgbOutputR9PlusShortToBatt,
-    RgbOutputR9PlusShortToGnd,
-    RgbOutputR9MinusShortToBatt,
-    RgbOutputR9MinusShortToGnd,
-    RgbOutputR9CircuitFailure,
-    RgbOutputR9Open,
-    RgbOutputR9Short,
-    RgbOutputR9OverCurrent,
-    RgbOutputR9UnderCurrent,
-    RgbOutputG9ShortToBatt,
-    RgbOutputG9ShortToGnd,
-    RgbOutputG9PlusShortToBatt,
-    RgbOutputG9PlusShortToGnd,
-    RgbOutputG9MinusShortToBatt,
-    RgbOutputG9MinusShortToGnd,
-    RgbOutputG9CircuitFailure,
-    RgbOutputG9Open,
-    RgbOutputG9Short,
-    RgbOutputG9OverCurrent,
-    RgbOutputG9UnderCurrent,
-    RgbOutputB9ShortToBatt,
-    RgbOutputB9ShortToGnd,
-    RgbOutputB9PlusShortToBatt,
-    RgbOutputB9PlusShortToGnd,
-    RgbOutputB9MinusShortToBatt,
-    RgbOutputB9MinusShortToGnd,
-    RgbOutputB9CircuitFailure,
-    RgbOutputB9Open,
-    RgbOutputB9Short,
-    RgbOutputB9OverCurrent,
-    RgbOutputB9UnderCurrent,
-    RgbOutputR10ShortToBatt,
-    RgbOutputR10ShortToGnd,
-    RgbOutputR10PlusShortToBatt,
-    RgbOutputR10PlusShortToGnd,
-    RgbOutputR10MinusShortToBatt,
-    RgbOutputR10MinusShortToGnd,
-    RgbOutputR10CircuitFailure,
-    RgbOutputR10Open,
-    RgbOutputR10Short,
-    RgbOutputR10OverCurrent,
-    RgbOutputR10UnderCurrent,
-    RgbOutputG10ShortToBatt,
-    RgbOutputG10ShortToGnd,
-    RgbOutputG10PlusShortToBatt,
-    RgbOutputG10PlusShortToGnd,
-    RgbOutputG10MinusShortToBatt,
-    RgbOutputG10MinusShortToGnd,
-    RgbOutputG10CircuitFailure,
-    RgbOutputG10Open,
-    RgbOutputG10Short,
-    RgbOutputG10OverCurrent,
-    RgbOutputG10UnderCurrent,
-    RgbOutputB10ShortToBatt,
-    RgbOutputB10ShortToGnd,
-    RgbOutputB10PlusShortToBatt,
-    RgbOutputB10PlusShortToGnd,
-    RgbOutputB10MinusShortToBatt,
-    RgbOutputB10MinusShortToGnd,
-    RgbOutputB10CircuitFailure,
-    RgbOutputB10Open,
-    RgbOutputB10Short,
-    RgbOutputB10OverCurrent,
-    RgbOutputB10UnderCurrent,
-    RgbOutputR11ShortToBatt,
-    RgbOutputR11ShortToGnd,
-    RgbOutputR11PlusShortToBatt,
-    RgbOutputR11PlusShortToGnd,
-    RgbOutputR11MinusShortToBatt,
-    RgbOutputR11MinusShortToGnd,
-    RgbOutputR11CircuitFailure,
-    RgbOutputR11Open,
-    RgbOutputR11Short,
-    RgbOutputR11OverCurrent,
-    RgbOutputR11UnderCurrent,
-    RgbOutputG11ShortToBatt,
-    RgbOutputG11ShortToGnd,
-    RgbOutputG11PlusShortToBatt,
-    RgbOutputG11PlusShortToGnd,
-    RgbOutputG11MinusShortToBatt,
-    RgbOutputG11MinusShortToGnd,
-    RgbOutputG11CircuitFailure,
-    RgbOutputG11Open,
-    RgbOutputG11Short,
-    RgbOutputG11OverCurrent,
-    RgbOutputG11UnderCurrent,
-    RgbOutputB11ShortToBatt,
-    RgbOutputB11ShortToGnd,
-    RgbOutputB11PlusShortToBatt,
-    RgbOutputB11PlusShortToGnd,
-    RgbOutputB11MinusShortToBatt,
-    RgbOutputB11MinusShortToGnd,
-    RgbOutputB11CircuitFailure,
-    RgbOutputB11Open,
-    RgbOutputB11Short,
-    RgbOutputB11OverCurrent,
-    RgbOutputB11UnderCurrent,
-    RgbOutputR12ShortToBatt,
-    RgbOutputR12ShortToGnd,
-    RgbOutputR12PlusShortToBatt,
-    RgbOutputR12PlusShortToGnd,
-    RgbOutputR12MinusShortToBatt,
-    RgbOutputR12MinusShortToGnd,
-    RgbOutputR12CircuitFailure,
-    RgbOutputR12Open,
-    RgbOutputR12Short,
-    RgbOutputR12OverCurrent,
-    RgbOutputR12UnderCurrent,
-    RgbOutputG12ShortToBatt,
-    RgbOutputG12ShortToGnd,
-    RgbOutputG12PlusShortToBatt,
-    RgbOutputG12PlusShortToGnd,
-    RgbOutputG12MinusShortToBatt,
-    RgbOutputG12MinusShortToGnd,
-    RgbOutputG12CircuitFailure,
-    RgbOutputG12Open,
-    RgbOutputG12Short,
-    RgbOutputG12OverCurrent,
-    RgbOutputG12UnderCurrent,
-    RgbOutputB12ShortToBatt,
-    RgbOutputB12ShortToGnd,
-    RgbOutputB12PlusShortToBatt,
-    RgbOutputB12PlusShortToGnd,
-    RgbOutputB12MinusShortToBatt,
-    RgbOutputB12MinusShortToGnd,
-    RgbOutputB12CircuitFailure,
-    RgbOutputB12Open,
-    RgbOutputB12Short,
-    RgbOutputB12OverCurrent,
-    RgbOutputB12UnderCurrent,
-    RgbOutputR13ShortToBatt,
-    RgbOutputR13ShortToGnd,
-    RgbOutputR13PlusShortToBatt,
-    RgbOutputR13PlusShortToGnd,
-    RgbOutputR13MinusShortToBatt,
-    RgbOutputR13MinusShortToGnd,
-    RgbOutputR13CircuitFailure,
-    RgbOutputR13Open,
-    RgbOutputR13Short,
-    RgbOutputR13OverCurrent,
-    RgbOutputR13UnderCurrent,
-    RgbOutputG13ShortToBatt,
-    RgbOutputG13ShortToGnd,
-    RgbOutputG13PlusShortToBatt,
-    RgbOutputG13PlusShortToGnd,
-    RgbOutputG13MinusShortToBatt,
-    RgbOutputG13MinusShortToGnd,
-    RgbOutputG13CircuitFailure,
-    RgbOutputG13Open,
-    RgbOutputG13Short,
-    RgbOutputG13OverCurrent,
-    RgbOutputG13UnderCurrent,
-    RgbOutputB13ShortToBatt,
-    RgbOutputB13ShortToGnd,
-    RgbOutputB13PlusShortToBatt,
-    RgbOutputB13PlusShortToGnd,
-    RgbOutputB13MinusShortToBatt,
-    RgbOutputB13MinusShortToGnd,
-    RgbOutputB13CircuitFailure,
-    RgbOutputB13Open,
-    RgbOutputB13Short,
-    RgbOutputB13OverCurrent,
-    RgbOutputB13UnderCurrent,
-    RgbOutputR14ShortToBatt,
-    RgbOutputR14ShortToGnd,
-    RgbOutputR14PlusShortToBatt,
-    RgbOutputR14PlusShortToGnd,
-    RgbOutputR14MinusShortToBatt,
-    RgbOutputR14MinusShortToGnd,
-    RgbOutputR14CircuitFailure,
-    RgbOutputR14Open,
-    RgbOutputR14Short,
-    RgbOutputR14OverCurrent,
-    RgbOutputR14UnderCurrent,
-    RgbOutputG14ShortToBatt,
-    RgbOutputG14ShortToGnd,
-    RgbOutputG14PlusShortToBatt,
-    RgbOutputG14PlusShortToGnd,
-    RgbOutputG14MinusShortToBatt,
-    RgbOutputG14MinusShortToGnd,
-    RgbOutputG14CircuitFailure,
-    RgbOutputG14Open,
-    RgbOutputG14Short,
-    RgbOutputG14OverCurrent,
-    RgbOutputG14UnderCurrent,
-    RgbOutputB14ShortToBatt,
-    RgbOutputB14ShortToGnd,
-    RgbOutputB14PlusShortToBatt,
-    RgbOutputB14PlusShortToGnd,
-    RgbOutputB14MinusShortToBatt,
-    RgbOutputB14MinusShortToGnd,
-    RgbOutputB14CircuitFailure,
-    RgbOutputB14Open,
-    RgbOutputB14Short,
-    RgbOutputB14OverCurrent,
-    RgbOutputB14UnderCurrent,
-    RgbOutputR15ShortToBatt,
-    RgbOutputR15ShortToGnd,
-    RgbOutputR15PlusShortToBatt,
-    RgbOutputR15PlusShortToGnd,
-    RgbOutputR15MinusShortToBatt,
-    RgbOutputR15MinusShortToGnd,
-    RgbOutputR15CircuitFailure,
-    RgbOutputR15Open,
-    RgbOutputR15Short,
-    RgbOutputR15OverCurrent,
-    RgbOutputR15UnderCurrent,
-    RgbOutputG15ShortToBatt,
-    RgbOutputG15ShortToGnd,
-    RgbOutputG15PlusShortToBatt,
-    RgbOutputG15PlusShortToGnd,
-    RgbOutputG15MinusShortToBatt,
-    RgbOutputG15MinusShortToGnd,
-    RgbOutputG15CircuitFailure,
-    RgbOutputG15Open,
-    RgbOutputG15Short,
-    RgbOutputG15OverCurrent,
-    RgbOutputG15UnderCurrent,
-    RgbOutputB15ShortToBatt,
-    RgbOutputB15ShortToGnd,
-    RgbOutputB15PlusShortToBatt,
-    RgbOutputB15PlusShortToGnd,
-    RgbOutputB15MinusShortToBatt,
-    RgbOutputB15MinusShortToGnd,
-    RgbOutputB15CircuitFailure,
-    RgbOutputB15Open,
-    RgbOutputB15Short,
-    RgbOutputB15OverCurrent,
-    RgbOutputB15UnderCurrent,
-    RgbOutputR16ShortToBatt,
-    RgbOutputR16ShortToGnd,
-    RgbOutputR16PlusShortToBatt,
-    RgbOutputR16PlusShortToGnd,
-    RgbOutputR16MinusShortToBatt,
-    RgbOutputR16MinusShortToGnd,
-    RgbOutputR16CircuitFailure,
-    RgbOutputR16Open,
-    RgbOutputR16Short,
-    RgbOutputR16OverCurrent,
-    RgbOutputR16UnderCurrent,
-    RgbOutputG16ShortToBatt,
-    RgbOutputG16ShortToGnd,
-    RgbOutputG16PlusShortToBatt,
-    RgbOutputG16PlusShortToGnd,
-    RgbOutputG16MinusShortToBatt,
-    RgbOutputG16MinusShortToGnd,
-    RgbOutputG16CircuitFailure,
-    RgbOutputG16Open,
-    RgbOutputG16Short,
-    RgbOutputG16OverCurrent,
-    RgbOutputG16UnderCurrent,
-    RgbOutputB16ShortToBatt,
-    RgbOutputB16ShortToGnd,
-    RgbOutputB16PlusShortToBatt,
-    RgbOutputB16PlusShortToGnd,
-    RgbOutputB16MinusShortToBatt,
-    RgbOutputB16MinusShortToGnd,
-    RgbOutputB16CircuitFailure,
-    RgbOutputB16Open,
-    RgbOutputB16Short,
-    RgbOutputB16OverCurrent,
-    RgbOutputB16UnderCurrent,
-    HallEffectPowerShortToBatt,
-    HallEffectPowerShortToGnd,
-    HallEffectPowerPlusShortToBatt,
-    HallEffectPowerPlusShortToGnd,
-    HallEffectPowerMinusShortToBatt,
-    HallEffectPowerMinusShortToGnd,
-    HallEffectPowerCircuitFailure,
-    HallEffectPowerOpen,
-    HallEffectPowerShort,
-    HallEffectPowerOverCurrent,
-    HallEffectPowerUnderCurrent,
-    JackLfShortToBatt,
-    JackLfShortToGnd,
-    JackLfPlusShortToBatt,
-    JackLfPlusShortToGnd,
-    JackLfMinusShortToBatt,
-    JackLfMinusShortToGnd,
-    JackLfCircuitFailure,
-    JackLfOpen,
-    JackLfShort,
-    JackLfOverCurrent,
-    JackLfUnderCurrent,
-    JackLfOutOfStroke,
-    JackLfHallEffectSignalLost,
-    JackLfPositionLost,
-    JackLmShortToBatt,
-    JackLmShortToGnd,
-    JackLmPlusShortToBatt,
-    JackLmPlusShortToGnd,
-    JackLmMinusShortToBatt,
-    JackLmMinusShortToGnd,
-    JackLmCircuitFailure,
-    JackLmOpen,
-    JackLmShort,
-    JackLmOverCurrent,
-    JackLmUnderCurrent,
-    JackLmOutOfStroke,
-    JackLmHallEffectSignalLost,
-    JackLmPositionLost,
-    JackLrShortToBatt,
-    JackLrShortToGnd,
-    JackLrPlusShortToBatt,
-    JackLrPlusShortToGnd,
-    JackLrMinusShortToBatt,
-    JackLrMinusShortToGnd,
-    JackLrCircuitFailure,
-    JackLrOpen,
-    JackLrShort,
-    JackLrOverCurrent,
-    JackLrUnderCurrent,
-    JackLrOutOfStroke,
-    JackLrHallEffectSignalLost,
-    JackLrPositionLost,
-    JackRfShortToBatt,
-    JackRfShortToGnd,
-    JackRfPlusShortToBatt,
-    JackRfPlusShortToGnd,
-    JackRfMinusShortToBatt,
-    JackRfMinusShortToGnd,
-    JackRfCircuitFailure,
-    JackRfOpen,
-    JackRfShort,
-    JackRfOverCurrent,
-    JackRfUnderCurrent,
-    JackRfOutOfStroke,
-    JackRfHallEffectSignalLost,
-    JackRfPositionLost,
-    JackRmShortToBatt,
-    JackRmShortToGnd,
-    JackRmPlusShortToBatt,
-    JackRmPlusShortToGnd,
-    JackRmMinusShortToBatt,
-    JackRmMinusShortToGnd,
-    JackRmCircuitFailure,
-    JackRmOpen,
-    JackRmShort,
-    JackRmOverCurrent,
-    JackRmUnderCurrent,
-    JackRmOutOfStroke,
-    JackRmHallEffectSignalLost,
-    JackRmPositionLost,
-    JackRrShortToBatt,
-    JackRrShortToGnd,
-    JackRrPlusShortToBatt,
-    JackRrPlusShortToGnd,
-    JackRrMinusShortToBatt,
-    JackRrMinusShortToGnd,
-    JackRrCircuitFailure,
-    JackRrOpen,
-    JackRrShort,
-    JackRrOverCurrent,
-    JackRrUnderCurrent,
-    JackRrOutOfStroke,
-    JackRrHallEffectSignalLost,
-    JackRrPositionLost,
-    TongueJackShortToBatt,
-    TongueJackShortToGnd,
-    TongueJackPlusShortToBatt,
-    TongueJackPlusShortToGnd,
-    TongueJackMinusShortToBatt,
-    TongueJackMinusShortToGnd,
-    TongueJackCircuitFailure,
-    TongueJackOpen,
-    TongueJackShort,
-    TongueJackOverCurrent,
-    TongueJackUnderCurrent,
-    TongueJackOutOfStroke,
-    TongueJackHallEffectSignalLost,
-    TongueJackPositionLost,
-    StabilizerJackShortToBatt,
-    StabilizerJackShortToGnd,
-    StabilizerJackPlusShortToBatt,
-    StabilizerJackPlusShortToGnd,
-    StabilizerJackMinusShortToBatt,
-    StabilizerJackMinusShortToGnd,
-    StabilizerJackCircuitFailure,
-    StabilizerJackOpen,
-    StabilizerJackShort,
-    StabilizerJackOverCurrent,
-    StabilizerJackUnderCurrent,
-    StabilizerJackOutOfStroke,
-    StabilizerJackHallEffectSignalLost,
-    StabilizerJackPositionLost,
-    FuseOpen,
-    Fuse1Open,
-    Fuse2Open,
-    Fuse3Open,
-    Fuse4Open,
-    Fuse5Open,
-    Fuse6Open,
-    Fuse7Open,
-    Fuse8Open,
-    Fuse9Open,
-    Fuse10Open,
-    Fuse11Open,
-    Fuse12Open,
-    Fuse13Open,
-    Fuse14Open,
-    Fuse15Open,
-    Fuse16Open,
-    Fuse17Open,
-    Fuse18Open,
-    Fuse19Open,
-    Fuse20Open,
-    InputFuseOpen,
-    Input1FuseOpen,
-    Input2FuseOpen,
-    Input3FuseOpen,
-    Input4FuseOpen,
-    Input5FuseOpen,
-    Input6FuseOpen,
-    Input7FuseOpen,
-    Input8FuseOpen,
-    Input9FuseOpen,
-    Input10FuseOpen,
-    Input11FuseOpen,
-    Input12FuseOpen,
-    Input13FuseOpen,
-    Input14FuseOpen,
-    Input15FuseOpen,
-    Input16FuseOpen,
-    Input17FuseOpen,
-    Input18FuseOpen,
-    Input19FuseOpen,
-    Input20FuseOpen,
-    OutputFuseOpen,
-    Output1FuseOpen,
-    Output2FuseOpen,
-    Output3FuseOpen,
-    Output4FuseOpen,
-    Output5FuseOpen,
-    Output6FuseOpen,
-    Output7FuseOpen,
-    Output8FuseOpen,
-    Output9FuseOpen,
-    Output10FuseOpen,
-    Output11FuseOpen,
-    Output12FuseOpen,
-    Output13FuseOpen,
-    Output14FuseOpen,
-    Output15FuseOpen,
-    Output16FuseOpen,
-    Output17FuseOpen,
-    Output18FuseOpen,
-    Output19FuseOpen,
-    Output20FuseOpen,
-    GeneratorStartedUnexpectedly,
-    GeneratorStoppedUnexpectedly,
-    ClimateZone3FreeSensorOpenCircuit,
-    ClimateZone3FreeSensorShortCircuit,
-    ClimateZone3TempSensorOpenCircuit,
-    ClimateZone3TempSensorShortCircuit,
-    ClimateZone3PowerMissing,
-    ClimateZone3DipSwitchInvalid,
-    ClimateZone1VoltageHigh,
-    ClimateZone1VoltageLow,
-    ClimateZone2VoltageHigh,
-    ClimateZone2VoltageLow,
-    ClimateZone3VoltageHigh,
-    ClimateZone3VoltageLow,
-    ExtendSwitchShortToGnd,
-    ExtendSwitchShortToBatt,
-    ExtendSwitchStuck,
-    ExtendSwitchFault,
-    ExtendSwitchCircuitOpen,
-    RetractSwitchShortToGnd,
-    RetractSwitchShortToBatt,
-    RetractSwitchStuck,
-    RetractSwitchFault,
-    RetractSwitchCircuitOpen,
-    ParkBrakeSwitchShortToGnd,
-    ParkBrakeSwitchShortToBatt,
-    ParkBrakeSwitchStuck,
-    ParkBrakeSwitchFault,
-    ParkBrakeSwitchCircuitOpen,
-    Motor1SwitchShortToGnd,
-    Motor1SwitchShortToBatt,
-    Motor1SwitchStuck,
-    Motor1SwitchFault,
-    Motor1SwitchCircuitOpen,
-    Motor2SwitchShortToGnd,
-    Motor2SwitchShortToBatt,
-    Motor2SwitchStuck,
-    Motor2SwitchFault,
-    Motor2SwitchCircuitOpen,
-    Motor3SwitchShortToGnd,
-    Motor3SwitchShortToBatt,
-    Motor3SwitchStuck,
-    Motor3SwitchFault,
-    Motor3SwitchCircuitOpen,
-    Motor4SwitchShortToGnd,
-    Motor4SwitchShortToBatt,
-    Motor4SwitchStuck,
-    Motor4SwitchFault,
-    Motor4SwitchCircuitOpen,
-    Motor5SwitchShortToGnd,
-    Motor5SwitchShortToBatt,
-    Motor5SwitchStuck,
-    Motor5SwitchFault,
-    Motor5SwitchCircuitOpen,
-    Motor6SwitchShortToGnd,
-    Motor6SwitchShortToBatt,
-    Motor6SwitchStuck,
-    Motor6SwitchFault,
-    Motor6SwitchCircuitOpen,
-    Motor7SwitchShortToGnd,
-    Motor7SwitchShortToBatt,
-    Motor7SwitchStuck,
-    Motor7SwitchFault,
-    Motor7SwitchCircuitOpen,
-    Motor8SwitchShortToGnd,
-    Motor8SwitchShortToBatt,
-    Motor8SwitchStuck,
-    Motor8SwitchFault,
-    Motor8SwitchCircuitOpen,
-    Motor9SwitchShortToGnd,
-    Motor9SwitchShortToBatt,
-    Motor9SwitchStuck,
-    Motor9SwitchFault,
-    Motor9SwitchCircuitOpen,
-    Motor10SwitchShortToGnd,
-    Motor10SwitchShortToBatt,
-    Motor10SwitchStuck,
-    Motor10SwitchFault,
-    Motor10SwitchCircuitOpen,
-    Motor11SwitchShortToGnd,
-    Motor11SwitchShortToBatt,
-    Motor11SwitchStuck,
-    Motor11SwitchFault,
-    Motor11SwitchCircuitOpen,
-    Motor12SwitchShortToGnd,
-    Motor12SwitchShortToBatt,
-    Motor12SwitchStuck,
-    Motor12SwitchFault,
-    Motor12SwitchCircuitOpen,
-    Motor13SwitchShortToGnd,
-    Motor13SwitchShortToBatt,
-    Motor13SwitchStuck,
-    Motor13SwitchFault,
-    Motor13SwitchCircuitOpen,
-    Motor14SwitchShortToGnd,
-    Motor14SwitchShortToBatt,
-    Motor14SwitchStuck,
-    Motor14SwitchFault,
-    Motor14SwitchCircuitOpen,
-    Motor15SwitchShortToGnd,
-    Motor15SwitchShortToBatt,
-    Motor15SwitchStuck,
-    Motor15SwitchFault,
-    Motor15SwitchCircuitOpen,
-    Motor16SwitchShortToGnd,
-    Motor16SwitchShortToBatt,
-    Motor16SwitchStuck,
-    Motor16SwitchFault,
-    Motor16SwitchCircuitOpen,
-    HallEffect1SignalShortToGnd,
-    HallEffect1SignalShortToBatt,
-    HallEffect1SignalFault,
-    HallEffect1SignalCircuitOpen,
-    HallEffect2SignalShortToGnd,
-    HallEffect2SignalShortToBatt,
-    HallEffect2SignalFault,
-    HallEffect2SignalCircuitOpen,
-    HallEffect3SignalShortToGnd,
-    HallEffect3SignalShortToBatt,
-    HallEffect3SignalFault,
-    HallEffect3SignalCircuitOpen,
-    HallEffect4SignalShortToGnd,
-    HallEffect4SignalShortToBatt,
-    HallEffect4SignalFault,
-    HallEffect4SignalCircuitOpen,
-    HallEffect5SignalShortToGnd,
-    HallEffect5SignalShortToBatt,
-    HallEffect5SignalFault,
-    HallEffect5SignalCircuitOpen,
-    HallEffect6SignalShortToGnd,
-    HallEffect6SignalShortToBatt,
-    HallEffect6SignalFault,
-    HallEffect6SignalCircuitOpen,
-    HallEffect7SignalShortToGnd,
-    HallEffect7SignalShortToBatt,
-    HallEffect7SignalFault,
-    HallEffect7SignalCircuitOpen,
-    HallEffect8SignalShortToGnd,
-    HallEffect8SignalShortToBatt,
-    HallEffect8SignalFault,
-    HallEffect8SignalCircuitOpen,
-    MotorOutputShortToBatt,
-    MotorOutputShortToGnd,
-    MotorOutputPlusShortToBatt,
-    MotorOutputPlusShortToGnd,
-    MotorOutputMinusShortToBatt,
-    MotorOutputMinusShortToGnd,
-    MotorOutputCircuitFailure,
-    MotorOutputOpen,
-    MotorOutputShort,
-    MotorOutputOverCurrent,
-    MotorOutputUnderCurrent,
-    MotorOutput1ShortToBatt,
-    MotorOutput1ShortToGnd,
-    MotorOutput1PlusShortToBatt,
-    MotorOutput1PlusShortToGnd,
-    MotorOutput1MinusShortToBatt,
-    MotorOutput1MinusShortToGnd,
-    MotorOutput1CircuitFailure,
-    MotorOutput1Open,
-    MotorOutput1Short,
-    MotorOutput1OverCurrent,
-    MotorOutput1UnderCurrent,
-    MotorOutput2ShortToBatt,
-    MotorOutput2ShortToGnd,
-    MotorOutput2PlusShortToBatt,
-    MotorOutput2PlusShortToGnd,
-    MotorOutput2MinusShortToBatt,
-    MotorOutput2MinusShortToGnd,
-    MotorOutput2CircuitFailure,
-    MotorOutput2Open,
-    MotorOutput2Short,
-    MotorOutput2OverCurrent,
-    MotorOutput2UnderCurrent,
-    MotorOutput3ShortToBatt,
-    MotorOutput3ShortToGnd,
-    MotorOutput3PlusShortToBatt,
-    MotorOutput3PlusShortToGnd,
-    MotorOutput3MinusShortToBatt,
-    MotorOutput3MinusShortToGnd,
-    MotorOutput3CircuitFailure,
-    MotorOutput3Open,
-    MotorOutput3Short,
-    MotorOutput3OverCurrent,
-    MotorOutput3UnderCurrent,
-    MotorOutput4ShortToBatt,
-    MotorOutput4ShortToGnd,
-    MotorOutput4PlusShortToBatt,
-    MotorOutput4PlusShortToGnd,
-    MotorOutput4MinusShortToBatt,
-    MotorOutput4MinusShortToGnd,
-    MotorOutput4CircuitFailure,
-    MotorOutput4Open,
-    MotorOutput4Short,
-    MotorOutput4OverCurrent,
-    MotorOutput4UnderCurrent,
-    MotorOutput5ShortToBatt,
-    MotorOutput5ShortToGnd,
-    MotorOutput5PlusShortToBatt,
-    MotorOutput5PlusShortToGnd,
-    MotorOutput5MinusShortToBatt,
-    MotorOutput5MinusShortToGnd,
-    MotorOutput5CircuitFailure,
-    MotorOutput5Open,
-    MotorOutput5Short,
-    MotorOutput5OverCurrent,
-    MotorOutput5UnderCurrent,
-    MotorOutput6ShortToBatt,
-    MotorOutput6ShortToGnd,
-    MotorOutput6PlusShortToBatt,
-    MotorOutput6PlusShortToGnd,
-    MotorOutput6MinusShortToBatt,
-    MotorOutput6MinusShortToGnd,
-    MotorOutput6CircuitFailure,
-    MotorOutput6Open,
-    MotorOutput6Short,
-    MotorOutput6OverCurrent,
-    MotorOutput6UnderCurrent,
-    MotorOutput7ShortToBatt,
-    MotorOutput7ShortToGnd,
-    MotorOutput7PlusShortToBatt,
-    MotorOutput7PlusShortToGnd,
-    MotorOutput7MinusShortToBatt,
-    MotorOutput7MinusShortToGnd,
-    MotorOutput7CircuitFailure,
-    MotorOutput7Open,
-    MotorOutput7Short,
-    MotorOutput7OverCurrent,
-    MotorOutput7UnderCurrent,
-    MotorOutput8ShortToBatt,
-    MotorOutput8ShortToGnd,
-    MotorOutput8PlusShortToBatt,
-    MotorOutput8PlusShortToGnd,
-    MotorOutput8MinusShortToBatt,
-    MotorOutput8MinusShortToGnd,
-    MotorOutput8CircuitFailure,
-    MotorOutput8Open,
-    MotorOutput8Short,
-    MotorOutput8OverCurrent,
-    MotorOutput8UnderCurrent,
-    MotorOutput9ShortToBatt,
-    MotorOutput9ShortToGnd,
-    MotorOutput9PlusShortToBatt,
-    MotorOutput9PlusShortToGnd,
-    MotorOutput9MinusShortToBatt,
-    MotorOutput9MinusShortToGnd,
-    MotorOutput9CircuitFailure,
-    MotorOutput9Open,
-    MotorOutput9Short,
-    MotorOutput9OverCurrent,
-    MotorOutput9UnderCurrent,
-    MotorOutput10ShortToBatt,
-    MotorOutput10ShortToGnd,
-    MotorOutput10PlusShortToBatt,
-    MotorOutput10PlusShortToGnd,
-    MotorOutput10MinusShortToBatt,
-    MotorOutput10MinusShortToGnd,
-    MotorOutput10CircuitFailure,
-    MotorOutput10Open,
-    MotorOutput10Short,
-    MotorOutput10OverCurrent,
-    MotorOutput10UnderCurrent,
-    MotorOutput11ShortToBatt,
-    MotorOutput11ShortToGnd,
-    MotorOutput11PlusShortToBatt,
-    MotorOutput11PlusShortToGnd,
-    MotorOutput11MinusShortToBatt,
-    MotorOutput11MinusShortToGnd,
-    MotorOutput11CircuitFailure,
-    MotorOutput11Open,
-    MotorOutput11Short,
-    MotorOutput11OverCurrent,
-    MotorOutput11UnderCurrent,
-    MotorOutput12ShortToBatt,
-    MotorOutput12ShortToGnd,
-    MotorOutput12PlusShortToBatt,
-    MotorOutput12PlusShortToGnd,
-    MotorOutput12MinusShortToBatt,
-    MotorOutput12MinusShortToGnd,
-    MotorOutput12CircuitFailure,
-    MotorOutput12Open,
-    MotorOutput12Short,
-    MotorOutput12OverCurrent,
-    MotorOutput12UnderCurrent,
-    MotorOutput13ShortToBatt,
-    MotorOutput13ShortToGnd,
-    MotorOutput13PlusShortToBatt,
-    MotorOutput13PlusShortToGnd,
-    MotorOutput13MinusShortToBatt,
-    MotorOutput13MinusShortToGnd,
-    MotorOutput13CircuitFailure,
-    MotorOutput13Open,
-    MotorOutput13Short,
-    MotorOutput13OverCurrent,
-    MotorOutput13UnderCurrent,
-    MotorOutput14ShortToBatt,
-    MotorOutput14ShortToGnd,
-    MotorOutput14PlusShortToBatt,
-    MotorOutput14PlusShortToGnd,
-    MotorOutput14MinusShortToBatt,
-    MotorOutput14MinusShortToGnd,
-    MotorOutput14CircuitFailure,
-    MotorOutput14Open,
-    MotorOutput14Short,
-    MotorOutput14OverCurrent,
-    MotorOutput14UnderCurrent,
-    MotorOutput15ShortToBatt,
-    MotorOutput15ShortToGnd,
-    MotorOutput15PlusShortToBatt,
-    MotorOutput15PlusShortToGnd,
-    MotorOutput15MinusShortToBatt,
-    MotorOutput15MinusShortToGnd,
-    MotorOutput15CircuitFailure,
-    MotorOutput15Open,
-    MotorOutput15Short,
-    MotorOutput15OverCurrent,
-    MotorOutput15UnderCurrent,
-    MotorOutput16ShortToBatt,
-    MotorOutput16ShortToGnd,
-    MotorOutput16PlusShortToBatt,
-    MotorOutput16PlusShortToGnd,
-    MotorOutput16MinusShortToBatt,
-    MotorOutput16MinusShortToGnd,
-    MotorOutput16CircuitFailure,
-    MotorOutput16Open,
-    MotorOutput16Short,
-    MotorOutput16OverCurrent,
-    MotorOutput16UnderCurrent,
-    MotorSoftstopsNotConfigured,
-    BatteryDropout,
-    WaterHeaterIgnitionFailure,
-    AutoLevelTimeout,
-    AutoLevelFail,
-    AutoRetractTimeout,
-    AutoHitchTimeout,
-    GroundJacksTimeout,
-    ExcessAngle,
-    UserPanicStop,
-    ParkBrakeNotEngaged,
-    RequestedFeatureDisabled,
-    PsiSwitchTimeout,
-    PsiSwitchShortToGnd,
-    PsiSwitchShortToBatt,
-    PsiSwitchSwitchStuck,
-    PsiSwitchSwitchFault,
-    PsiSwitchCircuitOpen,
-    TouchPadPowerShortToBatt,
-    TouchPadPowerShortToGnd,
-    TouchPadPowerPlusShortToBatt,
-    TouchPadPowerPlusShortToGnd,
-    TouchPadPowerMinusShortToBatt,
-    TouchPadPowerMinusShortToGnd,
-    TouchPadPowerCircuitFailure,
-    TouchPadPowerOpen,
-    TouchPadPowerShort,
-    TouchPadPowerOverCurrent,
-    TouchPadPowerUnderCurrent,
-    IgnitionSwitchNotEngaged,
-    AutoStartVoltageLow,
-    ReturnFuseOpen,
-    Output1SoftwareFuseOpen,
-    Output2SoftwareFuseOpen,
-    Output3SoftwareFuseOpen,
-    Output4SoftwareFuseOpen,
-    Output5SoftwareFuseOpen,
-    Output6SoftwareFuseOpen,
-    Output7SoftwareFuseOpen,
-    Output8SoftwareFuseOpen,
-    Output9SoftwareFuseOpen,
-    Output10SoftwareFuseOpen,
-    Output11SoftwareFuseOpen,
-    Output12SoftwareFuseOpen,
-    Output13SoftwareFuseOpen,
-    Output14SoftwareFuseOpen,
-    Output15SoftwareFuseOpen,
-    Output16SoftwareFuseOpen,
-    Output17SoftwareFuseOpen,
-    Output18SoftwareFuseOpen,
-    Output19SoftwareFuseOpen,
-    Output20SoftwareFuseOpen,
-    Output21SoftwareFuseOpen,
-    Output22SoftwareFuseOpen,
-    Output23SoftwareFuseOpen,
-    Output24SoftwareFuseOpen,
-    Output25SoftwareFuseOpen,
-    Output26SoftwareFuseOpen,
-    Output27SoftwareFuseOpen,
-    Output28SoftwareFuseOpen,
-    Output29SoftwareFuseOpen,
-    Output30SoftwareFuseOpen,
-    Output31SoftwareFuseOpen,
-    Output32SoftwareFuseOpen,
-    WirelessSwitchBatteryLow,
-    WirelessSwitch1BatteryLow,
-    WirelessSwitch2BatteryLow,
-    WirelessSwitch3BatteryLow,
-    WirelessSwitch4BatteryLow,
-    WirelessSwitch5BatteryLow,
-    WirelessSwitch6BatteryLow,
-    WirelessSwitch7BatteryLow,
-    WirelessSwitch8BatteryLow,
-    WirelessSwitch9BatteryLow,
-    WirelessSwitch10BatteryLow,
-    WirelessSwitch11BatteryLow,
-    WirelessSwitch12BatteryLow,
-    WirelessSwitch13BatteryLow,
-    WirelessSwitch14BatteryLow,
-    WirelessSwitch15BatteryLow,
-    WirelessSwitch16BatteryLow,
-    WaterIntrusionDetected,
-    WaterIntrusionDetectedInConnector,
-    WaterIntrusionDetectedInTouchpad,
-    OperatingVoltageDropout,
-    BatteryVoltageHighFastDetect,
-    IgnitionNotActive,
-    BatteryTooLowToOperate,
-    LightOutputSoftwareFuseOpen,
-    LightOutput1SoftwareFuseOpen,
-    LightOutput2SoftwareFuseOpen,
-    LightOutput3SoftwareFuseOpen,
-    LightOutput4SoftwareFuseOpen,
-    LightOutput5SoftwareFuseOpen,
-    LightOutput6SoftwareFuseOpen,
-    LightOutput7SoftwareFuseOpen,
-    LightOutput8SoftwareFuseOpen,
-    LightOutput9SoftwareFuseOpen,
-    LightOutput10SoftwareFuseOpen,
-    LightOutput11SoftwareFuseOpen,
-    LightOutput12SoftwareFuseOpen,
-    LightOutput13SoftwareFuseOpen,
-    LightOutput14SoftwareFuseOpen,
-    LightOutput15SoftwareFuseOpen,
-    LightOutput16SoftwareFuseOpen,
-    PowerInputMissing,
-    PowerInput1Missing,
-    PowerInput2Missing,
-    PowerInput3Missing,
-    PowerInput4Missing,
-    PowerInput5Missing,
-    PowerInput6Missing,
-    PowerInput7Missing,
-    PowerInput8Missing,
-    PowerInput9Missing,
-    PowerInput10Missing,
-    PowerInput11Missing,
-    PowerInput12Missing,
-    PowerInput13Missing,
-    PowerInput14Missing,
-    PowerInput15Missing,
-    PowerInput16Missing,
-    IgnitionActive,
-    ParkbrakeNotActive,
-    ParkbrakeActive,
-    MotorRetractSoftstopNotConfigured,
-    MotorExtendSoftstopNotConfigured,
-    SwitchActiveOnlyViaHardware,
-    LevelerOperatingVoltageDropout,
-    Output1OperatingVoltageDropout,
-    Output2OperatingVoltageDropout,
-    Output3OperatingVoltageDropout,
-    Output4OperatingVoltageDropout,
-    Output5OperatingVoltageDropout,
-    Output6OperatingVoltageDropout,
-    Output7OperatingVoltageDropout,
-    Output8OperatingVoltageDropout,
-    Output9OperatingVoltageDropout,
-    Output10OperatingVoltageDropout,
-    Output11OperatingVoltageDropout,
-    Output12OperatingVoltageDropout,
-    Output13OperatingVoltageDropout,
-    Output14OperatingVoltageDropout,
-    Output15OperatingVoltageDropout,
-    Output16OperatingVoltageDropout,
-    BlePairingCorruption,
-    SureshadeGeneralFault,
-    TbbCommError,
-    UserAutoOperationInProgress,
-    WindEventAutoOperationInProgress,
-    AutoOperationCanceled,
-    WindSensor1CommFailure,
-    WindSensor2CommFailure,
-    WindSensor3CommFailure,
-    WindSensor4CommFailure,
-    WindSensor5CommFailure,
-    WindSensor6CommFailure,
-    WindSensor7CommFailure,
-    WindSensor8CommFailure,
-    WindSensor9CommFailure,
-    WindSensor10CommFailure,
-    WindSensor11CommFailure,
-    WindSensor12CommFailure,
-    WindSensor13CommFailure,
-    WindSensor14CommFailure,
-    WindSensor15CommFailure,
-    WindSensor16CommFailure,
-    LowBatteryOperatingMode,
-    Axle1LeftActuatorOpen,
-    Axle1LeftActuatorShortToBatt,
-    Axle1LeftActuatorShortToGround,
-    Axle1LeftActuatorRelayFailure,
-    Axle1LeftWssOpen,
-    Axle1LeftWssShortToBatt,
-    Axle1LeftWssShortToGround,
-    Axle1RightActuatorOpen,
-    Axle1RightActuatorShortToBatt,
-    Axle1RightActuatorShortToGround,
-    Axle1RightActuatorRelayFailure,
-    Axle1RightWssOpen,
-    Axle1RightWssShortToBatt,
-    Axle1RightWssShortToGround,
-    Axle2LeftActuatorOpen,
-    Axle2LeftActuatorShortToBatt,
-    Axle2LeftActuatorShortToGround,
-    Axle2LeftActuatorRelayFailure,
-    Axle2LeftWssOpen,
-    Axle2LeftWssShortToBatt,
-    Axle2LeftWssShortToGround,
-    Axle2RightActuatorOpen,
-    Axle2RightActuatorShortToBatt,
-    Axle2RightActuatorShortToGround,
-    Axle2RightActuatorRelayFailure,
-    Axle2RightWssOpen,
-    Axle2RightWssShortToBatt,
-    Axle2RightWssShortToGround,
-    Axle3LeftActuatorOpen,
-    Axle3LeftActuatorShortToBatt,
-    Axle3LeftActuatorShortToGround,
-    Axle3LeftActuatorRelayFailure,
-    Axle3LeftWssOpen,
-    Axle3LeftWssShortToBatt,
-    Axle3LeftWssShortToGround,
-    Axle3RightActuatorOpen,
-    Axle3RightActuatorShortToBatt,
-    Axle3RightActuatorShortToGround,
-    Axle3RightActuatorRelayFailure,
-    Axle3RightWssOpen,
-    Axle3RightWssShortToBatt,
-    Axle3RightWssShortToGround,
-    BrakeLoadCircuitOpen,
-    BrakeLoadCircuitShortToBatt,
-    BrakeLoadCircuitShortToGround,
-    BrakeOutputOpen,
-    BrakeOutputShortToBatt,
-    BrakeOutputShortToGround,
-    TailRunlightsOutputOpen,
-    TailRunlightsOutputShortToBatt,
-    TailRunlightsOutputShortToGround,
-    LeftTurnBrakeOutputOpen,
-    LeftTurnBrakeOutputShortToBatt,
-    LeftTurnBrakeOutputShortToGround,
-    RightTurnBrakeOutputOpen,
-    RightTurnBrakeOutputShortToBatt,
-    RightTurnBrakeOutputShortToGround,
-    RvCExternalNodeSerialNumberError,
-    ProductWatchdogTriggered,
-    RvCExternalNodeCalibrationRequired,
-    RvCCommunicationError,
-    RvCExternalNodeCommunicationError,
-    RvCExternalNodeTempSensorFailure,
-    RvCExternalNodeLowVoltage,
-    RvCExternalNodeHighVoltage,
-    RvCExternalNodeYellowLamp,
-    RvCExternalNodeRedLamp,
-    BrakeControllerLossOfCommBleMicro,
-    BrakeControllerModuleFailure,
-    WssA1lMechanicalFailure,
-    WssA1rMechanicalFailure,
-    WssA2lMechanicalFailure,
-    WssA2rMechanicalFailure,
-    WssA3lMechanicalFailure,
-    WssA3rMechanicalFailure,
-    BrakeControllerModuleOverTemperature,
-    UserAutoOperationComplete,
-    WindEventAutoOperationComplete,
-    WindSensor1Error,
-    WindSensor2Error,
-    WindSensor3Error,
-    WindSensor4Error,
-    WindSensor5Error,
-    WindSensor6Error,
-    WindSensor7Error,
-    WindSensor8Error,
-    WindSensor9Error,
-    WindSensor10Error,
-    WindSensor11Error,
-    WindSensor12Error,
-    WindSensor13Error,
-    WindSensor14Error,
-    WindSensor15Error,
-    WindSensor16Error,
-    UserAutoOperationError,
-    WindEventAutoOperationError,
+    #[display(fmt = "other")]
+    Other,
+    #[display(fmt = "power")]
+    Power,
+    #[display(fmt = "sensor")]
+    Sensor,
+    #[display(fmt = "comms")]
+    Comms,
+    #[display(fmt = "motor")]
+    Motor,
+}
+
+// Severity and category are what technicians triage by, the description is shown to users as-is.
+// Categories go by the kind of failure rather than the part: lost communication is comms, shorts,
+// opens and over/under current are power wiring faults, stuck or implausible readings are sensor
+// faults and travel/position problems are motor faults
+enum_with_metadata! {
+    DTCID: u16; description:&'static str:0, severity:DtcSeverity:1, category:DtcCategory:2 {
+        Unknown = 0 {"Unknown trouble code", DtcSeverity::Info, DtcCategory::Other},
+        DtcStorageFailure = 1 {"DTC storage failure", DtcSeverity::Fault, DtcCategory::Other},
+        EcuIsDefective = 2 {"ECU is defective", DtcSeverity::Fault, DtcCategory::Other},
+        NvmFailure = 3 {"NVM failure", DtcSeverity::Fault, DtcCategory::Other},
+        ConfigurationFailure = 4 {"Configuration failure", DtcSeverity::Fault, DtcCategory::Other},
+        BatteryVoltageLow = 5 {"Battery voltage low", DtcSeverity::Warning, DtcCategory::Power},
+        BatteryVoltageHigh = 6 {"Battery voltage high", DtcSeverity::Warning, DtcCategory::Power},
+        BleSubsystemCommError = 7 {"BLE subsystem communication error", DtcSeverity::Fault, DtcCategory::Comms},
+        ModuleOverCurrent = 8 {"Module over current", DtcSeverity::Fault, DtcCategory::Power},
+        EthernetCommError = 9 {"Ethernet communication error", DtcSeverity::Fault, DtcCategory::Comms},
+        TiltSensorMalfunction = 10 {"Tilt sensor malfunction", DtcSeverity::Fault, DtcCategory::Sensor},
+        LevelerZeroPointNotConfigured = 11 {"Leveler zero point not configured", DtcSeverity::Warning, DtcCategory::Motor},
+        FuseConfigurationInvalid = 12 {"Fuse configuration invalid", DtcSeverity::Fault, DtcCategory::Power},
+        OutdoorTempSensorOpenCircuit = 13 {"Outdoor temperature sensor open circuit", DtcSeverity::Fault, DtcCategory::Power},
+        OutdoorTempSensorShortCircuit = 14 {"Outdoor temperature sensor short circuit", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadCommFailure = 15 {"Touch pad communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        RemoteSensorCommFailure = 16 {"Remote sensor communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        RemoteSensorPowerShortToGround = 17 {"Remote sensor power short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RemoteSensorFailure = 18 {"Remote sensor failure", DtcSeverity::Fault, DtcCategory::Sensor},
+        FrontRemoteSensorCommFailure = 19 {"Front remote sensor communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        FrontRemoteSensorPowerShortToGround = 20 {"Front remote sensor power short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        FrontRemoteSensorFailure = 21 {"Front remote sensor failure", DtcSeverity::Fault, DtcCategory::Sensor},
+        RearRemoteSensorCommFailure = 22 {"Rear remote sensor communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        RearRemoteSensorPowerShortToGround = 23 {"Rear remote sensor power short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RearRemoteSensorFailure = 24 {"Rear remote sensor failure", DtcSeverity::Fault, DtcCategory::Sensor},
+        HallEffectSignalShortToGnd = 25 {"Hall effect signal short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectSignalShortToBatt = 26 {"Hall effect signal short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectSignalFault = 27 {"Hall effect signal fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        HallEffectSignalCircuitOpen = 28 {"Hall effect signal circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        CalSwitchShortToGnd = 29 {"Calibration switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        CalSwitchShortToBatt = 30 {"Calibration switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        CalSwitchSwitchStuck = 31 {"Calibration switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        CalSwitchFault = 32 {"Calibration switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        CalSwitchCircuitOpen = 33 {"Calibration switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        FloatSwitchShortToGnd = 34 {"Float switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        FloatSwitchShortToBatt = 35 {"Float switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        FloatSwitchSwitchStuck = 36 {"Float switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        FloatSwitchFault = 37 {"Float switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        FloatSwitchCircuitOpen = 38 {"Float switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone1TempSensorOpenCircuit = 39 {"Climate zone 1 temperature sensor open circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone1TempSensorShortCircuit = 40 {"Climate zone 1 temperature sensor short circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone1FreeSensorOpenCircuit = 41 {"Climate zone 1 free sensor open circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone1FreeSensorShortCircuit = 42 {"Climate zone 1 free sensor short circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone2TempSensorOpenCircuit = 43 {"Climate zone 2 temperature sensor open circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone2TempSensorShortCircuit = 44 {"Climate zone 2 temperature sensor short circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone2FreeSensorOpenCircuit = 45 {"Climate zone 2 free sensor open circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone2FreeSensorShortCircuit = 46 {"Climate zone 2 free sensor short circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone1PowerMissing = 47 {"Climate zone 1 power missing", DtcSeverity::Warning, DtcCategory::Power},
+        ClimateZone2PowerMissing = 48 {"Climate zone 2 power missing", DtcSeverity::Warning, DtcCategory::Power},
+        ClimateZone1DipSwitchInvalid = 49 {"Climate zone 1 DIP switch invalid", DtcSeverity::Fault, DtcCategory::Sensor},
+        ClimateZone2DipSwitchInvalid = 50 {"Climate zone 2 DIP switch invalid", DtcSeverity::Fault, DtcCategory::Sensor},
+        GeneratorFailedToStart = 51 {"Generator failed to start", DtcSeverity::Fault, DtcCategory::Motor},
+        GeneratorFailedToStop = 52 {"Generator failed to stop", DtcSeverity::Fault, DtcCategory::Motor},
+        InputShortToGnd = 53 {"Input short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        InputShortToBatt = 54 {"Input short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        InputSwitchStuck = 55 {"Input switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        InputSwitchFault = 56 {"Input switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        InputCircuitOpen = 57 {"Input circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input1ShortToGnd = 58 {"Input 1 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input1ShortToBatt = 59 {"Input 1 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input1SwitchStuck = 60 {"Input 1 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input1SwitchFault = 61 {"Input 1 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input1CircuitOpen = 62 {"Input 1 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input2ShortToGnd = 63 {"Input 2 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input2ShortToBatt = 64 {"Input 2 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input2SwitchStuck = 65 {"Input 2 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input2SwitchFault = 66 {"Input 2 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input2CircuitOpen = 67 {"Input 2 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input3ShortToGnd = 68 {"Input 3 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input3ShortToBatt = 69 {"Input 3 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input3SwitchStuck = 70 {"Input 3 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input3SwitchFault = 71 {"Input 3 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input3CircuitOpen = 72 {"Input 3 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input4ShortToGnd = 73 {"Input 4 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input4ShortToBatt = 74 {"Input 4 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input4SwitchStuck = 75 {"Input 4 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input4SwitchFault = 76 {"Input 4 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input4CircuitOpen = 77 {"Input 4 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input5ShortToGnd = 78 {"Input 5 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input5ShortToBatt = 79 {"Input 5 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input5SwitchStuck = 80 {"Input 5 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input5SwitchFault = 81 {"Input 5 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input5CircuitOpen = 82 {"Input 5 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input6ShortToGnd = 83 {"Input 6 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input6ShortToBatt = 84 {"Input 6 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input6SwitchStuck = 85 {"Input 6 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input6SwitchFault = 86 {"Input 6 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input6CircuitOpen = 87 {"Input 6 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input7ShortToGnd = 88 {"Input 7 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input7ShortToBatt = 89 {"Input 7 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input7SwitchStuck = 90 {"Input 7 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input7SwitchFault = 91 {"Input 7 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input7CircuitOpen = 92 {"Input 7 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input8ShortToGnd = 93 {"Input 8 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input8ShortToBatt = 94 {"Input 8 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input8SwitchStuck = 95 {"Input 8 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input8SwitchFault = 96 {"Input 8 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input8CircuitOpen = 97 {"Input 8 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input9ShortToGnd = 98 {"Input 9 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input9ShortToBatt = 99 {"Input 9 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input9SwitchStuck = 100 {"Input 9 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input9SwitchFault = 101 {"Input 9 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input9CircuitOpen = 102 {"Input 9 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input10ShortToGnd = 103 {"Input 10 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input10ShortToBatt = 104 {"Input 10 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input10SwitchStuck = 105 {"Input 10 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input10SwitchFault = 106 {"Input 10 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input10CircuitOpen = 107 {"Input 10 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input11ShortToGnd = 108 {"Input 11 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input11ShortToBatt = 109 {"Input 11 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input11SwitchStuck = 110 {"Input 11 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input11SwitchFault = 111 {"Input 11 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input11CircuitOpen = 112 {"Input 11 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input12ShortToGnd = 113 {"Input 12 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input12ShortToBatt = 114 {"Input 12 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input12SwitchStuck = 115 {"Input 12 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input12SwitchFault = 116 {"Input 12 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input12CircuitOpen = 117 {"Input 12 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input13ShortToGnd = 118 {"Input 13 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input13ShortToBatt = 119 {"Input 13 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input13SwitchStuck = 120 {"Input 13 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input13SwitchFault = 121 {"Input 13 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input13CircuitOpen = 122 {"Input 13 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input14ShortToGnd = 123 {"Input 14 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input14ShortToBatt = 124 {"Input 14 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input14SwitchStuck = 125 {"Input 14 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input14SwitchFault = 126 {"Input 14 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input14CircuitOpen = 127 {"Input 14 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input15ShortToGnd = 128 {"Input 15 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input15ShortToBatt = 129 {"Input 15 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input15SwitchStuck = 130 {"Input 15 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input15SwitchFault = 131 {"Input 15 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input15CircuitOpen = 132 {"Input 15 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Input16ShortToGnd = 133 {"Input 16 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Input16ShortToBatt = 134 {"Input 16 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Input16SwitchStuck = 135 {"Input 16 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input16SwitchFault = 136 {"Input 16 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Input16CircuitOpen = 137 {"Input 16 circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        OutputShortToBatt = 138 {"Output short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        OutputShortToGnd = 139 {"Output short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        OutputPlusShortToBatt = 140 {"Output plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        OutputPlusShortToGnd = 141 {"Output plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        OutputMinusShortToBatt = 142 {"Output minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        OutputMinusShortToGnd = 143 {"Output minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        OutputCircuitFailure = 144 {"Output circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        OutputOpen = 145 {"Output open", DtcSeverity::Fault, DtcCategory::Power},
+        OutputShort = 146 {"Output short", DtcSeverity::Fault, DtcCategory::Power},
+        OutputOverCurrent = 147 {"Output over current", DtcSeverity::Fault, DtcCategory::Power},
+        OutputUnderCurrent = 148 {"Output under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output1ShortToBatt = 149 {"Output 1 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output1ShortToGnd = 150 {"Output 1 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output1PlusShortToBatt = 151 {"Output 1 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output1PlusShortToGnd = 152 {"Output 1 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output1MinusShortToBatt = 153 {"Output 1 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output1MinusShortToGnd = 154 {"Output 1 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output1CircuitFailure = 155 {"Output 1 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output1Open = 156 {"Output 1 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output1Short = 157 {"Output 1 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output1OverCurrent = 158 {"Output 1 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output1UnderCurrent = 159 {"Output 1 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output2ShortToBatt = 160 {"Output 2 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output2ShortToGnd = 161 {"Output 2 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output2PlusShortToBatt = 162 {"Output 2 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output2PlusShortToGnd = 163 {"Output 2 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output2MinusShortToBatt = 164 {"Output 2 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output2MinusShortToGnd = 165 {"Output 2 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output2CircuitFailure = 166 {"Output 2 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output2Open = 167 {"Output 2 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output2Short = 168 {"Output 2 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output2OverCurrent = 169 {"Output 2 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output2UnderCurrent = 170 {"Output 2 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output3ShortToBatt = 171 {"Output 3 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output3ShortToGnd = 172 {"Output 3 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output3PlusShortToBatt = 173 {"Output 3 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output3PlusShortToGnd = 174 {"Output 3 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output3MinusShortToBatt = 175 {"Output 3 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output3MinusShortToGnd = 176 {"Output 3 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output3CircuitFailure = 177 {"Output 3 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output3Open = 178 {"Output 3 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output3Short = 179 {"Output 3 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output3OverCurrent = 180 {"Output 3 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output3UnderCurrent = 181 {"Output 3 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output4ShortToBatt = 182 {"Output 4 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output4ShortToGnd = 183 {"Output 4 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output4PlusShortToBatt = 184 {"Output 4 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output4PlusShortToGnd = 185 {"Output 4 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output4MinusShortToBatt = 186 {"Output 4 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output4MinusShortToGnd = 187 {"Output 4 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output4CircuitFailure = 188 {"Output 4 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output4Open = 189 {"Output 4 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output4Short = 190 {"Output 4 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output4OverCurrent = 191 {"Output 4 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output4UnderCurrent = 192 {"Output 4 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output5ShortToBatt = 193 {"Output 5 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output5ShortToGnd = 194 {"Output 5 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output5PlusShortToBatt = 195 {"Output 5 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output5PlusShortToGnd = 196 {"Output 5 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output5MinusShortToBatt = 197 {"Output 5 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output5MinusShortToGnd = 198 {"Output 5 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output5CircuitFailure = 199 {"Output 5 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output5Open = 200 {"Output 5 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output5Short = 201 {"Output 5 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output5OverCurrent = 202 {"Output 5 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output5UnderCurrent = 203 {"Output 5 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output6ShortToBatt = 204 {"Output 6 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output6ShortToGnd = 205 {"Output 6 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output6PlusShortToBatt = 206 {"Output 6 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output6PlusShortToGnd = 207 {"Output 6 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output6MinusShortToBatt = 208 {"Output 6 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output6MinusShortToGnd = 209 {"Output 6 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output6CircuitFailure = 210 {"Output 6 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output6Open = 211 {"Output 6 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output6Short = 212 {"Output 6 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output6OverCurrent = 213 {"Output 6 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output6UnderCurrent = 214 {"Output 6 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output7ShortToBatt = 215 {"Output 7 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output7ShortToGnd = 216 {"Output 7 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output7PlusShortToBatt = 217 {"Output 7 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output7PlusShortToGnd = 218 {"Output 7 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output7MinusShortToBatt = 219 {"Output 7 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output7MinusShortToGnd = 220 {"Output 7 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output7CircuitFailure = 221 {"Output 7 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output7Open = 222 {"Output 7 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output7Short = 223 {"Output 7 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output7OverCurrent = 224 {"Output 7 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output7UnderCurrent = 225 {"Output 7 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output8ShortToBatt = 226 {"Output 8 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output8ShortToGnd = 227 {"Output 8 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output8PlusShortToBatt = 228 {"Output 8 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output8PlusShortToGnd = 229 {"Output 8 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output8MinusShortToBatt = 230 {"Output 8 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output8MinusShortToGnd = 231 {"Output 8 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output8CircuitFailure = 232 {"Output 8 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output8Open = 233 {"Output 8 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output8Short = 234 {"Output 8 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output8OverCurrent = 235 {"Output 8 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output8UnderCurrent = 236 {"Output 8 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output9ShortToBatt = 237 {"Output 9 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output9ShortToGnd = 238 {"Output 9 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output9PlusShortToBatt = 239 {"Output 9 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output9PlusShortToGnd = 240 {"Output 9 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output9MinusShortToBatt = 241 {"Output 9 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output9MinusShortToGnd = 242 {"Output 9 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output9CircuitFailure = 243 {"Output 9 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output9Open = 244 {"Output 9 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output9Short = 245 {"Output 9 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output9OverCurrent = 246 {"Output 9 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output9UnderCurrent = 247 {"Output 9 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output10ShortToBatt = 248 {"Output 10 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output10ShortToGnd = 249 {"Output 10 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output10PlusShortToBatt = 250 {"Output 10 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output10PlusShortToGnd = 251 {"Output 10 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output10MinusShortToBatt = 252 {"Output 10 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output10MinusShortToGnd = 253 {"Output 10 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output10CircuitFailure = 254 {"Output 10 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output10Open = 255 {"Output 10 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output10Short = 256 {"Output 10 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output10OverCurrent = 257 {"Output 10 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output10UnderCurrent = 258 {"Output 10 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output11ShortToBatt = 259 {"Output 11 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output11ShortToGnd = 260 {"Output 11 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output11PlusShortToBatt = 261 {"Output 11 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output11PlusShortToGnd = 262 {"Output 11 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output11MinusShortToBatt = 263 {"Output 11 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output11MinusShortToGnd = 264 {"Output 11 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output11CircuitFailure = 265 {"Output 11 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output11Open = 266 {"Output 11 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output11Short = 267 {"Output 11 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output11OverCurrent = 268 {"Output 11 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output11UnderCurrent = 269 {"Output 11 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output12ShortToBatt = 270 {"Output 12 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output12ShortToGnd = 271 {"Output 12 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output12PlusShortToBatt = 272 {"Output 12 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output12PlusShortToGnd = 273 {"Output 12 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output12MinusShortToBatt = 274 {"Output 12 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output12MinusShortToGnd = 275 {"Output 12 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output12CircuitFailure = 276 {"Output 12 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output12Open = 277 {"Output 12 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output12Short = 278 {"Output 12 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output12OverCurrent = 279 {"Output 12 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output12UnderCurrent = 280 {"Output 12 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output13ShortToBatt = 281 {"Output 13 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output13ShortToGnd = 282 {"Output 13 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output13PlusShortToBatt = 283 {"Output 13 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output13PlusShortToGnd = 284 {"Output 13 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output13MinusShortToBatt = 285 {"Output 13 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output13MinusShortToGnd = 286 {"Output 13 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output13CircuitFailure = 287 {"Output 13 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output13Open = 288 {"Output 13 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output13Short = 289 {"Output 13 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output13OverCurrent = 290 {"Output 13 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output13UnderCurrent = 291 {"Output 13 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output14ShortToBatt = 292 {"Output 14 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output14ShortToGnd = 293 {"Output 14 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output14PlusShortToBatt = 294 {"Output 14 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output14PlusShortToGnd = 295 {"Output 14 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output14MinusShortToBatt = 296 {"Output 14 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output14MinusShortToGnd = 297 {"Output 14 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output14CircuitFailure = 298 {"Output 14 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output14Open = 299 {"Output 14 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output14Short = 300 {"Output 14 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output14OverCurrent = 301 {"Output 14 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output14UnderCurrent = 302 {"Output 14 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output15ShortToBatt = 303 {"Output 15 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output15ShortToGnd = 304 {"Output 15 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output15PlusShortToBatt = 305 {"Output 15 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output15PlusShortToGnd = 306 {"Output 15 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output15MinusShortToBatt = 307 {"Output 15 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output15MinusShortToGnd = 308 {"Output 15 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output15CircuitFailure = 309 {"Output 15 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output15Open = 310 {"Output 15 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output15Short = 311 {"Output 15 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output15OverCurrent = 312 {"Output 15 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output15UnderCurrent = 313 {"Output 15 under current", DtcSeverity::Fault, DtcCategory::Power},
+        Output16ShortToBatt = 314 {"Output 16 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output16ShortToGnd = 315 {"Output 16 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output16PlusShortToBatt = 316 {"Output 16 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output16PlusShortToGnd = 317 {"Output 16 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output16MinusShortToBatt = 318 {"Output 16 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Output16MinusShortToGnd = 319 {"Output 16 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Output16CircuitFailure = 320 {"Output 16 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        Output16Open = 321 {"Output 16 open", DtcSeverity::Fault, DtcCategory::Power},
+        Output16Short = 322 {"Output 16 short", DtcSeverity::Fault, DtcCategory::Power},
+        Output16OverCurrent = 323 {"Output 16 over current", DtcSeverity::Fault, DtcCategory::Power},
+        Output16UnderCurrent = 324 {"Output 16 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutputShortToBatt = 325 {"Light output short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutputShortToGnd = 326 {"Light output short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutputPlusShortToBatt = 327 {"Light output plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutputPlusShortToGnd = 328 {"Light output plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutputMinusShortToBatt = 329 {"Light output minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutputMinusShortToGnd = 330 {"Light output minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutputCircuitFailure = 331 {"Light output circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutputOpen = 332 {"Light output open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutputShort = 333 {"Light output short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutputOverCurrent = 334 {"Light output over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutputUnderCurrent = 335 {"Light output under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1ShortToBatt = 336 {"Light output 1 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1ShortToGnd = 337 {"Light output 1 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1PlusShortToBatt = 338 {"Light output 1 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1PlusShortToGnd = 339 {"Light output 1 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1MinusShortToBatt = 340 {"Light output 1 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1MinusShortToGnd = 341 {"Light output 1 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1CircuitFailure = 342 {"Light output 1 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1Open = 343 {"Light output 1 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1Short = 344 {"Light output 1 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1OverCurrent = 345 {"Light output 1 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1UnderCurrent = 346 {"Light output 1 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2ShortToBatt = 347 {"Light output 2 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2ShortToGnd = 348 {"Light output 2 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2PlusShortToBatt = 349 {"Light output 2 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2PlusShortToGnd = 350 {"Light output 2 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2MinusShortToBatt = 351 {"Light output 2 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2MinusShortToGnd = 352 {"Light output 2 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2CircuitFailure = 353 {"Light output 2 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2Open = 354 {"Light output 2 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2Short = 355 {"Light output 2 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2OverCurrent = 356 {"Light output 2 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2UnderCurrent = 357 {"Light output 2 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3ShortToBatt = 358 {"Light output 3 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3ShortToGnd = 359 {"Light output 3 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3PlusShortToBatt = 360 {"Light output 3 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3PlusShortToGnd = 361 {"Light output 3 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3MinusShortToBatt = 362 {"Light output 3 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3MinusShortToGnd = 363 {"Light output 3 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3CircuitFailure = 364 {"Light output 3 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3Open = 365 {"Light output 3 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3Short = 366 {"Light output 3 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3OverCurrent = 367 {"Light output 3 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3UnderCurrent = 368 {"Light output 3 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4ShortToBatt = 369 {"Light output 4 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4ShortToGnd = 370 {"Light output 4 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4PlusShortToBatt = 371 {"Light output 4 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4PlusShortToGnd = 372 {"Light output 4 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4MinusShortToBatt = 373 {"Light output 4 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4MinusShortToGnd = 374 {"Light output 4 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4CircuitFailure = 375 {"Light output 4 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4Open = 376 {"Light output 4 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4Short = 377 {"Light output 4 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4OverCurrent = 378 {"Light output 4 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4UnderCurrent = 379 {"Light output 4 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5ShortToBatt = 380 {"Light output 5 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5ShortToGnd = 381 {"Light output 5 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5PlusShortToBatt = 382 {"Light output 5 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5PlusShortToGnd = 383 {"Light output 5 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5MinusShortToBatt = 384 {"Light output 5 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5MinusShortToGnd = 385 {"Light output 5 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5CircuitFailure = 386 {"Light output 5 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5Open = 387 {"Light output 5 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5Short = 388 {"Light output 5 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5OverCurrent = 389 {"Light output 5 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5UnderCurrent = 390 {"Light output 5 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6ShortToBatt = 391 {"Light output 6 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6ShortToGnd = 392 {"Light output 6 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6PlusShortToBatt = 393 {"Light output 6 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6PlusShortToGnd = 394 {"Light output 6 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6MinusShortToBatt = 395 {"Light output 6 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6MinusShortToGnd = 396 {"Light output 6 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6CircuitFailure = 397 {"Light output 6 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6Open = 398 {"Light output 6 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6Short = 399 {"Light output 6 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6OverCurrent = 400 {"Light output 6 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6UnderCurrent = 401 {"Light output 6 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7ShortToBatt = 402 {"Light output 7 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7ShortToGnd = 403 {"Light output 7 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7PlusShortToBatt = 404 {"Light output 7 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7PlusShortToGnd = 405 {"Light output 7 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7MinusShortToBatt = 406 {"Light output 7 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7MinusShortToGnd = 407 {"Light output 7 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7CircuitFailure = 408 {"Light output 7 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7Open = 409 {"Light output 7 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7Short = 410 {"Light output 7 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7OverCurrent = 411 {"Light output 7 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7UnderCurrent = 412 {"Light output 7 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8ShortToBatt = 413 {"Light output 8 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8ShortToGnd = 414 {"Light output 8 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8PlusShortToBatt = 415 {"Light output 8 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8PlusShortToGnd = 416 {"Light output 8 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8MinusShortToBatt = 417 {"Light output 8 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8MinusShortToGnd = 418 {"Light output 8 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8CircuitFailure = 419 {"Light output 8 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8Open = 420 {"Light output 8 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8Short = 421 {"Light output 8 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8OverCurrent = 422 {"Light output 8 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8UnderCurrent = 423 {"Light output 8 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9ShortToBatt = 424 {"Light output 9 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9ShortToGnd = 425 {"Light output 9 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9PlusShortToBatt = 426 {"Light output 9 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9PlusShortToGnd = 427 {"Light output 9 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9MinusShortToBatt = 428 {"Light output 9 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9MinusShortToGnd = 429 {"Light output 9 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9CircuitFailure = 430 {"Light output 9 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9Open = 431 {"Light output 9 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9Short = 432 {"Light output 9 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9OverCurrent = 433 {"Light output 9 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9UnderCurrent = 434 {"Light output 9 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10ShortToBatt = 435 {"Light output 10 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10ShortToGnd = 436 {"Light output 10 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10PlusShortToBatt = 437 {"Light output 10 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10PlusShortToGnd = 438 {"Light output 10 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10MinusShortToBatt = 439 {"Light output 10 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10MinusShortToGnd = 440 {"Light output 10 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10CircuitFailure = 441 {"Light output 10 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10Open = 442 {"Light output 10 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10Short = 443 {"Light output 10 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10OverCurrent = 444 {"Light output 10 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10UnderCurrent = 445 {"Light output 10 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11ShortToBatt = 446 {"Light output 11 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11ShortToGnd = 447 {"Light output 11 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11PlusShortToBatt = 448 {"Light output 11 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11PlusShortToGnd = 449 {"Light output 11 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11MinusShortToBatt = 450 {"Light output 11 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11MinusShortToGnd = 451 {"Light output 11 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11CircuitFailure = 452 {"Light output 11 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11Open = 453 {"Light output 11 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11Short = 454 {"Light output 11 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11OverCurrent = 455 {"Light output 11 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11UnderCurrent = 456 {"Light output 11 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12ShortToBatt = 457 {"Light output 12 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12ShortToGnd = 458 {"Light output 12 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12PlusShortToBatt = 459 {"Light output 12 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12PlusShortToGnd = 460 {"Light output 12 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12MinusShortToBatt = 461 {"Light output 12 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12MinusShortToGnd = 462 {"Light output 12 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12CircuitFailure = 463 {"Light output 12 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12Open = 464 {"Light output 12 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12Short = 465 {"Light output 12 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12OverCurrent = 466 {"Light output 12 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12UnderCurrent = 467 {"Light output 12 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13ShortToBatt = 468 {"Light output 13 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13ShortToGnd = 469 {"Light output 13 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13PlusShortToBatt = 470 {"Light output 13 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13PlusShortToGnd = 471 {"Light output 13 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13MinusShortToBatt = 472 {"Light output 13 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13MinusShortToGnd = 473 {"Light output 13 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13CircuitFailure = 474 {"Light output 13 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13Open = 475 {"Light output 13 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13Short = 476 {"Light output 13 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13OverCurrent = 477 {"Light output 13 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13UnderCurrent = 478 {"Light output 13 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14ShortToBatt = 479 {"Light output 14 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14ShortToGnd = 480 {"Light output 14 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14PlusShortToBatt = 481 {"Light output 14 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14PlusShortToGnd = 482 {"Light output 14 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14MinusShortToBatt = 483 {"Light output 14 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14MinusShortToGnd = 484 {"Light output 14 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14CircuitFailure = 485 {"Light output 14 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14Open = 486 {"Light output 14 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14Short = 487 {"Light output 14 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14OverCurrent = 488 {"Light output 14 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14UnderCurrent = 489 {"Light output 14 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15ShortToBatt = 490 {"Light output 15 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15ShortToGnd = 491 {"Light output 15 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15PlusShortToBatt = 492 {"Light output 15 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15PlusShortToGnd = 493 {"Light output 15 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15MinusShortToBatt = 494 {"Light output 15 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15MinusShortToGnd = 495 {"Light output 15 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15CircuitFailure = 496 {"Light output 15 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15Open = 497 {"Light output 15 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15Short = 498 {"Light output 15 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15OverCurrent = 499 {"Light output 15 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15UnderCurrent = 500 {"Light output 15 under current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16ShortToBatt = 501 {"Light output 16 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16ShortToGnd = 502 {"Light output 16 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16PlusShortToBatt = 503 {"Light output 16 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16PlusShortToGnd = 504 {"Light output 16 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16MinusShortToBatt = 505 {"Light output 16 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16MinusShortToGnd = 506 {"Light output 16 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16CircuitFailure = 507 {"Light output 16 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16Open = 508 {"Light output 16 open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16Short = 509 {"Light output 16 short", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16OverCurrent = 510 {"Light output 16 over current", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16UnderCurrent = 511 {"Light output 16 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputRShortToBatt = 512 {"RGB output R short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputRShortToGnd = 513 {"RGB output R short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputRPlusShortToBatt = 514 {"RGB output R plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputRPlusShortToGnd = 515 {"RGB output R plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputRMinusShortToBatt = 516 {"RGB output R minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputRMinusShortToGnd = 517 {"RGB output R minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputRCircuitFailure = 518 {"RGB output R circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputROpen = 519 {"RGB output R open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputRShort = 520 {"RGB output R short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputROverCurrent = 521 {"RGB output R over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputRUnderCurrent = 522 {"RGB output R under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputGShortToBatt = 523 {"RGB output G short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputGShortToGnd = 524 {"RGB output G short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputGPlusShortToBatt = 525 {"RGB output G plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputGPlusShortToGnd = 526 {"RGB output G plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputGMinusShortToBatt = 527 {"RGB output G minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputGMinusShortToGnd = 528 {"RGB output G minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputGCircuitFailure = 529 {"RGB output G circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputGOpen = 530 {"RGB output G open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputGShort = 531 {"RGB output G short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputGOverCurrent = 532 {"RGB output G over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputGUnderCurrent = 533 {"RGB output G under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputBShortToBatt = 534 {"RGB output B short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputBShortToGnd = 535 {"RGB output B short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputBPlusShortToBatt = 536 {"RGB output B plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputBPlusShortToGnd = 537 {"RGB output B plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputBMinusShortToBatt = 538 {"RGB output B minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputBMinusShortToGnd = 539 {"RGB output B minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputBCircuitFailure = 540 {"RGB output B circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputBOpen = 541 {"RGB output B open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputBShort = 542 {"RGB output B short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputBOverCurrent = 543 {"RGB output B over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputBUnderCurrent = 544 {"RGB output B under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR1ShortToBatt = 545 {"RGB output R1 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR1ShortToGnd = 546 {"RGB output R1 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR1PlusShortToBatt = 547 {"RGB output R1 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR1PlusShortToGnd = 548 {"RGB output R1 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR1MinusShortToBatt = 549 {"RGB output R1 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR1MinusShortToGnd = 550 {"RGB output R1 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR1CircuitFailure = 551 {"RGB output R1 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR1Open = 552 {"RGB output R1 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR1Short = 553 {"RGB output R1 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR1OverCurrent = 554 {"RGB output R1 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR1UnderCurrent = 555 {"RGB output R1 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG1ShortToBatt = 556 {"RGB output G1 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG1ShortToGnd = 557 {"RGB output G1 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG1PlusShortToBatt = 558 {"RGB output G1 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG1PlusShortToGnd = 559 {"RGB output G1 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG1MinusShortToBatt = 560 {"RGB output G1 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG1MinusShortToGnd = 561 {"RGB output G1 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG1CircuitFailure = 562 {"RGB output G1 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG1Open = 563 {"RGB output G1 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG1Short = 564 {"RGB output G1 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG1OverCurrent = 565 {"RGB output G1 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG1UnderCurrent = 566 {"RGB output G1 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB1ShortToBatt = 567 {"RGB output B1 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB1ShortToGnd = 568 {"RGB output B1 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB1PlusShortToBatt = 569 {"RGB output B1 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB1PlusShortToGnd = 570 {"RGB output B1 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB1MinusShortToBatt = 571 {"RGB output B1 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB1MinusShortToGnd = 572 {"RGB output B1 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB1CircuitFailure = 573 {"RGB output B1 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB1Open = 574 {"RGB output B1 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB1Short = 575 {"RGB output B1 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB1OverCurrent = 576 {"RGB output B1 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB1UnderCurrent = 577 {"RGB output B1 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR2ShortToBatt = 578 {"RGB output R2 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR2ShortToGnd = 579 {"RGB output R2 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR2PlusShortToBatt = 580 {"RGB output R2 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR2PlusShortToGnd = 581 {"RGB output R2 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR2MinusShortToBatt = 582 {"RGB output R2 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR2MinusShortToGnd = 583 {"RGB output R2 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR2CircuitFailure = 584 {"RGB output R2 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR2Open = 585 {"RGB output R2 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR2Short = 586 {"RGB output R2 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR2OverCurrent = 587 {"RGB output R2 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR2UnderCurrent = 588 {"RGB output R2 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG2ShortToBatt = 589 {"RGB output G2 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG2ShortToGnd = 590 {"RGB output G2 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG2PlusShortToBatt = 591 {"RGB output G2 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG2PlusShortToGnd = 592 {"RGB output G2 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG2MinusShortToBatt = 593 {"RGB output G2 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG2MinusShortToGnd = 594 {"RGB output G2 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG2CircuitFailure = 595 {"RGB output G2 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG2Open = 596 {"RGB output G2 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG2Short = 597 {"RGB output G2 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG2OverCurrent = 598 {"RGB output G2 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG2UnderCurrent = 599 {"RGB output G2 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB2ShortToBatt = 600 {"RGB output B2 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB2ShortToGnd = 601 {"RGB output B2 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB2PlusShortToBatt = 602 {"RGB output B2 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB2PlusShortToGnd = 603 {"RGB output B2 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB2MinusShortToBatt = 604 {"RGB output B2 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB2MinusShortToGnd = 605 {"RGB output B2 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB2CircuitFailure = 606 {"RGB output B2 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB2Open = 607 {"RGB output B2 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB2Short = 608 {"RGB output B2 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB2OverCurrent = 609 {"RGB output B2 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB2UnderCurrent = 610 {"RGB output B2 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR3ShortToBatt = 611 {"RGB output R3 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR3ShortToGnd = 612 {"RGB output R3 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR3PlusShortToBatt = 613 {"RGB output R3 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR3PlusShortToGnd = 614 {"RGB output R3 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR3MinusShortToBatt = 615 {"RGB output R3 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR3MinusShortToGnd = 616 {"RGB output R3 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR3CircuitFailure = 617 {"RGB output R3 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR3Open = 618 {"RGB output R3 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR3Short = 619 {"RGB output R3 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR3OverCurrent = 620 {"RGB output R3 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR3UnderCurrent = 621 {"RGB output R3 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG3ShortToBatt = 622 {"RGB output G3 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG3ShortToGnd = 623 {"RGB output G3 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG3PlusShortToBatt = 624 {"RGB output G3 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG3PlusShortToGnd = 625 {"RGB output G3 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG3MinusShortToBatt = 626 {"RGB output G3 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG3MinusShortToGnd = 627 {"RGB output G3 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG3CircuitFailure = 628 {"RGB output G3 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG3Open = 629 {"RGB output G3 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG3Short = 630 {"RGB output G3 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG3OverCurrent = 631 {"RGB output G3 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG3UnderCurrent = 632 {"RGB output G3 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB3ShortToBatt = 633 {"RGB output B3 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB3ShortToGnd = 634 {"RGB output B3 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB3PlusShortToBatt = 635 {"RGB output B3 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB3PlusShortToGnd = 636 {"RGB output B3 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB3MinusShortToBatt = 637 {"RGB output B3 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB3MinusShortToGnd = 638 {"RGB output B3 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB3CircuitFailure = 639 {"RGB output B3 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB3Open = 640 {"RGB output B3 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB3Short = 641 {"RGB output B3 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB3OverCurrent = 642 {"RGB output B3 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB3UnderCurrent = 643 {"RGB output B3 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR4ShortToBatt = 644 {"RGB output R4 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR4ShortToGnd = 645 {"RGB output R4 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR4PlusShortToBatt = 646 {"RGB output R4 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR4PlusShortToGnd = 647 {"RGB output R4 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR4MinusShortToBatt = 648 {"RGB output R4 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR4MinusShortToGnd = 649 {"RGB output R4 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR4CircuitFailure = 650 {"RGB output R4 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR4Open = 651 {"RGB output R4 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR4Short = 652 {"RGB output R4 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR4OverCurrent = 653 {"RGB output R4 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR4UnderCurrent = 654 {"RGB output R4 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG4ShortToBatt = 655 {"RGB output G4 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG4ShortToGnd = 656 {"RGB output G4 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG4PlusShortToBatt = 657 {"RGB output G4 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG4PlusShortToGnd = 658 {"RGB output G4 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG4MinusShortToBatt = 659 {"RGB output G4 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG4MinusShortToGnd = 660 {"RGB output G4 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG4CircuitFailure = 661 {"RGB output G4 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG4Open = 662 {"RGB output G4 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG4Short = 663 {"RGB output G4 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG4OverCurrent = 664 {"RGB output G4 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG4UnderCurrent = 665 {"RGB output G4 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB4ShortToBatt = 666 {"RGB output B4 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB4ShortToGnd = 667 {"RGB output B4 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB4PlusShortToBatt = 668 {"RGB output B4 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB4PlusShortToGnd = 669 {"RGB output B4 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB4MinusShortToBatt = 670 {"RGB output B4 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB4MinusShortToGnd = 671 {"RGB output B4 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB4CircuitFailure = 672 {"RGB output B4 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB4Open = 673 {"RGB output B4 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB4Short = 674 {"RGB output B4 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB4OverCurrent = 675 {"RGB output B4 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB4UnderCurrent = 676 {"RGB output B4 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR5ShortToBatt = 677 {"RGB output R5 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR5ShortToGnd = 678 {"RGB output R5 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR5PlusShortToBatt = 679 {"RGB output R5 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR5PlusShortToGnd = 680 {"RGB output R5 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR5MinusShortToBatt = 681 {"RGB output R5 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR5MinusShortToGnd = 682 {"RGB output R5 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR5CircuitFailure = 683 {"RGB output R5 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR5Open = 684 {"RGB output R5 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR5Short = 685 {"RGB output R5 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR5OverCurrent = 686 {"RGB output R5 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR5UnderCurrent = 687 {"RGB output R5 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG5ShortToBatt = 688 {"RGB output G5 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG5ShortToGnd = 689 {"RGB output G5 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG5PlusShortToBatt = 690 {"RGB output G5 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG5PlusShortToGnd = 691 {"RGB output G5 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG5MinusShortToBatt = 692 {"RGB output G5 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG5MinusShortToGnd = 693 {"RGB output G5 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG5CircuitFailure = 694 {"RGB output G5 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG5Open = 695 {"RGB output G5 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG5Short = 696 {"RGB output G5 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG5OverCurrent = 697 {"RGB output G5 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG5UnderCurrent = 698 {"RGB output G5 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB5ShortToBatt = 699 {"RGB output B5 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB5ShortToGnd = 700 {"RGB output B5 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB5PlusShortToBatt = 701 {"RGB output B5 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB5PlusShortToGnd = 702 {"RGB output B5 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB5MinusShortToBatt = 703 {"RGB output B5 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB5MinusShortToGnd = 704 {"RGB output B5 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB5CircuitFailure = 705 {"RGB output B5 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB5Open = 706 {"RGB output B5 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB5Short = 707 {"RGB output B5 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB5OverCurrent = 708 {"RGB output B5 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB5UnderCurrent = 709 {"RGB output B5 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR6ShortToBatt = 710 {"RGB output R6 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR6ShortToGnd = 711 {"RGB output R6 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR6PlusShortToBatt = 712 {"RGB output R6 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR6PlusShortToGnd = 713 {"RGB output R6 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR6MinusShortToBatt = 714 {"RGB output R6 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR6MinusShortToGnd = 715 {"RGB output R6 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR6CircuitFailure = 716 {"RGB output R6 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR6Open = 717 {"RGB output R6 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR6Short = 718 {"RGB output R6 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR6OverCurrent = 719 {"RGB output R6 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR6UnderCurrent = 720 {"RGB output R6 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG6ShortToBatt = 721 {"RGB output G6 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG6ShortToGnd = 722 {"RGB output G6 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG6PlusShortToBatt = 723 {"RGB output G6 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG6PlusShortToGnd = 724 {"RGB output G6 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG6MinusShortToBatt = 725 {"RGB output G6 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG6MinusShortToGnd = 726 {"RGB output G6 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG6CircuitFailure = 727 {"RGB output G6 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG6Open = 728 {"RGB output G6 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG6Short = 729 {"RGB output G6 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG6OverCurrent = 730 {"RGB output G6 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG6UnderCurrent = 731 {"RGB output G6 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB6ShortToBatt = 732 {"RGB output B6 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB6ShortToGnd = 733 {"RGB output B6 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB6PlusShortToBatt = 734 {"RGB output B6 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB6PlusShortToGnd = 735 {"RGB output B6 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB6MinusShortToBatt = 736 {"RGB output B6 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB6MinusShortToGnd = 737 {"RGB output B6 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB6CircuitFailure = 738 {"RGB output B6 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB6Open = 739 {"RGB output B6 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB6Short = 740 {"RGB output B6 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB6OverCurrent = 741 {"RGB output B6 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB6UnderCurrent = 742 {"RGB output B6 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR7ShortToBatt = 743 {"RGB output R7 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR7ShortToGnd = 744 {"RGB output R7 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR7PlusShortToBatt = 745 {"RGB output R7 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR7PlusShortToGnd = 746 {"RGB output R7 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR7MinusShortToBatt = 747 {"RGB output R7 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR7MinusShortToGnd = 748 {"RGB output R7 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR7CircuitFailure = 749 {"RGB output R7 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR7Open = 750 {"RGB output R7 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR7Short = 751 {"RGB output R7 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR7OverCurrent = 752 {"RGB output R7 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR7UnderCurrent = 753 {"RGB output R7 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG7ShortToBatt = 754 {"RGB output G7 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG7ShortToGnd = 755 {"RGB output G7 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG7PlusShortToBatt = 756 {"RGB output G7 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG7PlusShortToGnd = 757 {"RGB output G7 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG7MinusShortToBatt = 758 {"RGB output G7 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG7MinusShortToGnd = 759 {"RGB output G7 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG7CircuitFailure = 760 {"RGB output G7 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG7Open = 761 {"RGB output G7 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG7Short = 762 {"RGB output G7 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG7OverCurrent = 763 {"RGB output G7 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG7UnderCurrent = 764 {"RGB output G7 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB7ShortToBatt = 765 {"RGB output B7 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB7ShortToGnd = 766 {"RGB output B7 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB7PlusShortToBatt = 767 {"RGB output B7 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB7PlusShortToGnd = 768 {"RGB output B7 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB7MinusShortToBatt = 769 {"RGB output B7 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB7MinusShortToGnd = 770 {"RGB output B7 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB7CircuitFailure = 771 {"RGB output B7 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB7Open = 772 {"RGB output B7 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB7Short = 773 {"RGB output B7 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB7OverCurrent = 774 {"RGB output B7 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB7UnderCurrent = 775 {"RGB output B7 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR8ShortToBatt = 776 {"RGB output R8 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR8ShortToGnd = 777 {"RGB output R8 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR8PlusShortToBatt = 778 {"RGB output R8 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR8PlusShortToGnd = 779 {"RGB output R8 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR8MinusShortToBatt = 780 {"RGB output R8 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR8MinusShortToGnd = 781 {"RGB output R8 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR8CircuitFailure = 782 {"RGB output R8 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR8Open = 783 {"RGB output R8 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR8Short = 784 {"RGB output R8 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR8OverCurrent = 785 {"RGB output R8 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR8UnderCurrent = 786 {"RGB output R8 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG8ShortToBatt = 787 {"RGB output G8 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG8ShortToGnd = 788 {"RGB output G8 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG8PlusShortToBatt = 789 {"RGB output G8 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG8PlusShortToGnd = 790 {"RGB output G8 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG8MinusShortToBatt = 791 {"RGB output G8 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG8MinusShortToGnd = 792 {"RGB output G8 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG8CircuitFailure = 793 {"RGB output G8 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG8Open = 794 {"RGB output G8 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG8Short = 795 {"RGB output G8 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG8OverCurrent = 796 {"RGB output G8 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG8UnderCurrent = 797 {"RGB output G8 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB8ShortToBatt = 798 {"RGB output B8 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB8ShortToGnd = 799 {"RGB output B8 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB8PlusShortToBatt = 800 {"RGB output B8 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB8PlusShortToGnd = 801 {"RGB output B8 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB8MinusShortToBatt = 802 {"RGB output B8 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB8MinusShortToGnd = 803 {"RGB output B8 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB8CircuitFailure = 804 {"RGB output B8 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB8Open = 805 {"RGB output B8 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB8Short = 806 {"RGB output B8 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB8OverCurrent = 807 {"RGB output B8 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB8UnderCurrent = 808 {"RGB output B8 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR9ShortToBatt = 809 {"RGB output R9 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR9ShortToGnd = 810 {"RGB output R9 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR9PlusShortToBatt = 811 {"RGB output R9 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR9PlusShortToGnd = 812 {"RGB output R9 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR9MinusShortToBatt = 813 {"RGB output R9 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR9MinusShortToGnd = 814 {"RGB output R9 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR9CircuitFailure = 815 {"RGB output R9 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR9Open = 816 {"RGB output R9 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR9Short = 817 {"RGB output R9 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR9OverCurrent = 818 {"RGB output R9 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR9UnderCurrent = 819 {"RGB output R9 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG9ShortToBatt = 820 {"RGB output G9 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG9ShortToGnd = 821 {"RGB output G9 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG9PlusShortToBatt = 822 {"RGB output G9 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG9PlusShortToGnd = 823 {"RGB output G9 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG9MinusShortToBatt = 824 {"RGB output G9 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG9MinusShortToGnd = 825 {"RGB output G9 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG9CircuitFailure = 826 {"RGB output G9 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG9Open = 827 {"RGB output G9 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG9Short = 828 {"RGB output G9 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG9OverCurrent = 829 {"RGB output G9 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG9UnderCurrent = 830 {"RGB output G9 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB9ShortToBatt = 831 {"RGB output B9 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB9ShortToGnd = 832 {"RGB output B9 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB9PlusShortToBatt = 833 {"RGB output B9 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB9PlusShortToGnd = 834 {"RGB output B9 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB9MinusShortToBatt = 835 {"RGB output B9 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB9MinusShortToGnd = 836 {"RGB output B9 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB9CircuitFailure = 837 {"RGB output B9 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB9Open = 838 {"RGB output B9 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB9Short = 839 {"RGB output B9 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB9OverCurrent = 840 {"RGB output B9 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB9UnderCurrent = 841 {"RGB output B9 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR10ShortToBatt = 842 {"RGB output R10 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR10ShortToGnd = 843 {"RGB output R10 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR10PlusShortToBatt = 844 {"RGB output R10 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR10PlusShortToGnd = 845 {"RGB output R10 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR10MinusShortToBatt = 846 {"RGB output R10 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR10MinusShortToGnd = 847 {"RGB output R10 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR10CircuitFailure = 848 {"RGB output R10 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR10Open = 849 {"RGB output R10 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR10Short = 850 {"RGB output R10 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR10OverCurrent = 851 {"RGB output R10 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR10UnderCurrent = 852 {"RGB output R10 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG10ShortToBatt = 853 {"RGB output G10 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG10ShortToGnd = 854 {"RGB output G10 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG10PlusShortToBatt = 855 {"RGB output G10 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG10PlusShortToGnd = 856 {"RGB output G10 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG10MinusShortToBatt = 857 {"RGB output G10 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG10MinusShortToGnd = 858 {"RGB output G10 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG10CircuitFailure = 859 {"RGB output G10 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG10Open = 860 {"RGB output G10 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG10Short = 861 {"RGB output G10 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG10OverCurrent = 862 {"RGB output G10 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG10UnderCurrent = 863 {"RGB output G10 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB10ShortToBatt = 864 {"RGB output B10 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB10ShortToGnd = 865 {"RGB output B10 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB10PlusShortToBatt = 866 {"RGB output B10 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB10PlusShortToGnd = 867 {"RGB output B10 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB10MinusShortToBatt = 868 {"RGB output B10 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB10MinusShortToGnd = 869 {"RGB output B10 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB10CircuitFailure = 870 {"RGB output B10 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB10Open = 871 {"RGB output B10 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB10Short = 872 {"RGB output B10 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB10OverCurrent = 873 {"RGB output B10 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB10UnderCurrent = 874 {"RGB output B10 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR11ShortToBatt = 875 {"RGB output R11 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR11ShortToGnd = 876 {"RGB output R11 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR11PlusShortToBatt = 877 {"RGB output R11 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR11PlusShortToGnd = 878 {"RGB output R11 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR11MinusShortToBatt = 879 {"RGB output R11 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR11MinusShortToGnd = 880 {"RGB output R11 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR11CircuitFailure = 881 {"RGB output R11 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR11Open = 882 {"RGB output R11 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR11Short = 883 {"RGB output R11 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR11OverCurrent = 884 {"RGB output R11 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR11UnderCurrent = 885 {"RGB output R11 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG11ShortToBatt = 886 {"RGB output G11 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG11ShortToGnd = 887 {"RGB output G11 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG11PlusShortToBatt = 888 {"RGB output G11 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG11PlusShortToGnd = 889 {"RGB output G11 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG11MinusShortToBatt = 890 {"RGB output G11 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG11MinusShortToGnd = 891 {"RGB output G11 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG11CircuitFailure = 892 {"RGB output G11 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG11Open = 893 {"RGB output G11 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG11Short = 894 {"RGB output G11 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG11OverCurrent = 895 {"RGB output G11 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG11UnderCurrent = 896 {"RGB output G11 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB11ShortToBatt = 897 {"RGB output B11 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB11ShortToGnd = 898 {"RGB output B11 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB11PlusShortToBatt = 899 {"RGB output B11 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB11PlusShortToGnd = 900 {"RGB output B11 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB11MinusShortToBatt = 901 {"RGB output B11 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB11MinusShortToGnd = 902 {"RGB output B11 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB11CircuitFailure = 903 {"RGB output B11 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB11Open = 904 {"RGB output B11 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB11Short = 905 {"RGB output B11 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB11OverCurrent = 906 {"RGB output B11 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB11UnderCurrent = 907 {"RGB output B11 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR12ShortToBatt = 908 {"RGB output R12 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR12ShortToGnd = 909 {"RGB output R12 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR12PlusShortToBatt = 910 {"RGB output R12 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR12PlusShortToGnd = 911 {"RGB output R12 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR12MinusShortToBatt = 912 {"RGB output R12 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR12MinusShortToGnd = 913 {"RGB output R12 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR12CircuitFailure = 914 {"RGB output R12 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR12Open = 915 {"RGB output R12 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR12Short = 916 {"RGB output R12 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR12OverCurrent = 917 {"RGB output R12 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR12UnderCurrent = 918 {"RGB output R12 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG12ShortToBatt = 919 {"RGB output G12 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG12ShortToGnd = 920 {"RGB output G12 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG12PlusShortToBatt = 921 {"RGB output G12 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG12PlusShortToGnd = 922 {"RGB output G12 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG12MinusShortToBatt = 923 {"RGB output G12 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG12MinusShortToGnd = 924 {"RGB output G12 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG12CircuitFailure = 925 {"RGB output G12 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG12Open = 926 {"RGB output G12 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG12Short = 927 {"RGB output G12 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG12OverCurrent = 928 {"RGB output G12 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG12UnderCurrent = 929 {"RGB output G12 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB12ShortToBatt = 930 {"RGB output B12 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB12ShortToGnd = 931 {"RGB output B12 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB12PlusShortToBatt = 932 {"RGB output B12 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB12PlusShortToGnd = 933 {"RGB output B12 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB12MinusShortToBatt = 934 {"RGB output B12 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB12MinusShortToGnd = 935 {"RGB output B12 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB12CircuitFailure = 936 {"RGB output B12 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB12Open = 937 {"RGB output B12 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB12Short = 938 {"RGB output B12 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB12OverCurrent = 939 {"RGB output B12 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB12UnderCurrent = 940 {"RGB output B12 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR13ShortToBatt = 941 {"RGB output R13 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR13ShortToGnd = 942 {"RGB output R13 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR13PlusShortToBatt = 943 {"RGB output R13 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR13PlusShortToGnd = 944 {"RGB output R13 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR13MinusShortToBatt = 945 {"RGB output R13 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR13MinusShortToGnd = 946 {"RGB output R13 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR13CircuitFailure = 947 {"RGB output R13 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR13Open = 948 {"RGB output R13 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR13Short = 949 {"RGB output R13 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR13OverCurrent = 950 {"RGB output R13 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR13UnderCurrent = 951 {"RGB output R13 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG13ShortToBatt = 952 {"RGB output G13 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG13ShortToGnd = 953 {"RGB output G13 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG13PlusShortToBatt = 954 {"RGB output G13 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG13PlusShortToGnd = 955 {"RGB output G13 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG13MinusShortToBatt = 956 {"RGB output G13 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG13MinusShortToGnd = 957 {"RGB output G13 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG13CircuitFailure = 958 {"RGB output G13 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG13Open = 959 {"RGB output G13 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG13Short = 960 {"RGB output G13 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG13OverCurrent = 961 {"RGB output G13 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG13UnderCurrent = 962 {"RGB output G13 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB13ShortToBatt = 963 {"RGB output B13 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB13ShortToGnd = 964 {"RGB output B13 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB13PlusShortToBatt = 965 {"RGB output B13 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB13PlusShortToGnd = 966 {"RGB output B13 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB13MinusShortToBatt = 967 {"RGB output B13 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB13MinusShortToGnd = 968 {"RGB output B13 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB13CircuitFailure = 969 {"RGB output B13 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB13Open = 970 {"RGB output B13 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB13Short = 971 {"RGB output B13 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB13OverCurrent = 972 {"RGB output B13 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB13UnderCurrent = 973 {"RGB output B13 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR14ShortToBatt = 974 {"RGB output R14 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR14ShortToGnd = 975 {"RGB output R14 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR14PlusShortToBatt = 976 {"RGB output R14 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR14PlusShortToGnd = 977 {"RGB output R14 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR14MinusShortToBatt = 978 {"RGB output R14 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR14MinusShortToGnd = 979 {"RGB output R14 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR14CircuitFailure = 980 {"RGB output R14 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR14Open = 981 {"RGB output R14 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR14Short = 982 {"RGB output R14 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR14OverCurrent = 983 {"RGB output R14 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR14UnderCurrent = 984 {"RGB output R14 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG14ShortToBatt = 985 {"RGB output G14 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG14ShortToGnd = 986 {"RGB output G14 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG14PlusShortToBatt = 987 {"RGB output G14 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG14PlusShortToGnd = 988 {"RGB output G14 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG14MinusShortToBatt = 989 {"RGB output G14 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG14MinusShortToGnd = 990 {"RGB output G14 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG14CircuitFailure = 991 {"RGB output G14 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG14Open = 992 {"RGB output G14 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG14Short = 993 {"RGB output G14 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG14OverCurrent = 994 {"RGB output G14 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG14UnderCurrent = 995 {"RGB output G14 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB14ShortToBatt = 996 {"RGB output B14 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB14ShortToGnd = 997 {"RGB output B14 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB14PlusShortToBatt = 998 {"RGB output B14 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB14PlusShortToGnd = 999 {"RGB output B14 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB14MinusShortToBatt = 1000 {"RGB output B14 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB14MinusShortToGnd = 1001 {"RGB output B14 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB14CircuitFailure = 1002 {"RGB output B14 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB14Open = 1003 {"RGB output B14 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB14Short = 1004 {"RGB output B14 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB14OverCurrent = 1005 {"RGB output B14 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB14UnderCurrent = 1006 {"RGB output B14 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR15ShortToBatt = 1007 {"RGB output R15 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR15ShortToGnd = 1008 {"RGB output R15 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR15PlusShortToBatt = 1009 {"RGB output R15 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR15PlusShortToGnd = 1010 {"RGB output R15 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR15MinusShortToBatt = 1011 {"RGB output R15 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR15MinusShortToGnd = 1012 {"RGB output R15 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR15CircuitFailure = 1013 {"RGB output R15 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR15Open = 1014 {"RGB output R15 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR15Short = 1015 {"RGB output R15 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR15OverCurrent = 1016 {"RGB output R15 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR15UnderCurrent = 1017 {"RGB output R15 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG15ShortToBatt = 1018 {"RGB output G15 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG15ShortToGnd = 1019 {"RGB output G15 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG15PlusShortToBatt = 1020 {"RGB output G15 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG15PlusShortToGnd = 1021 {"RGB output G15 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG15MinusShortToBatt = 1022 {"RGB output G15 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG15MinusShortToGnd = 1023 {"RGB output G15 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG15CircuitFailure = 1024 {"RGB output G15 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG15Open = 1025 {"RGB output G15 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG15Short = 1026 {"RGB output G15 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG15OverCurrent = 1027 {"RGB output G15 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG15UnderCurrent = 1028 {"RGB output G15 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB15ShortToBatt = 1029 {"RGB output B15 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB15ShortToGnd = 1030 {"RGB output B15 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB15PlusShortToBatt = 1031 {"RGB output B15 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB15PlusShortToGnd = 1032 {"RGB output B15 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB15MinusShortToBatt = 1033 {"RGB output B15 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB15MinusShortToGnd = 1034 {"RGB output B15 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB15CircuitFailure = 1035 {"RGB output B15 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB15Open = 1036 {"RGB output B15 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB15Short = 1037 {"RGB output B15 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB15OverCurrent = 1038 {"RGB output B15 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB15UnderCurrent = 1039 {"RGB output B15 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR16ShortToBatt = 1040 {"RGB output R16 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR16ShortToGnd = 1041 {"RGB output R16 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR16PlusShortToBatt = 1042 {"RGB output R16 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR16PlusShortToGnd = 1043 {"RGB output R16 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR16MinusShortToBatt = 1044 {"RGB output R16 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR16MinusShortToGnd = 1045 {"RGB output R16 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR16CircuitFailure = 1046 {"RGB output R16 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR16Open = 1047 {"RGB output R16 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR16Short = 1048 {"RGB output R16 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR16OverCurrent = 1049 {"RGB output R16 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputR16UnderCurrent = 1050 {"RGB output R16 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG16ShortToBatt = 1051 {"RGB output G16 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG16ShortToGnd = 1052 {"RGB output G16 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG16PlusShortToBatt = 1053 {"RGB output G16 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG16PlusShortToGnd = 1054 {"RGB output G16 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG16MinusShortToBatt = 1055 {"RGB output G16 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG16MinusShortToGnd = 1056 {"RGB output G16 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG16CircuitFailure = 1057 {"RGB output G16 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG16Open = 1058 {"RGB output G16 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG16Short = 1059 {"RGB output G16 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG16OverCurrent = 1060 {"RGB output G16 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputG16UnderCurrent = 1061 {"RGB output G16 under current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB16ShortToBatt = 1062 {"RGB output B16 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB16ShortToGnd = 1063 {"RGB output B16 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB16PlusShortToBatt = 1064 {"RGB output B16 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB16PlusShortToGnd = 1065 {"RGB output B16 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB16MinusShortToBatt = 1066 {"RGB output B16 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB16MinusShortToGnd = 1067 {"RGB output B16 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB16CircuitFailure = 1068 {"RGB output B16 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB16Open = 1069 {"RGB output B16 open", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB16Short = 1070 {"RGB output B16 short", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB16OverCurrent = 1071 {"RGB output B16 over current", DtcSeverity::Fault, DtcCategory::Power},
+        RgbOutputB16UnderCurrent = 1072 {"RGB output B16 under current", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectPowerShortToBatt = 1073 {"Hall effect power short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectPowerShortToGnd = 1074 {"Hall effect power short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectPowerPlusShortToBatt = 1075 {"Hall effect power plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectPowerPlusShortToGnd = 1076 {"Hall effect power plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectPowerMinusShortToBatt = 1077 {"Hall effect power minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectPowerMinusShortToGnd = 1078 {"Hall effect power minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectPowerCircuitFailure = 1079 {"Hall effect power circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectPowerOpen = 1080 {"Hall effect power open", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectPowerShort = 1081 {"Hall effect power short", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectPowerOverCurrent = 1082 {"Hall effect power over current", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffectPowerUnderCurrent = 1083 {"Hall effect power under current", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfShortToBatt = 1084 {"Jack left front short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfShortToGnd = 1085 {"Jack left front short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfPlusShortToBatt = 1086 {"Jack left front plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfPlusShortToGnd = 1087 {"Jack left front plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfMinusShortToBatt = 1088 {"Jack left front minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfMinusShortToGnd = 1089 {"Jack left front minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfCircuitFailure = 1090 {"Jack left front circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfOpen = 1091 {"Jack left front open", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfShort = 1092 {"Jack left front short", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfOverCurrent = 1093 {"Jack left front over current", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfUnderCurrent = 1094 {"Jack left front under current", DtcSeverity::Fault, DtcCategory::Power},
+        JackLfOutOfStroke = 1095 {"Jack left front out of stroke", DtcSeverity::Warning, DtcCategory::Motor},
+        JackLfHallEffectSignalLost = 1096 {"Jack left front hall effect signal lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        JackLfPositionLost = 1097 {"Jack left front position lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        JackLmShortToBatt = 1098 {"Jack left middle short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackLmShortToGnd = 1099 {"Jack left middle short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackLmPlusShortToBatt = 1100 {"Jack left middle plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackLmPlusShortToGnd = 1101 {"Jack left middle plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackLmMinusShortToBatt = 1102 {"Jack left middle minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackLmMinusShortToGnd = 1103 {"Jack left middle minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackLmCircuitFailure = 1104 {"Jack left middle circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        JackLmOpen = 1105 {"Jack left middle open", DtcSeverity::Fault, DtcCategory::Power},
+        JackLmShort = 1106 {"Jack left middle short", DtcSeverity::Fault, DtcCategory::Power},
+        JackLmOverCurrent = 1107 {"Jack left middle over current", DtcSeverity::Fault, DtcCategory::Power},
+        JackLmUnderCurrent = 1108 {"Jack left middle under current", DtcSeverity::Fault, DtcCategory::Power},
+        JackLmOutOfStroke = 1109 {"Jack left middle out of stroke", DtcSeverity::Warning, DtcCategory::Motor},
+        JackLmHallEffectSignalLost = 1110 {"Jack left middle hall effect signal lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        JackLmPositionLost = 1111 {"Jack left middle position lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        JackLrShortToBatt = 1112 {"Jack left rear short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackLrShortToGnd = 1113 {"Jack left rear short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackLrPlusShortToBatt = 1114 {"Jack left rear plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackLrPlusShortToGnd = 1115 {"Jack left rear plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackLrMinusShortToBatt = 1116 {"Jack left rear minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackLrMinusShortToGnd = 1117 {"Jack left rear minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackLrCircuitFailure = 1118 {"Jack left rear circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        JackLrOpen = 1119 {"Jack left rear open", DtcSeverity::Fault, DtcCategory::Power},
+        JackLrShort = 1120 {"Jack left rear short", DtcSeverity::Fault, DtcCategory::Power},
+        JackLrOverCurrent = 1121 {"Jack left rear over current", DtcSeverity::Fault, DtcCategory::Power},
+        JackLrUnderCurrent = 1122 {"Jack left rear under current", DtcSeverity::Fault, DtcCategory::Power},
+        JackLrOutOfStroke = 1123 {"Jack left rear out of stroke", DtcSeverity::Warning, DtcCategory::Motor},
+        JackLrHallEffectSignalLost = 1124 {"Jack left rear hall effect signal lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        JackLrPositionLost = 1125 {"Jack left rear position lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        JackRfShortToBatt = 1126 {"Jack right front short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackRfShortToGnd = 1127 {"Jack right front short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackRfPlusShortToBatt = 1128 {"Jack right front plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackRfPlusShortToGnd = 1129 {"Jack right front plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackRfMinusShortToBatt = 1130 {"Jack right front minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackRfMinusShortToGnd = 1131 {"Jack right front minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackRfCircuitFailure = 1132 {"Jack right front circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        JackRfOpen = 1133 {"Jack right front open", DtcSeverity::Fault, DtcCategory::Power},
+        JackRfShort = 1134 {"Jack right front short", DtcSeverity::Fault, DtcCategory::Power},
+        JackRfOverCurrent = 1135 {"Jack right front over current", DtcSeverity::Fault, DtcCategory::Power},
+        JackRfUnderCurrent = 1136 {"Jack right front under current", DtcSeverity::Fault, DtcCategory::Power},
+        JackRfOutOfStroke = 1137 {"Jack right front out of stroke", DtcSeverity::Warning, DtcCategory::Motor},
+        JackRfHallEffectSignalLost = 1138 {"Jack right front hall effect signal lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        JackRfPositionLost = 1139 {"Jack right front position lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        JackRmShortToBatt = 1140 {"Jack right middle short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackRmShortToGnd = 1141 {"Jack right middle short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackRmPlusShortToBatt = 1142 {"Jack right middle plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackRmPlusShortToGnd = 1143 {"Jack right middle plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackRmMinusShortToBatt = 1144 {"Jack right middle minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackRmMinusShortToGnd = 1145 {"Jack right middle minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackRmCircuitFailure = 1146 {"Jack right middle circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        JackRmOpen = 1147 {"Jack right middle open", DtcSeverity::Fault, DtcCategory::Power},
+        JackRmShort = 1148 {"Jack right middle short", DtcSeverity::Fault, DtcCategory::Power},
+        JackRmOverCurrent = 1149 {"Jack right middle over current", DtcSeverity::Fault, DtcCategory::Power},
+        JackRmUnderCurrent = 1150 {"Jack right middle under current", DtcSeverity::Fault, DtcCategory::Power},
+        JackRmOutOfStroke = 1151 {"Jack right middle out of stroke", DtcSeverity::Warning, DtcCategory::Motor},
+        JackRmHallEffectSignalLost = 1152 {"Jack right middle hall effect signal lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        JackRmPositionLost = 1153 {"Jack right middle position lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        JackRrShortToBatt = 1154 {"Jack right rear short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackRrShortToGnd = 1155 {"Jack right rear short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackRrPlusShortToBatt = 1156 {"Jack right rear plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackRrPlusShortToGnd = 1157 {"Jack right rear plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackRrMinusShortToBatt = 1158 {"Jack right rear minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        JackRrMinusShortToGnd = 1159 {"Jack right rear minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        JackRrCircuitFailure = 1160 {"Jack right rear circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        JackRrOpen = 1161 {"Jack right rear open", DtcSeverity::Fault, DtcCategory::Power},
+        JackRrShort = 1162 {"Jack right rear short", DtcSeverity::Fault, DtcCategory::Power},
+        JackRrOverCurrent = 1163 {"Jack right rear over current", DtcSeverity::Fault, DtcCategory::Power},
+        JackRrUnderCurrent = 1164 {"Jack right rear under current", DtcSeverity::Fault, DtcCategory::Power},
+        JackRrOutOfStroke = 1165 {"Jack right rear out of stroke", DtcSeverity::Warning, DtcCategory::Motor},
+        JackRrHallEffectSignalLost = 1166 {"Jack right rear hall effect signal lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        JackRrPositionLost = 1167 {"Jack right rear position lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        TongueJackShortToBatt = 1168 {"Tongue jack short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        TongueJackShortToGnd = 1169 {"Tongue jack short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        TongueJackPlusShortToBatt = 1170 {"Tongue jack plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        TongueJackPlusShortToGnd = 1171 {"Tongue jack plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        TongueJackMinusShortToBatt = 1172 {"Tongue jack minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        TongueJackMinusShortToGnd = 1173 {"Tongue jack minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        TongueJackCircuitFailure = 1174 {"Tongue jack circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        TongueJackOpen = 1175 {"Tongue jack open", DtcSeverity::Fault, DtcCategory::Power},
+        TongueJackShort = 1176 {"Tongue jack short", DtcSeverity::Fault, DtcCategory::Power},
+        TongueJackOverCurrent = 1177 {"Tongue jack over current", DtcSeverity::Fault, DtcCategory::Power},
+        TongueJackUnderCurrent = 1178 {"Tongue jack under current", DtcSeverity::Fault, DtcCategory::Power},
+        TongueJackOutOfStroke = 1179 {"Tongue jack out of stroke", DtcSeverity::Warning, DtcCategory::Motor},
+        TongueJackHallEffectSignalLost = 1180 {"Tongue jack hall effect signal lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        TongueJackPositionLost = 1181 {"Tongue jack position lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        StabilizerJackShortToBatt = 1182 {"Stabilizer jack short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        StabilizerJackShortToGnd = 1183 {"Stabilizer jack short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        StabilizerJackPlusShortToBatt = 1184 {"Stabilizer jack plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        StabilizerJackPlusShortToGnd = 1185 {"Stabilizer jack plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        StabilizerJackMinusShortToBatt = 1186 {"Stabilizer jack minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        StabilizerJackMinusShortToGnd = 1187 {"Stabilizer jack minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        StabilizerJackCircuitFailure = 1188 {"Stabilizer jack circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        StabilizerJackOpen = 1189 {"Stabilizer jack open", DtcSeverity::Fault, DtcCategory::Power},
+        StabilizerJackShort = 1190 {"Stabilizer jack short", DtcSeverity::Fault, DtcCategory::Power},
+        StabilizerJackOverCurrent = 1191 {"Stabilizer jack over current", DtcSeverity::Fault, DtcCategory::Power},
+        StabilizerJackUnderCurrent = 1192 {"Stabilizer jack under current", DtcSeverity::Fault, DtcCategory::Power},
+        StabilizerJackOutOfStroke = 1193 {"Stabilizer jack out of stroke", DtcSeverity::Warning, DtcCategory::Motor},
+        StabilizerJackHallEffectSignalLost = 1194 {"Stabilizer jack hall effect signal lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        StabilizerJackPositionLost = 1195 {"Stabilizer jack position lost", DtcSeverity::Fault, DtcCategory::Sensor},
+        FuseOpen = 1196 {"Fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse1Open = 1197 {"Fuse 1 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse2Open = 1198 {"Fuse 2 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse3Open = 1199 {"Fuse 3 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse4Open = 1200 {"Fuse 4 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse5Open = 1201 {"Fuse 5 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse6Open = 1202 {"Fuse 6 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse7Open = 1203 {"Fuse 7 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse8Open = 1204 {"Fuse 8 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse9Open = 1205 {"Fuse 9 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse10Open = 1206 {"Fuse 10 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse11Open = 1207 {"Fuse 11 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse12Open = 1208 {"Fuse 12 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse13Open = 1209 {"Fuse 13 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse14Open = 1210 {"Fuse 14 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse15Open = 1211 {"Fuse 15 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse16Open = 1212 {"Fuse 16 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse17Open = 1213 {"Fuse 17 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse18Open = 1214 {"Fuse 18 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse19Open = 1215 {"Fuse 19 open", DtcSeverity::Fault, DtcCategory::Power},
+        Fuse20Open = 1216 {"Fuse 20 open", DtcSeverity::Fault, DtcCategory::Power},
+        InputFuseOpen = 1217 {"Input fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input1FuseOpen = 1218 {"Input 1 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input2FuseOpen = 1219 {"Input 2 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input3FuseOpen = 1220 {"Input 3 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input4FuseOpen = 1221 {"Input 4 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input5FuseOpen = 1222 {"Input 5 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input6FuseOpen = 1223 {"Input 6 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input7FuseOpen = 1224 {"Input 7 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input8FuseOpen = 1225 {"Input 8 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input9FuseOpen = 1226 {"Input 9 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input10FuseOpen = 1227 {"Input 10 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input11FuseOpen = 1228 {"Input 11 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input12FuseOpen = 1229 {"Input 12 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input13FuseOpen = 1230 {"Input 13 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input14FuseOpen = 1231 {"Input 14 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input15FuseOpen = 1232 {"Input 15 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input16FuseOpen = 1233 {"Input 16 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input17FuseOpen = 1234 {"Input 17 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input18FuseOpen = 1235 {"Input 18 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input19FuseOpen = 1236 {"Input 19 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Input20FuseOpen = 1237 {"Input 20 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        OutputFuseOpen = 1238 {"Output fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output1FuseOpen = 1239 {"Output 1 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output2FuseOpen = 1240 {"Output 2 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output3FuseOpen = 1241 {"Output 3 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output4FuseOpen = 1242 {"Output 4 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output5FuseOpen = 1243 {"Output 5 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output6FuseOpen = 1244 {"Output 6 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output7FuseOpen = 1245 {"Output 7 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output8FuseOpen = 1246 {"Output 8 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output9FuseOpen = 1247 {"Output 9 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output10FuseOpen = 1248 {"Output 10 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output11FuseOpen = 1249 {"Output 11 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output12FuseOpen = 1250 {"Output 12 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output13FuseOpen = 1251 {"Output 13 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output14FuseOpen = 1252 {"Output 14 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output15FuseOpen = 1253 {"Output 15 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output16FuseOpen = 1254 {"Output 16 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output17FuseOpen = 1255 {"Output 17 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output18FuseOpen = 1256 {"Output 18 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output19FuseOpen = 1257 {"Output 19 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output20FuseOpen = 1258 {"Output 20 fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        GeneratorStartedUnexpectedly = 1259 {"Generator started unexpectedly", DtcSeverity::Warning, DtcCategory::Motor},
+        GeneratorStoppedUnexpectedly = 1260 {"Generator stopped unexpectedly", DtcSeverity::Warning, DtcCategory::Motor},
+        ClimateZone3FreeSensorOpenCircuit = 1261 {"Climate zone 3 free sensor open circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone3FreeSensorShortCircuit = 1262 {"Climate zone 3 free sensor short circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone3TempSensorOpenCircuit = 1263 {"Climate zone 3 temperature sensor open circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone3TempSensorShortCircuit = 1264 {"Climate zone 3 temperature sensor short circuit", DtcSeverity::Fault, DtcCategory::Power},
+        ClimateZone3PowerMissing = 1265 {"Climate zone 3 power missing", DtcSeverity::Warning, DtcCategory::Power},
+        ClimateZone3DipSwitchInvalid = 1266 {"Climate zone 3 DIP switch invalid", DtcSeverity::Fault, DtcCategory::Sensor},
+        ClimateZone1VoltageHigh = 1267 {"Climate zone 1 voltage high", DtcSeverity::Warning, DtcCategory::Power},
+        ClimateZone1VoltageLow = 1268 {"Climate zone 1 voltage low", DtcSeverity::Warning, DtcCategory::Power},
+        ClimateZone2VoltageHigh = 1269 {"Climate zone 2 voltage high", DtcSeverity::Warning, DtcCategory::Power},
+        ClimateZone2VoltageLow = 1270 {"Climate zone 2 voltage low", DtcSeverity::Warning, DtcCategory::Power},
+        ClimateZone3VoltageHigh = 1271 {"Climate zone 3 voltage high", DtcSeverity::Warning, DtcCategory::Power},
+        ClimateZone3VoltageLow = 1272 {"Climate zone 3 voltage low", DtcSeverity::Warning, DtcCategory::Power},
+        ExtendSwitchShortToGnd = 1273 {"Extend switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        ExtendSwitchShortToBatt = 1274 {"Extend switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        ExtendSwitchStuck = 1275 {"Extend switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        ExtendSwitchFault = 1276 {"Extend switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        ExtendSwitchCircuitOpen = 1277 {"Extend switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        RetractSwitchShortToGnd = 1278 {"Retract switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RetractSwitchShortToBatt = 1279 {"Retract switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RetractSwitchStuck = 1280 {"Retract switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        RetractSwitchFault = 1281 {"Retract switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        RetractSwitchCircuitOpen = 1282 {"Retract switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        ParkBrakeSwitchShortToGnd = 1283 {"Park brake switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        ParkBrakeSwitchShortToBatt = 1284 {"Park brake switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        ParkBrakeSwitchStuck = 1285 {"Park brake switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        ParkBrakeSwitchFault = 1286 {"Park brake switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        ParkBrakeSwitchCircuitOpen = 1287 {"Park brake switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor1SwitchShortToGnd = 1288 {"Motor 1 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor1SwitchShortToBatt = 1289 {"Motor 1 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor1SwitchStuck = 1290 {"Motor 1 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor1SwitchFault = 1291 {"Motor 1 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor1SwitchCircuitOpen = 1292 {"Motor 1 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor2SwitchShortToGnd = 1293 {"Motor 2 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor2SwitchShortToBatt = 1294 {"Motor 2 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor2SwitchStuck = 1295 {"Motor 2 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor2SwitchFault = 1296 {"Motor 2 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor2SwitchCircuitOpen = 1297 {"Motor 2 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor3SwitchShortToGnd = 1298 {"Motor 3 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor3SwitchShortToBatt = 1299 {"Motor 3 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor3SwitchStuck = 1300 {"Motor 3 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor3SwitchFault = 1301 {"Motor 3 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor3SwitchCircuitOpen = 1302 {"Motor 3 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor4SwitchShortToGnd = 1303 {"Motor 4 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor4SwitchShortToBatt = 1304 {"Motor 4 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor4SwitchStuck = 1305 {"Motor 4 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor4SwitchFault = 1306 {"Motor 4 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor4SwitchCircuitOpen = 1307 {"Motor 4 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor5SwitchShortToGnd = 1308 {"Motor 5 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor5SwitchShortToBatt = 1309 {"Motor 5 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor5SwitchStuck = 1310 {"Motor 5 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor5SwitchFault = 1311 {"Motor 5 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor5SwitchCircuitOpen = 1312 {"Motor 5 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor6SwitchShortToGnd = 1313 {"Motor 6 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor6SwitchShortToBatt = 1314 {"Motor 6 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor6SwitchStuck = 1315 {"Motor 6 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor6SwitchFault = 1316 {"Motor 6 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor6SwitchCircuitOpen = 1317 {"Motor 6 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor7SwitchShortToGnd = 1318 {"Motor 7 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor7SwitchShortToBatt = 1319 {"Motor 7 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor7SwitchStuck = 1320 {"Motor 7 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor7SwitchFault = 1321 {"Motor 7 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor7SwitchCircuitOpen = 1322 {"Motor 7 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor8SwitchShortToGnd = 1323 {"Motor 8 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor8SwitchShortToBatt = 1324 {"Motor 8 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor8SwitchStuck = 1325 {"Motor 8 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor8SwitchFault = 1326 {"Motor 8 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor8SwitchCircuitOpen = 1327 {"Motor 8 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor9SwitchShortToGnd = 1328 {"Motor 9 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor9SwitchShortToBatt = 1329 {"Motor 9 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor9SwitchStuck = 1330 {"Motor 9 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor9SwitchFault = 1331 {"Motor 9 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor9SwitchCircuitOpen = 1332 {"Motor 9 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor10SwitchShortToGnd = 1333 {"Motor 10 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor10SwitchShortToBatt = 1334 {"Motor 10 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor10SwitchStuck = 1335 {"Motor 10 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor10SwitchFault = 1336 {"Motor 10 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor10SwitchCircuitOpen = 1337 {"Motor 10 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor11SwitchShortToGnd = 1338 {"Motor 11 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor11SwitchShortToBatt = 1339 {"Motor 11 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor11SwitchStuck = 1340 {"Motor 11 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor11SwitchFault = 1341 {"Motor 11 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor11SwitchCircuitOpen = 1342 {"Motor 11 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor12SwitchShortToGnd = 1343 {"Motor 12 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor12SwitchShortToBatt = 1344 {"Motor 12 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor12SwitchStuck = 1345 {"Motor 12 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor12SwitchFault = 1346 {"Motor 12 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor12SwitchCircuitOpen = 1347 {"Motor 12 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor13SwitchShortToGnd = 1348 {"Motor 13 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor13SwitchShortToBatt = 1349 {"Motor 13 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor13SwitchStuck = 1350 {"Motor 13 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor13SwitchFault = 1351 {"Motor 13 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor13SwitchCircuitOpen = 1352 {"Motor 13 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor14SwitchShortToGnd = 1353 {"Motor 14 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor14SwitchShortToBatt = 1354 {"Motor 14 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor14SwitchStuck = 1355 {"Motor 14 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor14SwitchFault = 1356 {"Motor 14 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor14SwitchCircuitOpen = 1357 {"Motor 14 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor15SwitchShortToGnd = 1358 {"Motor 15 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor15SwitchShortToBatt = 1359 {"Motor 15 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor15SwitchStuck = 1360 {"Motor 15 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor15SwitchFault = 1361 {"Motor 15 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor15SwitchCircuitOpen = 1362 {"Motor 15 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        Motor16SwitchShortToGnd = 1363 {"Motor 16 switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Motor16SwitchShortToBatt = 1364 {"Motor 16 switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Motor16SwitchStuck = 1365 {"Motor 16 switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor16SwitchFault = 1366 {"Motor 16 switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        Motor16SwitchCircuitOpen = 1367 {"Motor 16 switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect1SignalShortToGnd = 1368 {"Hall effect 1 signal short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect1SignalShortToBatt = 1369 {"Hall effect 1 signal short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect1SignalFault = 1370 {"Hall effect 1 signal fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        HallEffect1SignalCircuitOpen = 1371 {"Hall effect 1 signal circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect2SignalShortToGnd = 1372 {"Hall effect 2 signal short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect2SignalShortToBatt = 1373 {"Hall effect 2 signal short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect2SignalFault = 1374 {"Hall effect 2 signal fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        HallEffect2SignalCircuitOpen = 1375 {"Hall effect 2 signal circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect3SignalShortToGnd = 1376 {"Hall effect 3 signal short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect3SignalShortToBatt = 1377 {"Hall effect 3 signal short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect3SignalFault = 1378 {"Hall effect 3 signal fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        HallEffect3SignalCircuitOpen = 1379 {"Hall effect 3 signal circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect4SignalShortToGnd = 1380 {"Hall effect 4 signal short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect4SignalShortToBatt = 1381 {"Hall effect 4 signal short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect4SignalFault = 1382 {"Hall effect 4 signal fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        HallEffect4SignalCircuitOpen = 1383 {"Hall effect 4 signal circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect5SignalShortToGnd = 1384 {"Hall effect 5 signal short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect5SignalShortToBatt = 1385 {"Hall effect 5 signal short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect5SignalFault = 1386 {"Hall effect 5 signal fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        HallEffect5SignalCircuitOpen = 1387 {"Hall effect 5 signal circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect6SignalShortToGnd = 1388 {"Hall effect 6 signal short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect6SignalShortToBatt = 1389 {"Hall effect 6 signal short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect6SignalFault = 1390 {"Hall effect 6 signal fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        HallEffect6SignalCircuitOpen = 1391 {"Hall effect 6 signal circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect7SignalShortToGnd = 1392 {"Hall effect 7 signal short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect7SignalShortToBatt = 1393 {"Hall effect 7 signal short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect7SignalFault = 1394 {"Hall effect 7 signal fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        HallEffect7SignalCircuitOpen = 1395 {"Hall effect 7 signal circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect8SignalShortToGnd = 1396 {"Hall effect 8 signal short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect8SignalShortToBatt = 1397 {"Hall effect 8 signal short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        HallEffect8SignalFault = 1398 {"Hall effect 8 signal fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        HallEffect8SignalCircuitOpen = 1399 {"Hall effect 8 signal circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutputShortToBatt = 1400 {"Motor output short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutputShortToGnd = 1401 {"Motor output short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutputPlusShortToBatt = 1402 {"Motor output plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutputPlusShortToGnd = 1403 {"Motor output plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutputMinusShortToBatt = 1404 {"Motor output minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutputMinusShortToGnd = 1405 {"Motor output minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutputCircuitFailure = 1406 {"Motor output circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutputOpen = 1407 {"Motor output open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutputShort = 1408 {"Motor output short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutputOverCurrent = 1409 {"Motor output over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutputUnderCurrent = 1410 {"Motor output under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput1ShortToBatt = 1411 {"Motor output 1 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput1ShortToGnd = 1412 {"Motor output 1 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput1PlusShortToBatt = 1413 {"Motor output 1 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput1PlusShortToGnd = 1414 {"Motor output 1 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput1MinusShortToBatt = 1415 {"Motor output 1 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput1MinusShortToGnd = 1416 {"Motor output 1 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput1CircuitFailure = 1417 {"Motor output 1 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput1Open = 1418 {"Motor output 1 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput1Short = 1419 {"Motor output 1 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput1OverCurrent = 1420 {"Motor output 1 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput1UnderCurrent = 1421 {"Motor output 1 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput2ShortToBatt = 1422 {"Motor output 2 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput2ShortToGnd = 1423 {"Motor output 2 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput2PlusShortToBatt = 1424 {"Motor output 2 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput2PlusShortToGnd = 1425 {"Motor output 2 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput2MinusShortToBatt = 1426 {"Motor output 2 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput2MinusShortToGnd = 1427 {"Motor output 2 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput2CircuitFailure = 1428 {"Motor output 2 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput2Open = 1429 {"Motor output 2 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput2Short = 1430 {"Motor output 2 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput2OverCurrent = 1431 {"Motor output 2 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput2UnderCurrent = 1432 {"Motor output 2 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput3ShortToBatt = 1433 {"Motor output 3 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput3ShortToGnd = 1434 {"Motor output 3 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput3PlusShortToBatt = 1435 {"Motor output 3 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput3PlusShortToGnd = 1436 {"Motor output 3 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput3MinusShortToBatt = 1437 {"Motor output 3 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput3MinusShortToGnd = 1438 {"Motor output 3 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput3CircuitFailure = 1439 {"Motor output 3 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput3Open = 1440 {"Motor output 3 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput3Short = 1441 {"Motor output 3 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput3OverCurrent = 1442 {"Motor output 3 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput3UnderCurrent = 1443 {"Motor output 3 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput4ShortToBatt = 1444 {"Motor output 4 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput4ShortToGnd = 1445 {"Motor output 4 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput4PlusShortToBatt = 1446 {"Motor output 4 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput4PlusShortToGnd = 1447 {"Motor output 4 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput4MinusShortToBatt = 1448 {"Motor output 4 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput4MinusShortToGnd = 1449 {"Motor output 4 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput4CircuitFailure = 1450 {"Motor output 4 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput4Open = 1451 {"Motor output 4 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput4Short = 1452 {"Motor output 4 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput4OverCurrent = 1453 {"Motor output 4 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput4UnderCurrent = 1454 {"Motor output 4 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput5ShortToBatt = 1455 {"Motor output 5 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput5ShortToGnd = 1456 {"Motor output 5 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput5PlusShortToBatt = 1457 {"Motor output 5 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput5PlusShortToGnd = 1458 {"Motor output 5 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput5MinusShortToBatt = 1459 {"Motor output 5 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput5MinusShortToGnd = 1460 {"Motor output 5 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput5CircuitFailure = 1461 {"Motor output 5 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput5Open = 1462 {"Motor output 5 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput5Short = 1463 {"Motor output 5 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput5OverCurrent = 1464 {"Motor output 5 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput5UnderCurrent = 1465 {"Motor output 5 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput6ShortToBatt = 1466 {"Motor output 6 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput6ShortToGnd = 1467 {"Motor output 6 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput6PlusShortToBatt = 1468 {"Motor output 6 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput6PlusShortToGnd = 1469 {"Motor output 6 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput6MinusShortToBatt = 1470 {"Motor output 6 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput6MinusShortToGnd = 1471 {"Motor output 6 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput6CircuitFailure = 1472 {"Motor output 6 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput6Open = 1473 {"Motor output 6 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput6Short = 1474 {"Motor output 6 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput6OverCurrent = 1475 {"Motor output 6 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput6UnderCurrent = 1476 {"Motor output 6 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput7ShortToBatt = 1477 {"Motor output 7 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput7ShortToGnd = 1478 {"Motor output 7 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput7PlusShortToBatt = 1479 {"Motor output 7 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput7PlusShortToGnd = 1480 {"Motor output 7 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput7MinusShortToBatt = 1481 {"Motor output 7 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput7MinusShortToGnd = 1482 {"Motor output 7 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput7CircuitFailure = 1483 {"Motor output 7 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput7Open = 1484 {"Motor output 7 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput7Short = 1485 {"Motor output 7 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput7OverCurrent = 1486 {"Motor output 7 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput7UnderCurrent = 1487 {"Motor output 7 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput8ShortToBatt = 1488 {"Motor output 8 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput8ShortToGnd = 1489 {"Motor output 8 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput8PlusShortToBatt = 1490 {"Motor output 8 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput8PlusShortToGnd = 1491 {"Motor output 8 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput8MinusShortToBatt = 1492 {"Motor output 8 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput8MinusShortToGnd = 1493 {"Motor output 8 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput8CircuitFailure = 1494 {"Motor output 8 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput8Open = 1495 {"Motor output 8 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput8Short = 1496 {"Motor output 8 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput8OverCurrent = 1497 {"Motor output 8 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput8UnderCurrent = 1498 {"Motor output 8 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput9ShortToBatt = 1499 {"Motor output 9 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput9ShortToGnd = 1500 {"Motor output 9 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput9PlusShortToBatt = 1501 {"Motor output 9 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput9PlusShortToGnd = 1502 {"Motor output 9 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput9MinusShortToBatt = 1503 {"Motor output 9 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput9MinusShortToGnd = 1504 {"Motor output 9 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput9CircuitFailure = 1505 {"Motor output 9 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput9Open = 1506 {"Motor output 9 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput9Short = 1507 {"Motor output 9 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput9OverCurrent = 1508 {"Motor output 9 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput9UnderCurrent = 1509 {"Motor output 9 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput10ShortToBatt = 1510 {"Motor output 10 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput10ShortToGnd = 1511 {"Motor output 10 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput10PlusShortToBatt = 1512 {"Motor output 10 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput10PlusShortToGnd = 1513 {"Motor output 10 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput10MinusShortToBatt = 1514 {"Motor output 10 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput10MinusShortToGnd = 1515 {"Motor output 10 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput10CircuitFailure = 1516 {"Motor output 10 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput10Open = 1517 {"Motor output 10 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput10Short = 1518 {"Motor output 10 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput10OverCurrent = 1519 {"Motor output 10 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput10UnderCurrent = 1520 {"Motor output 10 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput11ShortToBatt = 1521 {"Motor output 11 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput11ShortToGnd = 1522 {"Motor output 11 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput11PlusShortToBatt = 1523 {"Motor output 11 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput11PlusShortToGnd = 1524 {"Motor output 11 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput11MinusShortToBatt = 1525 {"Motor output 11 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput11MinusShortToGnd = 1526 {"Motor output 11 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput11CircuitFailure = 1527 {"Motor output 11 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput11Open = 1528 {"Motor output 11 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput11Short = 1529 {"Motor output 11 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput11OverCurrent = 1530 {"Motor output 11 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput11UnderCurrent = 1531 {"Motor output 11 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput12ShortToBatt = 1532 {"Motor output 12 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput12ShortToGnd = 1533 {"Motor output 12 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput12PlusShortToBatt = 1534 {"Motor output 12 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput12PlusShortToGnd = 1535 {"Motor output 12 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput12MinusShortToBatt = 1536 {"Motor output 12 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput12MinusShortToGnd = 1537 {"Motor output 12 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput12CircuitFailure = 1538 {"Motor output 12 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput12Open = 1539 {"Motor output 12 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput12Short = 1540 {"Motor output 12 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput12OverCurrent = 1541 {"Motor output 12 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput12UnderCurrent = 1542 {"Motor output 12 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput13ShortToBatt = 1543 {"Motor output 13 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput13ShortToGnd = 1544 {"Motor output 13 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput13PlusShortToBatt = 1545 {"Motor output 13 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput13PlusShortToGnd = 1546 {"Motor output 13 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput13MinusShortToBatt = 1547 {"Motor output 13 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput13MinusShortToGnd = 1548 {"Motor output 13 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput13CircuitFailure = 1549 {"Motor output 13 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput13Open = 1550 {"Motor output 13 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput13Short = 1551 {"Motor output 13 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput13OverCurrent = 1552 {"Motor output 13 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput13UnderCurrent = 1553 {"Motor output 13 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput14ShortToBatt = 1554 {"Motor output 14 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput14ShortToGnd = 1555 {"Motor output 14 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput14PlusShortToBatt = 1556 {"Motor output 14 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput14PlusShortToGnd = 1557 {"Motor output 14 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput14MinusShortToBatt = 1558 {"Motor output 14 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput14MinusShortToGnd = 1559 {"Motor output 14 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput14CircuitFailure = 1560 {"Motor output 14 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput14Open = 1561 {"Motor output 14 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput14Short = 1562 {"Motor output 14 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput14OverCurrent = 1563 {"Motor output 14 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput14UnderCurrent = 1564 {"Motor output 14 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput15ShortToBatt = 1565 {"Motor output 15 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput15ShortToGnd = 1566 {"Motor output 15 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput15PlusShortToBatt = 1567 {"Motor output 15 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput15PlusShortToGnd = 1568 {"Motor output 15 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput15MinusShortToBatt = 1569 {"Motor output 15 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput15MinusShortToGnd = 1570 {"Motor output 15 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput15CircuitFailure = 1571 {"Motor output 15 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput15Open = 1572 {"Motor output 15 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput15Short = 1573 {"Motor output 15 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput15OverCurrent = 1574 {"Motor output 15 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput15UnderCurrent = 1575 {"Motor output 15 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput16ShortToBatt = 1576 {"Motor output 16 short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput16ShortToGnd = 1577 {"Motor output 16 short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput16PlusShortToBatt = 1578 {"Motor output 16 plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput16PlusShortToGnd = 1579 {"Motor output 16 plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput16MinusShortToBatt = 1580 {"Motor output 16 minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput16MinusShortToGnd = 1581 {"Motor output 16 minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput16CircuitFailure = 1582 {"Motor output 16 circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput16Open = 1583 {"Motor output 16 open", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput16Short = 1584 {"Motor output 16 short", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput16OverCurrent = 1585 {"Motor output 16 over current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorOutput16UnderCurrent = 1586 {"Motor output 16 under current", DtcSeverity::Fault, DtcCategory::Power},
+        MotorSoftstopsNotConfigured = 1587 {"Motor soft stops not configured", DtcSeverity::Warning, DtcCategory::Motor},
+        BatteryDropout = 1588 {"Battery dropout", DtcSeverity::Warning, DtcCategory::Power},
+        WaterHeaterIgnitionFailure = 1589 {"Water heater ignition failure", DtcSeverity::Fault, DtcCategory::Power},
+        AutoLevelTimeout = 1590 {"Auto level timeout", DtcSeverity::Warning, DtcCategory::Motor},
+        AutoLevelFail = 1591 {"Auto level fail", DtcSeverity::Fault, DtcCategory::Motor},
+        AutoRetractTimeout = 1592 {"Auto retract timeout", DtcSeverity::Warning, DtcCategory::Motor},
+        AutoHitchTimeout = 1593 {"Auto hitch timeout", DtcSeverity::Warning, DtcCategory::Motor},
+        GroundJacksTimeout = 1594 {"Ground jacks timeout", DtcSeverity::Warning, DtcCategory::Motor},
+        ExcessAngle = 1595 {"Excess angle", DtcSeverity::Warning, DtcCategory::Motor},
+        UserPanicStop = 1596 {"User panic stop", DtcSeverity::Info, DtcCategory::Other},
+        ParkBrakeNotEngaged = 1597 {"Park brake not engaged", DtcSeverity::Info, DtcCategory::Motor},
+        RequestedFeatureDisabled = 1598 {"Requested feature disabled", DtcSeverity::Info, DtcCategory::Other},
+        PsiSwitchTimeout = 1599 {"PSI switch timeout", DtcSeverity::Warning, DtcCategory::Sensor},
+        PsiSwitchShortToGnd = 1600 {"PSI switch short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        PsiSwitchShortToBatt = 1601 {"PSI switch short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        PsiSwitchSwitchStuck = 1602 {"PSI switch stuck", DtcSeverity::Fault, DtcCategory::Sensor},
+        PsiSwitchSwitchFault = 1603 {"PSI switch fault", DtcSeverity::Fault, DtcCategory::Sensor},
+        PsiSwitchCircuitOpen = 1604 {"PSI switch circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadPowerShortToBatt = 1605 {"Touch pad power short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadPowerShortToGnd = 1606 {"Touch pad power short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadPowerPlusShortToBatt = 1607 {"Touch pad power plus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadPowerPlusShortToGnd = 1608 {"Touch pad power plus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadPowerMinusShortToBatt = 1609 {"Touch pad power minus short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadPowerMinusShortToGnd = 1610 {"Touch pad power minus short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadPowerCircuitFailure = 1611 {"Touch pad power circuit failure", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadPowerOpen = 1612 {"Touch pad power open", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadPowerShort = 1613 {"Touch pad power short", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadPowerOverCurrent = 1614 {"Touch pad power over current", DtcSeverity::Fault, DtcCategory::Power},
+        TouchPadPowerUnderCurrent = 1615 {"Touch pad power under current", DtcSeverity::Fault, DtcCategory::Power},
+        IgnitionSwitchNotEngaged = 1616 {"Ignition switch not engaged", DtcSeverity::Info, DtcCategory::Power},
+        AutoStartVoltageLow = 1617 {"Auto start voltage low", DtcSeverity::Warning, DtcCategory::Power},
+        ReturnFuseOpen = 1618 {"Return fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output1SoftwareFuseOpen = 1619 {"Output 1 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output2SoftwareFuseOpen = 1620 {"Output 2 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output3SoftwareFuseOpen = 1621 {"Output 3 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output4SoftwareFuseOpen = 1622 {"Output 4 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output5SoftwareFuseOpen = 1623 {"Output 5 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output6SoftwareFuseOpen = 1624 {"Output 6 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output7SoftwareFuseOpen = 1625 {"Output 7 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output8SoftwareFuseOpen = 1626 {"Output 8 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output9SoftwareFuseOpen = 1627 {"Output 9 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output10SoftwareFuseOpen = 1628 {"Output 10 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output11SoftwareFuseOpen = 1629 {"Output 11 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output12SoftwareFuseOpen = 1630 {"Output 12 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output13SoftwareFuseOpen = 1631 {"Output 13 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output14SoftwareFuseOpen = 1632 {"Output 14 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output15SoftwareFuseOpen = 1633 {"Output 15 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output16SoftwareFuseOpen = 1634 {"Output 16 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output17SoftwareFuseOpen = 1635 {"Output 17 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output18SoftwareFuseOpen = 1636 {"Output 18 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output19SoftwareFuseOpen = 1637 {"Output 19 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output20SoftwareFuseOpen = 1638 {"Output 20 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output21SoftwareFuseOpen = 1639 {"Output 21 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output22SoftwareFuseOpen = 1640 {"Output 22 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output23SoftwareFuseOpen = 1641 {"Output 23 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output24SoftwareFuseOpen = 1642 {"Output 24 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output25SoftwareFuseOpen = 1643 {"Output 25 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output26SoftwareFuseOpen = 1644 {"Output 26 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output27SoftwareFuseOpen = 1645 {"Output 27 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output28SoftwareFuseOpen = 1646 {"Output 28 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output29SoftwareFuseOpen = 1647 {"Output 29 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output30SoftwareFuseOpen = 1648 {"Output 30 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output31SoftwareFuseOpen = 1649 {"Output 31 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        Output32SoftwareFuseOpen = 1650 {"Output 32 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        WirelessSwitchBatteryLow = 1651 {"Wireless switch battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch1BatteryLow = 1652 {"Wireless switch 1 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch2BatteryLow = 1653 {"Wireless switch 2 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch3BatteryLow = 1654 {"Wireless switch 3 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch4BatteryLow = 1655 {"Wireless switch 4 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch5BatteryLow = 1656 {"Wireless switch 5 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch6BatteryLow = 1657 {"Wireless switch 6 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch7BatteryLow = 1658 {"Wireless switch 7 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch8BatteryLow = 1659 {"Wireless switch 8 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch9BatteryLow = 1660 {"Wireless switch 9 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch10BatteryLow = 1661 {"Wireless switch 10 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch11BatteryLow = 1662 {"Wireless switch 11 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch12BatteryLow = 1663 {"Wireless switch 12 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch13BatteryLow = 1664 {"Wireless switch 13 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch14BatteryLow = 1665 {"Wireless switch 14 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch15BatteryLow = 1666 {"Wireless switch 15 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WirelessSwitch16BatteryLow = 1667 {"Wireless switch 16 battery low", DtcSeverity::Warning, DtcCategory::Power},
+        WaterIntrusionDetected = 1668 {"Water intrusion detected", DtcSeverity::Fault, DtcCategory::Sensor},
+        WaterIntrusionDetectedInConnector = 1669 {"Water intrusion detected in connector", DtcSeverity::Fault, DtcCategory::Sensor},
+        WaterIntrusionDetectedInTouchpad = 1670 {"Water intrusion detected in touch pad", DtcSeverity::Fault, DtcCategory::Sensor},
+        OperatingVoltageDropout = 1671 {"Operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        BatteryVoltageHighFastDetect = 1672 {"Battery voltage high fast detect", DtcSeverity::Warning, DtcCategory::Power},
+        IgnitionNotActive = 1673 {"Ignition not active", DtcSeverity::Info, DtcCategory::Power},
+        BatteryTooLowToOperate = 1674 {"Battery too low to operate", DtcSeverity::Warning, DtcCategory::Power},
+        LightOutputSoftwareFuseOpen = 1675 {"Light output software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput1SoftwareFuseOpen = 1676 {"Light output 1 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput2SoftwareFuseOpen = 1677 {"Light output 2 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput3SoftwareFuseOpen = 1678 {"Light output 3 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput4SoftwareFuseOpen = 1679 {"Light output 4 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput5SoftwareFuseOpen = 1680 {"Light output 5 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput6SoftwareFuseOpen = 1681 {"Light output 6 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput7SoftwareFuseOpen = 1682 {"Light output 7 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput8SoftwareFuseOpen = 1683 {"Light output 8 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput9SoftwareFuseOpen = 1684 {"Light output 9 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput10SoftwareFuseOpen = 1685 {"Light output 10 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput11SoftwareFuseOpen = 1686 {"Light output 11 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput12SoftwareFuseOpen = 1687 {"Light output 12 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput13SoftwareFuseOpen = 1688 {"Light output 13 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput14SoftwareFuseOpen = 1689 {"Light output 14 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput15SoftwareFuseOpen = 1690 {"Light output 15 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        LightOutput16SoftwareFuseOpen = 1691 {"Light output 16 software fuse open", DtcSeverity::Fault, DtcCategory::Power},
+        PowerInputMissing = 1692 {"Power input missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput1Missing = 1693 {"Power input 1 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput2Missing = 1694 {"Power input 2 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput3Missing = 1695 {"Power input 3 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput4Missing = 1696 {"Power input 4 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput5Missing = 1697 {"Power input 5 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput6Missing = 1698 {"Power input 6 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput7Missing = 1699 {"Power input 7 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput8Missing = 1700 {"Power input 8 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput9Missing = 1701 {"Power input 9 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput10Missing = 1702 {"Power input 10 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput11Missing = 1703 {"Power input 11 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput12Missing = 1704 {"Power input 12 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput13Missing = 1705 {"Power input 13 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput14Missing = 1706 {"Power input 14 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput15Missing = 1707 {"Power input 15 missing", DtcSeverity::Warning, DtcCategory::Power},
+        PowerInput16Missing = 1708 {"Power input 16 missing", DtcSeverity::Warning, DtcCategory::Power},
+        IgnitionActive = 1709 {"Ignition active", DtcSeverity::Info, DtcCategory::Power},
+        ParkbrakeNotActive = 1710 {"Park brake not active", DtcSeverity::Info, DtcCategory::Motor},
+        ParkbrakeActive = 1711 {"Park brake active", DtcSeverity::Info, DtcCategory::Motor},
+        MotorRetractSoftstopNotConfigured = 1712 {"Motor retract soft stop not configured", DtcSeverity::Warning, DtcCategory::Motor},
+        MotorExtendSoftstopNotConfigured = 1713 {"Motor extend soft stop not configured", DtcSeverity::Warning, DtcCategory::Motor},
+        SwitchActiveOnlyViaHardware = 1714 {"Switch active only via hardware", DtcSeverity::Info, DtcCategory::Sensor},
+        LevelerOperatingVoltageDropout = 1715 {"Leveler operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output1OperatingVoltageDropout = 1716 {"Output 1 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output2OperatingVoltageDropout = 1717 {"Output 2 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output3OperatingVoltageDropout = 1718 {"Output 3 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output4OperatingVoltageDropout = 1719 {"Output 4 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output5OperatingVoltageDropout = 1720 {"Output 5 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output6OperatingVoltageDropout = 1721 {"Output 6 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output7OperatingVoltageDropout = 1722 {"Output 7 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output8OperatingVoltageDropout = 1723 {"Output 8 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output9OperatingVoltageDropout = 1724 {"Output 9 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output10OperatingVoltageDropout = 1725 {"Output 10 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output11OperatingVoltageDropout = 1726 {"Output 11 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output12OperatingVoltageDropout = 1727 {"Output 12 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output13OperatingVoltageDropout = 1728 {"Output 13 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output14OperatingVoltageDropout = 1729 {"Output 14 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output15OperatingVoltageDropout = 1730 {"Output 15 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        Output16OperatingVoltageDropout = 1731 {"Output 16 operating voltage dropout", DtcSeverity::Warning, DtcCategory::Power},
+        BlePairingCorruption = 1732 {"BLE pairing corruption", DtcSeverity::Fault, DtcCategory::Comms},
+        SureshadeGeneralFault = 1733 {"SureShade general fault", DtcSeverity::Fault, DtcCategory::Motor},
+        TbbCommError = 1734 {"TBB communication error", DtcSeverity::Fault, DtcCategory::Comms},
+        UserAutoOperationInProgress = 1735 {"User auto operation in progress", DtcSeverity::Info, DtcCategory::Other},
+        WindEventAutoOperationInProgress = 1736 {"Wind event auto operation in progress", DtcSeverity::Info, DtcCategory::Sensor},
+        AutoOperationCanceled = 1737 {"Auto operation canceled", DtcSeverity::Info, DtcCategory::Other},
+        WindSensor1CommFailure = 1738 {"Wind sensor 1 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor2CommFailure = 1739 {"Wind sensor 2 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor3CommFailure = 1740 {"Wind sensor 3 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor4CommFailure = 1741 {"Wind sensor 4 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor5CommFailure = 1742 {"Wind sensor 5 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor6CommFailure = 1743 {"Wind sensor 6 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor7CommFailure = 1744 {"Wind sensor 7 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor8CommFailure = 1745 {"Wind sensor 8 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor9CommFailure = 1746 {"Wind sensor 9 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor10CommFailure = 1747 {"Wind sensor 10 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor11CommFailure = 1748 {"Wind sensor 11 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor12CommFailure = 1749 {"Wind sensor 12 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor13CommFailure = 1750 {"Wind sensor 13 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor14CommFailure = 1751 {"Wind sensor 14 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor15CommFailure = 1752 {"Wind sensor 15 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        WindSensor16CommFailure = 1753 {"Wind sensor 16 communication failure", DtcSeverity::Fault, DtcCategory::Comms},
+        LowBatteryOperatingMode = 1754 {"Low battery operating mode", DtcSeverity::Info, DtcCategory::Power},
+        Axle1LeftActuatorOpen = 1755 {"Axle 1 left actuator open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle1LeftActuatorShortToBatt = 1756 {"Axle 1 left actuator short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle1LeftActuatorShortToGround = 1757 {"Axle 1 left actuator short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Axle1LeftActuatorRelayFailure = 1758 {"Axle 1 left actuator relay failure", DtcSeverity::Fault, DtcCategory::Motor},
+        Axle1LeftWssOpen = 1759 {"Axle 1 left wheel speed sensor open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle1LeftWssShortToBatt = 1760 {"Axle 1 left wheel speed sensor short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle1LeftWssShortToGround = 1761 {"Axle 1 left wheel speed sensor short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Axle1RightActuatorOpen = 1762 {"Axle 1 right actuator open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle1RightActuatorShortToBatt = 1763 {"Axle 1 right actuator short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle1RightActuatorShortToGround = 1764 {"Axle 1 right actuator short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Axle1RightActuatorRelayFailure = 1765 {"Axle 1 right actuator relay failure", DtcSeverity::Fault, DtcCategory::Motor},
+        Axle1RightWssOpen = 1766 {"Axle 1 right wheel speed sensor open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle1RightWssShortToBatt = 1767 {"Axle 1 right wheel speed sensor short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle1RightWssShortToGround = 1768 {"Axle 1 right wheel speed sensor short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2LeftActuatorOpen = 1769 {"Axle 2 left actuator open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2LeftActuatorShortToBatt = 1770 {"Axle 2 left actuator short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2LeftActuatorShortToGround = 1771 {"Axle 2 left actuator short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2LeftActuatorRelayFailure = 1772 {"Axle 2 left actuator relay failure", DtcSeverity::Fault, DtcCategory::Motor},
+        Axle2LeftWssOpen = 1773 {"Axle 2 left wheel speed sensor open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2LeftWssShortToBatt = 1774 {"Axle 2 left wheel speed sensor short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2LeftWssShortToGround = 1775 {"Axle 2 left wheel speed sensor short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2RightActuatorOpen = 1776 {"Axle 2 right actuator open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2RightActuatorShortToBatt = 1777 {"Axle 2 right actuator short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2RightActuatorShortToGround = 1778 {"Axle 2 right actuator short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2RightActuatorRelayFailure = 1779 {"Axle 2 right actuator relay failure", DtcSeverity::Fault, DtcCategory::Motor},
+        Axle2RightWssOpen = 1780 {"Axle 2 right wheel speed sensor open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2RightWssShortToBatt = 1781 {"Axle 2 right wheel speed sensor short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle2RightWssShortToGround = 1782 {"Axle 2 right wheel speed sensor short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3LeftActuatorOpen = 1783 {"Axle 3 left actuator open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3LeftActuatorShortToBatt = 1784 {"Axle 3 left actuator short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3LeftActuatorShortToGround = 1785 {"Axle 3 left actuator short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3LeftActuatorRelayFailure = 1786 {"Axle 3 left actuator relay failure", DtcSeverity::Fault, DtcCategory::Motor},
+        Axle3LeftWssOpen = 1787 {"Axle 3 left wheel speed sensor open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3LeftWssShortToBatt = 1788 {"Axle 3 left wheel speed sensor short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3LeftWssShortToGround = 1789 {"Axle 3 left wheel speed sensor short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3RightActuatorOpen = 1790 {"Axle 3 right actuator open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3RightActuatorShortToBatt = 1791 {"Axle 3 right actuator short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3RightActuatorShortToGround = 1792 {"Axle 3 right actuator short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3RightActuatorRelayFailure = 1793 {"Axle 3 right actuator relay failure", DtcSeverity::Fault, DtcCategory::Motor},
+        Axle3RightWssOpen = 1794 {"Axle 3 right wheel speed sensor open", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3RightWssShortToBatt = 1795 {"Axle 3 right wheel speed sensor short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        Axle3RightWssShortToGround = 1796 {"Axle 3 right wheel speed sensor short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        BrakeLoadCircuitOpen = 1797 {"Brake load circuit open", DtcSeverity::Fault, DtcCategory::Power},
+        BrakeLoadCircuitShortToBatt = 1798 {"Brake load circuit short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        BrakeLoadCircuitShortToGround = 1799 {"Brake load circuit short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        BrakeOutputOpen = 1800 {"Brake output open", DtcSeverity::Fault, DtcCategory::Power},
+        BrakeOutputShortToBatt = 1801 {"Brake output short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        BrakeOutputShortToGround = 1802 {"Brake output short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        TailRunlightsOutputOpen = 1803 {"Tail run lights output open", DtcSeverity::Fault, DtcCategory::Power},
+        TailRunlightsOutputShortToBatt = 1804 {"Tail run lights output short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        TailRunlightsOutputShortToGround = 1805 {"Tail run lights output short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        LeftTurnBrakeOutputOpen = 1806 {"Left turn brake output open", DtcSeverity::Fault, DtcCategory::Power},
+        LeftTurnBrakeOutputShortToBatt = 1807 {"Left turn brake output short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        LeftTurnBrakeOutputShortToGround = 1808 {"Left turn brake output short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RightTurnBrakeOutputOpen = 1809 {"Right turn brake output open", DtcSeverity::Fault, DtcCategory::Power},
+        RightTurnBrakeOutputShortToBatt = 1810 {"Right turn brake output short to battery", DtcSeverity::Fault, DtcCategory::Power},
+        RightTurnBrakeOutputShortToGround = 1811 {"Right turn brake output short to ground", DtcSeverity::Fault, DtcCategory::Power},
+        RvCExternalNodeSerialNumberError = 1812 {"RV-C external node serial number error", DtcSeverity::Fault, DtcCategory::Other},
+        ProductWatchdogTriggered = 1813 {"Product watchdog triggered", DtcSeverity::Fault, DtcCategory::Other},
+        RvCExternalNodeCalibrationRequired = 1814 {"RV-C external node calibration required", DtcSeverity::Warning, DtcCategory::Other},
+        RvCCommunicationError = 1815 {"RV-C communication error", DtcSeverity::Fault, DtcCategory::Comms},
+        RvCExternalNodeCommunicationError = 1816 {"RV-C external node communication error", DtcSeverity::Fault, DtcCategory::Comms},
+        RvCExternalNodeTempSensorFailure = 1817 {"RV-C external node temperature sensor failure", DtcSeverity::Fault, DtcCategory::Sensor},
+        RvCExternalNodeLowVoltage = 1818 {"RV-C external node low voltage", DtcSeverity::Warning, DtcCategory::Power},
+        RvCExternalNodeHighVoltage = 1819 {"RV-C external node high voltage", DtcSeverity::Warning, DtcCategory::Power},
+        RvCExternalNodeYellowLamp = 1820 {"RV-C external node yellow lamp", DtcSeverity::Warning, DtcCategory::Other},
+        RvCExternalNodeRedLamp = 1821 {"RV-C external node red lamp", DtcSeverity::Fault, DtcCategory::Other},
+        BrakeControllerLossOfCommBleMicro = 1822 {"Brake controller loss of communication BLE micro", DtcSeverity::Warning, DtcCategory::Comms},
+        BrakeControllerModuleFailure = 1823 {"Brake controller module failure", DtcSeverity::Fault, DtcCategory::Motor},
+        WssA1lMechanicalFailure = 1824 {"Wheel speed sensor A1L mechanical failure", DtcSeverity::Fault, DtcCategory::Sensor},
+        WssA1rMechanicalFailure = 1825 {"Wheel speed sensor A1R mechanical failure", DtcSeverity::Fault, DtcCategory::Sensor},
+        WssA2lMechanicalFailure = 1826 {"Wheel speed sensor A2L mechanical failure", DtcSeverity::Fault, DtcCategory::Sensor},
+        WssA2rMechanicalFailure = 1827 {"Wheel speed sensor A2R mechanical failure", DtcSeverity::Fault, DtcCategory::Sensor},
+        WssA3lMechanicalFailure = 1828 {"Wheel speed sensor A3L mechanical failure", DtcSeverity::Fault, DtcCategory::Sensor},
+        WssA3rMechanicalFailure = 1829 {"Wheel speed sensor A3R mechanical failure", DtcSeverity::Fault, DtcCategory::Sensor},
+        BrakeControllerModuleOverTemperature = 1830 {"Brake controller module over temperature", DtcSeverity::Fault, DtcCategory::Sensor},
+        UserAutoOperationComplete = 1831 {"User auto operation complete", DtcSeverity::Info, DtcCategory::Other},
+        WindEventAutoOperationComplete = 1832 {"Wind event auto operation complete", DtcSeverity::Info, DtcCategory::Sensor},
+        WindSensor1Error = 1833 {"Wind sensor 1 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor2Error = 1834 {"Wind sensor 2 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor3Error = 1835 {"Wind sensor 3 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor4Error = 1836 {"Wind sensor 4 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor5Error = 1837 {"Wind sensor 5 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor6Error = 1838 {"Wind sensor 6 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor7Error = 1839 {"Wind sensor 7 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor8Error = 1840 {"Wind sensor 8 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor9Error = 1841 {"Wind sensor 9 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor10Error = 1842 {"Wind sensor 10 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor11Error = 1843 {"Wind sensor 11 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor12Error = 1844 {"Wind sensor 12 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor13Error = 1845 {"Wind sensor 13 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor14Error = 1846 {"Wind sensor 14 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor15Error = 1847 {"Wind sensor 15 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        WindSensor16Error = 1848 {"Wind sensor 16 error", DtcSeverity::Fault, DtcCategory::Sensor},
+        UserAutoOperationError = 1849 {"User auto operation error", DtcSeverity::Fault, DtcCategory::Other},
+        WindEventAutoOperationError = 1850 {"Wind event auto operation error", DtcSeverity::Fault, DtcCategory::Sensor},
+    }
+}
+
+impl std::fmt::Display for DTCID {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
 }
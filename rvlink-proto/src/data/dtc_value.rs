@@ -0,0 +1,36 @@
+use super::*;
+
+define_encodable_struct! {
+    DtcValue [4] {
+        dtc: u16 [0],
+        status: u8 [2],
+        count: u8 [3],
+    }
+}
+
+#[allow(dead_code)]
+impl DtcValue {
+    /// The catalog entry for this code, devices may report codes we have no definition for
+    pub fn dtc_id(&self) -> Option<DTCID> {
+        Some(DTCID::from(self.dtc)).filter(|dtc| *dtc != DTCID::Unknown)
+    }
+
+    pub fn is_active(&self) -> bool {
+        (self.status & 0x01) == 0x01
+    }
+
+    pub fn is_stored(&self) -> bool {
+        (self.status & 0x02) == 0x02
+    }
+}
+
+impl std::fmt::Display for DtcValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.dtc_id() {
+            Some(dtc) => write!(f, "{}", dtc)?,
+            None => write!(f, "DTC {:#06x}", self.dtc)?,
+        }
+        let state = if self.is_active() { "active" } else { "stored" };
+        write!(f, " ({}, {}x)", state, self.count)
+    }
+}
@@ -1,7 +1,5 @@
 use rvlink_common::devices::DeviceEntityType;
 
-use super::*;
-
 enum_with_metadata! {
     FunctionName:u16; name:&'static str:0 {
         Unknown = 0 {"Unknown"},
@@ -379,6 +377,26 @@ impl std::fmt::Display for FunctionName {
 }
 
 impl FunctionName {
+    /// Entity type embedded in unique ids. Names that later got their own entity type keep the
+    /// one they were first published with, otherwise HA would see them as new entities
+    pub fn uniq_id_entity_type(&self) -> DeviceEntityType {
+        match self {
+            FunctionName::ClimateZone
+            | FunctionName::Thermostat
+            | FunctionName::MainClimateZone
+            | FunctionName::BedroomClimateZone
+            | FunctionName::GarageClimateZone
+            | FunctionName::LivingRoomClimateZone
+            | FunctionName::FrontLivingRoomClimateZone
+            | FunctionName::RearLivingRoomClimateZone
+            | FunctionName::FrontBedroomClimateZone
+            | FunctionName::RearBedroomClimateZone
+            | FunctionName::Generator
+            | FunctionName::DoorLock => DeviceEntityType::None,
+            _ => self.device_entity_type(),
+        }
+    }
+
     pub fn device_entity_type(&self) -> DeviceEntityType {
         match self {
             FunctionName::Slide
@@ -613,7 +631,9 @@ impl FunctionName {
             | FunctionName::WaterHeater
             | FunctionName::WaterHeaters => DeviceEntityType::WaterHeater,
             FunctionName::WaterPump => DeviceEntityType::WaterPump,
-            FunctionName::BathroomDoorLock
+            FunctionName::Generator => DeviceEntityType::Generator,
+            FunctionName::DoorLock
+            | FunctionName::BathroomDoorLock
             | FunctionName::BedroomDoorLock
             | FunctionName::FrontDoorLock
             | FunctionName::GarageDoorLock
@@ -625,6 +645,16 @@ impl FunctionName {
             | FunctionName::GarageAwning
             | FunctionName::SideAwning
             | FunctionName::Awning => DeviceEntityType::Awning,
+            FunctionName::ClimateZone
+            | FunctionName::Thermostat
+            | FunctionName::MainClimateZone
+            | FunctionName::BedroomClimateZone
+            | FunctionName::GarageClimateZone
+            | FunctionName::LivingRoomClimateZone
+            | FunctionName::FrontLivingRoomClimateZone
+            | FunctionName::RearLivingRoomClimateZone
+            | FunctionName::FrontBedroomClimateZone
+            | FunctionName::RearBedroomClimateZone => DeviceEntityType::Thermostat,
             FunctionName::Battery
             | FunctionName::MainBattery
             | FunctionName::AuxBattery
@@ -642,8 +672,6 @@ impl FunctionName {
             | FunctionName::TvLift
             | FunctionName::BedLift
             | FunctionName::BathVentCover
-            | FunctionName::DoorLock
-            | FunctionName::Generator
             | FunctionName::LevelUpLeveler
             | FunctionName::MyrvTouchscreen
             | FunctionName::Leveler
@@ -660,9 +688,7 @@ impl FunctionName {
             | FunctionName::Jacks
             | FunctionName::Leveler2
             | FunctionName::Clock
-            | FunctionName::ClimateZone
             | FunctionName::Fireplace
-            | FunctionName::Thermostat
             | FunctionName::LpTank
             | FunctionName::NetworkBridge
             | FunctionName::EthernetBridge
@@ -673,9 +699,6 @@ impl FunctionName {
             | FunctionName::TravelTrailerLeveler
             | FunctionName::FifthWheelLeveler
             | FunctionName::FuelPump
-            | FunctionName::MainClimateZone
-            | FunctionName::BedroomClimateZone
-            | FunctionName::GarageClimateZone
             | FunctionName::LeftStabilizer
             | FunctionName::RightStabilizer
             | FunctionName::Stabilizer
@@ -694,11 +717,6 @@ impl FunctionName {
             | FunctionName::KitchenFan
             | FunctionName::CeilingFan
             | FunctionName::TankHeater
-            | FunctionName::LivingRoomClimateZone
-            | FunctionName::FrontLivingRoomClimateZone
-            | FunctionName::RearLivingRoomClimateZone
-            | FunctionName::FrontBedroomClimateZone
-            | FunctionName::RearBedroomClimateZone
             | FunctionName::BedTilt
             | FunctionName::FrontBedTilt
             | FunctionName::RearBedTilt
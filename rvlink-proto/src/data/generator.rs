@@ -0,0 +1,57 @@
+use super::*;
+use fixed::{types::extra::U8, FixedI16, FixedU16};
+
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum GeneratorState {
+    #[default]
+    #[display(fmt = "off")]
+    Off = 0,
+    #[display(fmt = "priming")]
+    Priming = 1,
+    #[display(fmt = "starting")]
+    Starting = 2,
+    #[display(fmt = "running")]
+    Running = 3,
+    #[display(fmt = "stopping")]
+    Stopping = 4,
+}
+
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum GeneratorGenieCommand {
+    #[default]
+    #[display(fmt = "stop")]
+    Stop = 0,
+    #[display(fmt = "start")]
+    Start = 1,
+}
+
+define_encodable_struct! {
+    GeneratorGenieState [10] {
+        device_id: u8 [0],
+        status: u8 [1],
+        battery_voltage: FixedU16<U8> [2],
+        temperature: FixedI16<U8> [4],
+        operating_seconds: u32 [6],
+    }
+}
+
+#[allow(dead_code)]
+impl GeneratorGenieState {
+    pub fn state(&self) -> GeneratorState {
+        GeneratorState::try_from(self.status & 0x07).unwrap_or_default()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.state() == GeneratorState::Running
+    }
+
+    pub fn temperature(&self) -> Option<FixedI16<U8>> {
+        valid_temperature(self.temperature)
+    }
+
+    pub fn run_hours(&self) -> f32 {
+        self.operating_seconds as f32 / 3600f32
+    }
+}
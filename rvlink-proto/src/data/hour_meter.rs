@@ -0,0 +1,32 @@
+use super::*;
+
+define_encodable_struct! {
+    HourMeterState [6] {
+        device_id: u8 [0],
+        operating_seconds: u32 [1],
+        status: u8 [5],
+    }
+}
+
+#[allow(dead_code)]
+impl HourMeterState {
+    pub fn is_running(&self) -> bool {
+        (self.status & 0x01) == 0x01
+    }
+
+    pub fn is_maintenance_due(&self) -> bool {
+        (self.status & 0x02) == 0x02
+    }
+
+    pub fn is_maintenance_past_due(&self) -> bool {
+        (self.status & 0x04) == 0x04
+    }
+
+    pub fn has_error(&self) -> bool {
+        (self.status & 0x08) == 0x08
+    }
+
+    pub fn operating_hours(&self) -> f32 {
+        self.operating_seconds as f32 / 3600f32
+    }
+}
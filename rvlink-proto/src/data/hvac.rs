@@ -0,0 +1,128 @@
+use super::*;
+use fixed::{types::extra::U8, FixedI16};
+
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum HvacHeatMode {
+    #[default]
+    #[display(fmt = "off")]
+    Off = 0,
+    #[display(fmt = "heat")]
+    Heating = 1,
+    #[display(fmt = "cool")]
+    Cooling = 2,
+    #[display(fmt = "heat_cool")]
+    Both = 3,
+    #[display(fmt = "auto")]
+    RunSchedule = 4,
+}
+
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum HvacHeatSource {
+    #[default]
+    #[display(fmt = "prefer_gas")]
+    PreferGas = 0,
+    #[display(fmt = "prefer_heat_pump")]
+    PreferHeatPump = 1,
+    #[display(fmt = "other")]
+    Other = 2,
+}
+
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum HvacFanMode {
+    #[default]
+    #[display(fmt = "auto")]
+    Auto = 0,
+    #[display(fmt = "high")]
+    High = 1,
+    #[display(fmt = "low")]
+    Low = 2,
+}
+
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum HvacZoneStatus {
+    #[default]
+    #[display(fmt = "off")]
+    Off = 0,
+    #[display(fmt = "idle")]
+    Idle = 1,
+    #[display(fmt = "cooling")]
+    Cooling = 2,
+    #[display(fmt = "heat_pump")]
+    HeatPump = 3,
+    #[display(fmt = "electric_furnace")]
+    ElectricFurnace = 4,
+    #[display(fmt = "gas_furnace")]
+    GasFurnace = 5,
+    #[display(fmt = "gas_override")]
+    GasOverride = 6,
+    #[display(fmt = "dead_time")]
+    DeadTime = 7,
+    #[display(fmt = "load_shedding")]
+    LoadShedding = 8,
+}
+
+/// The HVAC command byte packs the heat mode (bits 0-2), heat source (bits 4-5) and fan mode (bits 6-7)
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct HvacCommand {
+    pub heat_mode: HvacHeatMode,
+    pub heat_source: HvacHeatSource,
+    pub fan_mode: HvacFanMode,
+}
+
+impl Encodable for HvacCommand {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        let val = <u8>::from_data(data)?;
+        Ok(Self {
+            heat_mode: HvacHeatMode::try_from(val & 0x07).unwrap_or_default(),
+            heat_source: HvacHeatSource::try_from((val >> 4) & 0x03).unwrap_or_default(),
+            fan_mode: HvacFanMode::try_from((val >> 6) & 0x03).unwrap_or_default(),
+        })
+    }
+
+    fn to_data(&self) -> Vec<u8> {
+        let heat_mode: u8 = self.heat_mode.into();
+        let heat_source: u8 = self.heat_source.into();
+        let fan_mode: u8 = self.fan_mode.into();
+        vec![heat_mode | (heat_source << 4) | (fan_mode << 6)]
+    }
+
+    fn data_size(&self) -> usize {
+        1
+    }
+}
+
+define_encodable_struct! {
+    HvacState [11] {
+        device_id: u8 [0],
+        command: HvacCommand [1],
+        low_trip_temperature: u8 [2],
+        high_trip_temperature: u8 [3],
+        zone_status: u8 [4],
+        indoor_temperature: FixedI16<U8> [5],
+        outdoor_temperature: FixedI16<U8> [7],
+        dtc: u16 [9],
+    }
+}
+
+#[allow(dead_code)]
+impl HvacState {
+    pub fn zone_status(&self) -> HvacZoneStatus {
+        HvacZoneStatus::try_from(self.zone_status & 0x0F).unwrap_or_default()
+    }
+
+    pub fn is_failed(&self) -> bool {
+        (self.zone_status & 0x80) == 0x80
+    }
+
+    pub fn indoor_temperature(&self) -> Option<FixedI16<U8>> {
+        valid_temperature(self.indoor_temperature)
+    }
+
+    pub fn outdoor_temperature(&self) -> Option<FixedI16<U8>> {
+        valid_temperature(self.outdoor_temperature)
+    }
+}
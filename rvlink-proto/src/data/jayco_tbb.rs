@@ -0,0 +1,52 @@
+use super::*;
+use fixed::{types::extra::U8, FixedU16};
+
+define_encodable_struct! {
+    JaycoTbbState [11] {
+        device_id: u8 [0],
+        status: u8 [1],
+        battery_voltage: FixedU16<U8> [2],
+        battery_current: u16 [4],
+        solar_current: u16 [6],
+        state_of_charge: u8 [8],
+        mains_voltage: u16 [9],
+    }
+}
+
+#[allow(dead_code)]
+impl JaycoTbbState {
+    pub fn is_mains_connected(&self) -> bool {
+        (self.status & 0x01) == 0x01
+    }
+
+    pub fn is_solar_charging(&self) -> bool {
+        (self.status & 0x02) == 0x02
+    }
+
+    /// Battery current in amps, signed with positive values while charging
+    pub fn battery_current(&self) -> f32 {
+        f32::from(self.battery_current as i16) / 10f32
+    }
+
+    /// Current from the solar regulator in amps
+    pub fn solar_current(&self) -> f32 {
+        f32::from(self.solar_current) / 10f32
+    }
+
+    pub fn state_of_charge(&self) -> Option<u8> {
+        if self.state_of_charge > 100 {
+            None
+        } else {
+            Some(self.state_of_charge)
+        }
+    }
+
+    /// Mains (shore power) voltage in volts, reported as 0xFFFF when there is no mains sensor
+    pub fn mains_voltage(&self) -> Option<u16> {
+        if self.mains_voltage == 0xFFFF {
+            None
+        } else {
+            Some(self.mains_voltage)
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use super::*;
+
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum LevelerMode {
+    #[default]
+    #[display(fmt = "idle")]
+    Idle = 0,
+    #[display(fmt = "auto_level")]
+    AutoLevel = 1,
+    #[display(fmt = "retracting")]
+    Retract = 2,
+    #[display(fmt = "manual")]
+    Manual = 3,
+    #[display(fmt = "hitch")]
+    Hitch = 4,
+    #[display(fmt = "fault")]
+    Fault = 5,
+}
+
+/// Buttons we can press on the leveler console, the value is the bit in the first button byte
+#[derive(Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum LevelerButton {
+    #[display(fmt = "auto_level")]
+    AutoLevel = 0x01,
+    #[display(fmt = "retract_all")]
+    RetractAll = 0x02,
+    #[display(fmt = "stop")]
+    Stop = 0x04,
+}
+
+impl std::str::FromStr for LevelerButton {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "auto_level" => Ok(LevelerButton::AutoLevel),
+            "retract_all" => Ok(LevelerButton::RetractAll),
+            "stop" => Ok(LevelerButton::Stop),
+            _ => Err(AppError::Generic(format!("Unknown leveler button: {}", s))),
+        }
+    }
+}
+
+define_encodable_struct! {
+    Leveler1State [3] {
+        device_id: u8 [0],
+        status: u8 [1],
+        jacks: u8 [2],
+    }
+    Leveler3State [4] {
+        device_id: u8 [0],
+        screen: u8 [1],
+        status: u8 [2],
+        jacks: u8 [3],
+    }
+    Leveler4State [5] {
+        device_id: u8 [0],
+        device_mode: u8 [1],
+        ui_mode: u8 [2],
+        status: u8 [3],
+        jacks: u8 [4],
+    }
+}
+
+/// Status bits shared by all leveler generations, `jacks` has one bit per extended jack
+#[allow(dead_code)]
+pub trait LevelerStatus {
+    fn device_id(&self) -> u8;
+    fn status(&self) -> u8;
+    fn jacks(&self) -> u8;
+
+    /// Older levelers don't report a mode, so it is derived from the status bits
+    fn mode(&self) -> LevelerMode {
+        if self.has_fault() {
+            LevelerMode::Fault
+        } else if (self.status() & 0x02) == 0x02 {
+            LevelerMode::AutoLevel
+        } else if (self.status() & 0x04) == 0x04 {
+            LevelerMode::Retract
+        } else {
+            LevelerMode::Idle
+        }
+    }
+
+    /// The screen (or UI mode) the console is on, needed when sending button presses
+    fn screen(&self) -> u8 {
+        0
+    }
+
+    fn is_level(&self) -> bool {
+        (self.status() & 0x01) == 0x01
+    }
+
+    fn is_excess_angle(&self) -> bool {
+        (self.status() & 0x40) == 0x40
+    }
+
+    fn has_fault(&self) -> bool {
+        (self.status() & 0x80) == 0x80
+    }
+
+    fn jacks_extended(&self) -> bool {
+        self.jacks() != 0
+    }
+}
+
+impl LevelerStatus for Leveler1State {
+    fn device_id(&self) -> u8 {
+        self.device_id
+    }
+
+    fn status(&self) -> u8 {
+        self.status
+    }
+
+    fn jacks(&self) -> u8 {
+        self.jacks
+    }
+}
+
+impl LevelerStatus for Leveler3State {
+    fn device_id(&self) -> u8 {
+        self.device_id
+    }
+
+    fn status(&self) -> u8 {
+        self.status
+    }
+
+    fn jacks(&self) -> u8 {
+        self.jacks
+    }
+
+    fn screen(&self) -> u8 {
+        self.screen
+    }
+}
+
+impl LevelerStatus for Leveler4State {
+    fn device_id(&self) -> u8 {
+        self.device_id
+    }
+
+    fn status(&self) -> u8 {
+        self.status
+    }
+
+    fn jacks(&self) -> u8 {
+        self.jacks
+    }
+
+    fn mode(&self) -> LevelerMode {
+        LevelerMode::try_from(self.device_mode).unwrap_or_default()
+    }
+
+    fn screen(&self) -> u8 {
+        self.ui_mode
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use fixed::{types::extra::U8, FixedI16};
 
 macro_rules! define_array_struct {
     ($( $name:ident [ $size:literal ] , )*) => {$(
@@ -81,7 +82,7 @@ impl BitFlags {
             // Assuming 12 then:
             let byte_index = index / 8; // 1
             let bit_index = index % 8; // 4
-            let bit_mask = 1 << (bit_index - 1); // 1 << 3 = b00001000
+            let bit_mask = 1 << bit_index; // 1 << 4 = b00010000
             Ok((self.0[byte_index] & bit_mask) > 0)
         }
     }
@@ -97,6 +98,20 @@ impl BitFlags {
     }
 }
 
+/// Unit of every temperature on the bus, readings and setpoints alike
+pub const TEMPERATURE_UNIT: &str = "°F";
+
+/// Signed temperature readings use this value when no sensor is present
+const INVALID_TEMPERATURE: u16 = 0x8000;
+
+pub(crate) fn valid_temperature(temp: FixedI16<U8>) -> Option<FixedI16<U8>> {
+    if temp.to_bits() as u16 == INVALID_TEMPERATURE {
+        None
+    } else {
+        Some(temp)
+    }
+}
+
 impl std::fmt::Display for MacAddress {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -2,16 +2,31 @@ use super::Encodable;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use rvlink_common::error::*;
 
-// pub use dtc_id::*;
+pub use accessory_gateway::*;
+pub use awning_sensor::*;
+pub use battery_monitor::*;
+pub use brakes::*;
+pub use cloud_gateway::*;
 pub use device::*;
 pub use device_metadata::*;
 pub use device_type::*;
+pub use door_lock::*;
+pub use dtc_id::*;
+pub use dtc_value::*;
 pub use function_name::*;
+pub use generator::*;
+pub use hour_meter::*;
+pub use hvac::*;
+pub use jayco_tbb::*;
+pub use leveler::*;
 pub use misc::*;
+pub use monitor_panel::*;
 pub use param_id::*;
+pub use pid_value::*;
 pub use product_id::*;
 pub use protocol_type::*;
 pub use states::*;
+pub use temperature::*;
 
 #[macro_export]
 macro_rules! define_encodable_struct {
@@ -51,17 +66,35 @@ macro_rules! define_encodable_struct {
 macro_rules! enum_with_metadata {
     ($(
         $name:ident: $repr:ty; $( $selector:ident : $selector_type:ty : $selector_index:tt ),* {$(
-            $variant:ident = $index:literal { $( $val:literal ),* },
+            $variant:ident = $index:literal { $( $val:expr ),* },
     )*}
     )*) => {$(
         #[allow(dead_code)]
-        #[derive(Default, Debug, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+        #[derive(Default, Debug, PartialEq, Clone, Copy)]
         #[repr($repr)]
         pub enum $name {
             #[default]
             $( $variant = $index , )*
         }
 
+        // Written out rather than derived, num_enum's derive overflows the compiler stack on
+        // catalogs as large as DTCID. Unlisted values map to the default variant like
+        // num_enum does with #[default]
+        impl From<$repr> for $name {
+            fn from(value: $repr) -> Self {
+                match value {
+                    $( $index => Self::$variant, )*
+                    _ => Self::default(),
+                }
+            }
+        }
+
+        impl From<$name> for $repr {
+            fn from(value: $name) -> $repr {
+                value as $repr
+            }
+        }
+
         #[allow(dead_code)]
         impl $name {
             fn _variant_metadata(&self) -> ($( $selector_type , )*) {
@@ -75,13 +108,28 @@ macro_rules! enum_with_metadata {
     )*};
 }
 
-// mod dtc_id;
+mod accessory_gateway;
+mod awning_sensor;
+mod battery_monitor;
+mod brakes;
+mod cloud_gateway;
 mod device;
 mod device_metadata;
 mod device_type;
+mod door_lock;
+mod dtc_id;
+mod dtc_value;
 mod function_name;
+mod generator;
+mod hour_meter;
+mod hvac;
+mod jayco_tbb;
+mod leveler;
 mod misc;
+mod monitor_panel;
 mod param_id;
+mod pid_value;
 mod product_id;
 mod protocol_type;
 mod states;
+mod temperature;
@@ -0,0 +1,48 @@
+use super::*;
+
+/// How a monitor panel channel is configured, matches the MonitorPanelControlType* parameters
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum MonitorPanelControlType {
+    #[default]
+    #[display(fmt = "none")]
+    None = 0,
+    #[display(fmt = "momentary_switch")]
+    MomentarySwitch = 1,
+    #[display(fmt = "latching_switch")]
+    LatchingSwitch = 2,
+    #[display(fmt = "supply_tank")]
+    SupplyTank = 3,
+    #[display(fmt = "waste_tank")]
+    WasteTank = 4,
+    #[display(fmt = "config_tank")]
+    ConfigTank = 5,
+}
+
+define_encodable_struct! {
+    MonitorPanelDeviceState [3] {
+        device_id: u8 [0],
+        control_type: u8 [1],
+        value: u8 [2],
+    }
+}
+
+#[allow(dead_code)]
+impl MonitorPanelDeviceState {
+    pub fn control_type(&self) -> MonitorPanelControlType {
+        MonitorPanelControlType::try_from(self.control_type).unwrap_or_default()
+    }
+
+    pub fn is_tank(&self) -> bool {
+        matches!(
+            self.control_type(),
+            MonitorPanelControlType::SupplyTank
+                | MonitorPanelControlType::WasteTank
+                | MonitorPanelControlType::ConfigTank
+        )
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.value != 0
+    }
+}
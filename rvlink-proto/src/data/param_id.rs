@@ -1,399 +1,403 @@
-use super::*;
+// Width is the value size in bytes (values wider than 4 bytes are raw data), frac_bits is the
+// number of fractional bits for fixed point values
+enum_with_metadata! {
+    ParameterID: u16; width:u8:0, signed:bool:1, frac_bits:u8:2, unit:&'static str:3, writable:bool:4 {
+        Unknown = 0 {0, false, 0, "", false},
+        ProductionBytes = 1 {6, false, 0, "", false},
+        CanAdapterMac = 2 {6, false, 0, "", false},
+        IdsCanCircuitId = 3 {4, false, 0, "", true},
+        IdsCanFunctionName = 4 {2, false, 0, "", true},
+        IdsCanFunctionInstance = 5 {1, false, 0, "", true},
+        IdsCanNumDevicesOnNetwork = 6 {1, false, 0, "", false},
+        IdsCanMaxNetworkHeartbeatTime = 7 {2, false, 0, "ms", false},
+        SerialNumber = 8 {4, false, 0, "", false},
+        CanBytesTx = 9 {4, false, 0, "", false},
+        CanBytesRx = 10 {4, false, 0, "", false},
+        CanMessagesTx = 11 {4, false, 0, "", false},
+        CanMessagesRx = 12 {4, false, 0, "", false},
+        CanTxBufferOverflowCount = 13 {4, false, 0, "", false},
+        CanRxBufferOverflowCount = 14 {4, false, 0, "", false},
+        CanTxMaxBytesQueued = 15 {4, false, 0, "", false},
+        CanRxMaxBytesQueued = 16 {4, false, 0, "", false},
+        UartBytesTx = 17 {4, false, 0, "", false},
+        UartBytesRx = 18 {4, false, 0, "", false},
+        UartMessagesTx = 19 {4, false, 0, "", false},
+        UartMessagesRx = 20 {4, false, 0, "", false},
+        UartTxBufferOverflowCount = 21 {4, false, 0, "", false},
+        UartRxBufferOverflowCount = 22 {4, false, 0, "", false},
+        UartTxMaxBytesQueued = 23 {4, false, 0, "", false},
+        UartRxMaxBytesQueued = 24 {4, false, 0, "", false},
+        WifiBytesTx = 25 {4, false, 0, "", false},
+        WifiBytesRx = 26 {4, false, 0, "", false},
+        WifiMessagesTx = 27 {4, false, 0, "", false},
+        WifiMessagesRx = 28 {4, false, 0, "", false},
+        WifiTxBufferOverflowCount = 29 {4, false, 0, "", false},
+        WifiRxBufferOverflowCount = 30 {4, false, 0, "", false},
+        WifiTxMaxBytesQueued = 31 {4, false, 0, "", false},
+        WifiRxMaxBytesQueued = 32 {4, false, 0, "", false},
+        WifiRssi = 33 {1, true, 0, "dBm", false},
+        RfBytesTx = 34 {4, false, 0, "", false},
+        RfBytesRx = 35 {4, false, 0, "", false},
+        RfMessagesTx = 36 {4, false, 0, "", false},
+        RfMessagesRx = 37 {4, false, 0, "", false},
+        RfTxBufferOverflowCount = 38 {4, false, 0, "", false},
+        RfRxBufferOverflowCount = 39 {4, false, 0, "", false},
+        RfTxMaxBytesQueued = 40 {4, false, 0, "", false},
+        RfRxMaxBytesQueued = 41 {4, false, 0, "", false},
+        RfRssi = 42 {1, true, 0, "dBm", false},
+        BatteryVoltage = 43 {2, false, 8, "V", false},
+        RegulatorVoltage = 44 {2, false, 8, "V", false},
+        NumTiltSensorAxes = 45 {1, false, 0, "", false},
+        TiltAxis1Angle = 46 {2, true, 8, "°", false},
+        TiltAxis2Angle = 47 {2, true, 8, "°", false},
+        TiltAxis3Angle = 48 {2, true, 8, "°", false},
+        TiltAxis4Angle = 49 {2, true, 8, "°", false},
+        TiltAxis5Angle = 50 {2, true, 8, "°", false},
+        TiltAxis6Angle = 51 {2, true, 8, "°", false},
+        TiltAxis7Angle = 52 {2, true, 8, "°", false},
+        TiltAxis8Angle = 53 {2, true, 8, "°", false},
+        IdsCanFixedAddress = 54 {1, false, 0, "", true},
+        FuseSetting1 = 55 {1, false, 0, "A", true},
+        FuseSetting2 = 56 {1, false, 0, "A", true},
+        FuseSetting3 = 57 {1, false, 0, "A", true},
+        FuseSetting4 = 58 {1, false, 0, "A", true},
+        FuseSetting5 = 59 {1, false, 0, "A", true},
+        FuseSetting6 = 60 {1, false, 0, "A", true},
+        FuseSetting7 = 61 {1, false, 0, "A", true},
+        FuseSetting8 = 62 {1, false, 0, "A", true},
+        FuseSetting9 = 63 {1, false, 0, "A", true},
+        FuseSetting10 = 64 {1, false, 0, "A", true},
+        FuseSetting11 = 65 {1, false, 0, "A", true},
+        FuseSetting12 = 66 {1, false, 0, "A", true},
+        FuseSetting13 = 67 {1, false, 0, "A", true},
+        FuseSetting14 = 68 {1, false, 0, "A", true},
+        FuseSetting15 = 69 {1, false, 0, "A", true},
+        FuseSetting16 = 70 {1, false, 0, "A", true},
+        ManufacturingPid1 = 71 {4, false, 0, "", false},
+        ManufacturingPid2 = 72 {4, false, 0, "", false},
+        ManufacturingPid3 = 73 {4, false, 0, "", false},
+        ManufacturingPid4 = 74 {4, false, 0, "", false},
+        ManufacturingPid5 = 75 {4, false, 0, "", false},
+        ManufacturingPid6 = 76 {4, false, 0, "", false},
+        ManufacturingPid7 = 77 {4, false, 0, "", false},
+        ManufacturingPid8 = 78 {4, false, 0, "", false},
+        ManufacturingPid9 = 79 {4, false, 0, "", false},
+        ManufacturingPid10 = 80 {4, false, 0, "", false},
+        ManufacturingPid11 = 81 {4, false, 0, "", false},
+        ManufacturingPid12 = 82 {4, false, 0, "", false},
+        ManufacturingPid13 = 83 {4, false, 0, "", false},
+        ManufacturingPid14 = 84 {4, false, 0, "", false},
+        ManufacturingPid15 = 85 {4, false, 0, "", false},
+        ManufacturingPid16 = 86 {4, false, 0, "", false},
+        ManufacturingPid17 = 87 {4, false, 0, "", false},
+        ManufacturingPid18 = 88 {4, false, 0, "", false},
+        ManufacturingPid19 = 89 {4, false, 0, "", false},
+        ManufacturingPid20 = 90 {4, false, 0, "", false},
+        ManufacturingPid21 = 91 {4, false, 0, "", false},
+        ManufacturingPid22 = 92 {4, false, 0, "", false},
+        ManufacturingPid23 = 93 {4, false, 0, "", false},
+        ManufacturingPid24 = 94 {4, false, 0, "", false},
+        ManufacturingPid25 = 95 {4, false, 0, "", false},
+        ManufacturingPid26 = 96 {4, false, 0, "", false},
+        ManufacturingPid27 = 97 {4, false, 0, "", false},
+        ManufacturingPid28 = 98 {4, false, 0, "", false},
+        ManufacturingPid29 = 99 {4, false, 0, "", false},
+        ManufacturingPid30 = 100 {4, false, 0, "", false},
+        ManufacturingPid31 = 101 {4, false, 0, "", false},
+        ManufacturingPid32 = 102 {4, false, 0, "", false},
+        MeteredTimeSec = 103 {4, false, 0, "s", false},
+        MaintenancePeriodSec = 104 {4, false, 0, "s", true},
+        LastMaintenanceTimeSec = 105 {4, false, 0, "s", true},
+        TimeZone = 106 {1, true, 0, "h", true},
+        RtcTimeSec = 107 {1, false, 0, "s", true},
+        RtcTimeMin = 108 {1, false, 0, "min", true},
+        RtcTimeHour = 109 {1, false, 0, "h", true},
+        RtcTimeDay = 110 {1, false, 0, "", true},
+        RtcTimeMonth = 111 {1, false, 0, "", true},
+        RtcTimeYear = 112 {2, false, 0, "", true},
+        RtcEpochSec = 113 {4, false, 0, "s", false},
+        RtcSetTimeSec = 114 {4, false, 0, "s", true},
+        BleDeviceName1 = 115 {6, false, 0, "", true},
+        BleDeviceName2 = 116 {6, false, 0, "", true},
+        BleDeviceName3 = 117 {6, false, 0, "", true},
+        BlePin = 118 {6, false, 0, "", true},
+        SystemUptimeMs = 119 {4, false, 0, "ms", false},
+        EthAdapterMac = 120 {6, false, 0, "", false},
+        EthBytesTx = 121 {4, false, 0, "", false},
+        EthBytesRx = 122 {4, false, 0, "", false},
+        EthMessagesTx = 123 {4, false, 0, "", false},
+        EthMessagesRx = 124 {4, false, 0, "", false},
+        EthTxBufferOverflowCount = 125 {4, false, 0, "", false},
+        EthRxBufferOverflowCount = 126 {4, false, 0, "", false},
+        EthPacketsTxDiscarded = 127 {4, false, 0, "", false},
+        EthPacketsRxDiscarded = 128 {4, false, 0, "", false},
+        EthPacketsTxError = 129 {4, false, 0, "", false},
+        EthPacketsRxError = 130 {4, false, 0, "", false},
+        EthPacketsTxOverflow = 131 {4, false, 0, "", false},
+        EthPacketsTxLateCollision = 132 {4, false, 0, "", false},
+        EthPacketsTxExcessCollision = 133 {4, false, 0, "", false},
+        EthPacketsTxUnderflow = 134 {4, false, 0, "", false},
+        EthPacketsRxAlignErr = 135 {4, false, 0, "", false},
+        EthPacketsRxCrcErr = 136 {4, false, 0, "", false},
+        EthPacketsRxTruncErr = 137 {4, false, 0, "", false},
+        EthPacketsRxLenErr = 138 {4, false, 0, "", false},
+        EthPacketsRxCollision = 139 {4, false, 0, "", false},
+        IpAddress = 140 {4, false, 0, "", false},
+        IpSubnetmask = 141 {4, false, 0, "", false},
+        IpGateway = 142 {4, false, 0, "", false},
+        TcpNumConnections = 143 {1, false, 0, "", false},
+        AuxBatteryVoltage = 144 {2, false, 8, "V", false},
+        RgbLightingGangEnable = 145 {1, false, 0, "", true},
+        InputSwitchType = 146 {1, false, 0, "", true},
+        DoorLockState = 147 {1, false, 0, "", false},
+        GeneratorQuietHoursStartTime = 148 {2, false, 0, "min", true},
+        GeneratorQuietHoursEndTime = 149 {2, false, 0, "min", true},
+        GeneratorAutoStartLowVoltage = 150 {2, false, 8, "V", true},
+        GeneratorAutoStartHiTempC = 151 {2, true, 8, "°C", true},
+        GeneratorAutoRunDurationMinutes = 152 {2, false, 0, "min", true},
+        GeneratorAutoRunMinOffTimeMinutes = 153 {2, false, 0, "min", true},
+        SoftwareBuildDateTime = 154 {6, false, 0, "", false},
+        GeneratorQuietHoursEnabled = 155 {1, false, 0, "", true},
+        ShorePowerAmpRating = 156 {1, false, 0, "A", true},
+        BatteryCapacityAmpHours = 157 {2, false, 0, "Ah", true},
+        PcbAssemblyPartNumber = 158 {4, false, 0, "", false},
+        UnlockPin = 159 {4, false, 0, "", true},
+        UnlockPinMode = 160 {1, false, 0, "", true},
+        SimulateOnOffStyleLight = 161 {1, false, 0, "", true},
+        FanSpeedControlType = 162 {1, false, 0, "", true},
+        HvacControlType = 163 {1, false, 0, "", true},
+        SoftwareFuseRatingAmps = 164 {1, false, 0, "A", true},
+        SoftwareFuseMaxRatingAmps = 165 {1, false, 0, "A", false},
+        CumminsOnanGeneratorFaultCode = 166 {2, false, 0, "", false},
+        Motor1CurrentAmps = 167 {2, false, 8, "A", false},
+        Motor2CurrentAmps = 168 {2, false, 8, "A", false},
+        Motor3CurrentAmps = 169 {2, false, 8, "A", false},
+        Motor4CurrentAmps = 170 {2, false, 8, "A", false},
+        Motor5CurrentAmps = 171 {2, false, 8, "A", false},
+        Motor6CurrentAmps = 172 {2, false, 8, "A", false},
+        Motor7CurrentAmps = 173 {2, false, 8, "A", false},
+        Motor8CurrentAmps = 174 {2, false, 8, "A", false},
+        Motor9CurrentAmps = 175 {2, false, 8, "A", false},
+        Motor10CurrentAmps = 176 {2, false, 8, "A", false},
+        Motor11CurrentAmps = 177 {2, false, 8, "A", false},
+        Motor12CurrentAmps = 178 {2, false, 8, "A", false},
+        Motor13CurrentAmps = 179 {2, false, 8, "A", false},
+        Motor14CurrentAmps = 180 {2, false, 8, "A", false},
+        Motor15CurrentAmps = 181 {2, false, 8, "A", false},
+        Motor16CurrentAmps = 182 {2, false, 8, "A", false},
+        DeviceType = 183 {1, false, 0, "", false},
+        InMotionLockoutBehavior = 184 {1, false, 0, "", true},
+        RvcDetectedNodes = 185 {4, false, 0, "", false},
+        RvcLostNodes = 186 {4, false, 0, "", false},
+        RvcBytesTx = 187 {4, false, 0, "", false},
+        RvcBytesRx = 188 {4, false, 0, "", false},
+        RvcMessagesTx = 189 {4, false, 0, "", false},
+        RvcMessagesRx = 190 {4, false, 0, "", false},
+        RvcTxBuffersFree = 191 {4, false, 0, "", false},
+        RvcTxBuffersUsed = 192 {4, false, 0, "", false},
+        RvcRxBuffersFree = 193 {4, false, 0, "", false},
+        RvcRxBuffersUsed = 194 {4, false, 0, "", false},
+        RvcTxOutOfBuffersCount = 195 {4, false, 0, "", false},
+        RvcRxOutOfBuffersCount = 196 {4, false, 0, "", false},
+        RvcTxFailureCount = 197 {4, false, 0, "", false},
+        RvcDefaultSrcAddr = 198 {1, false, 0, "", true},
+        RvcDynamicAddr = 199 {1, false, 0, "", false},
+        RvcMake = 200 {6, false, 0, "", false},
+        RvcModel1 = 201 {6, false, 0, "", false},
+        RvcModel2 = 202 {6, false, 0, "", false},
+        RvcModel3 = 203 {6, false, 0, "", false},
+        RvcSerial = 204 {4, false, 0, "", false},
+        RvcIdNumber = 205 {4, false, 0, "", false},
+        CloudGatewayAssetIdPart1 = 206 {6, false, 0, "", false},
+        CloudGatewayAssetIdPart2 = 207 {6, false, 0, "", false},
+        CloudGatewayAssetIdPart3 = 208 {6, false, 0, "", false},
+        HvacZoneCapabilities = 209 {1, false, 0, "", false},
+        IgnitionBehavior = 210 {1, false, 0, "", true},
+        BleNumberOfForwardedCanDevices = 211 {1, false, 0, "", false},
+        BleNumberOfConnects = 212 {4, false, 0, "", false},
+        BleNumberOfDisconnects = 213 {4, false, 0, "", false},
+        BleTotalTraffic = 214 {4, false, 0, "", false},
+        BleWritesFromPhone = 215 {4, false, 0, "", false},
+        BleNotificationsToPhoneSuccessful = 216 {4, false, 0, "", false},
+        BleNotificationsToPhoneFailure = 217 {4, false, 0, "", false},
+        BleMtuSizeCentral = 218 {2, false, 0, "", false},
+        BleMtuSizePeripheral = 219 {2, false, 0, "", false},
+        BleDataLengthTime = 220 {2, false, 0, "", false},
+        BleSecurityUnlocked = 221 {1, false, 0, "", false},
+        BleClientConnected = 222 {1, false, 0, "", false},
+        BleCccdWritten = 223 {1, false, 0, "", false},
+        BleNumBuffersFree = 224 {1, false, 0, "", false},
+        BleLastTxError = 225 {1, false, 0, "", false},
+        BleConnectedDeviceRssi = 226 {1, true, 0, "dBm", false},
+        BleDeadClientCounter = 227 {4, false, 0, "", false},
+        BleLastDisconnectReason = 228 {1, false, 0, "", false},
+        BleSpiRxMsgsDropped = 229 {4, false, 0, "", false},
+        BleSpiTxMsgsDropped = 230 {4, false, 0, "", false},
+        LowVoltageBehavior = 231 {1, false, 0, "", true},
+        DhcpEnabled = 232 {1, false, 0, "", true},
+        UdpDeviceName1 = 233 {6, false, 0, "", true},
+        UdpDeviceName2 = 234 {6, false, 0, "", true},
+        UdpDeviceName3 = 235 {6, false, 0, "", true},
+        TcpBatchSize = 236 {2, false, 0, "", true},
+        TcpBatchTime = 237 {2, false, 0, "ms", true},
+        OnOffInputPin = 238 {1, false, 0, "", true},
+        ExtendInputPin = 239 {1, false, 0, "", true},
+        RetractInputPin = 240 {1, false, 0, "", true},
+        InputPinCount = 241 {1, false, 0, "", false},
+        DsiFaultInputPin = 242 {1, false, 0, "", true},
+        DeviceActivationTimeout = 243 {2, false, 0, "s", true},
+        LevelerUiSupportedFeatures = 244 {4, false, 0, "", false},
+        LevelerSensorTopology = 245 {1, false, 0, "", false},
+        LevelerDriveType = 246 {1, false, 0, "", false},
+        LevelerAutoModeProgress = 247 {1, false, 0, "%", false},
+        LeftFrontJackStrokeInches = 248 {2, false, 8, "in", false},
+        RightFrontJackStrokeInches = 249 {2, false, 8, "in", false},
+        LeftMiddleJackStrokeInches = 250 {2, false, 8, "in", false},
+        RightMiddleJackStrokeInches = 251 {2, false, 8, "in", false},
+        LeftRearJackStrokeInches = 252 {2, false, 8, "in", false},
+        RightRearJackStrokeInches = 253 {2, false, 8, "in", false},
+        LeftFrontJackMaxStrokeInches = 254 {2, false, 8, "in", false},
+        RightFrontJackMaxStrokeInches = 255 {2, false, 8, "in", false},
+        LeftMiddleJackMaxStrokeInches = 256 {2, false, 8, "in", false},
+        RightMiddleJackMaxStrokeInches = 257 {2, false, 8, "in", false},
+        LeftRearJackMaxStrokeInches = 258 {2, false, 8, "in", false},
+        RightRearJackMaxStrokeInches = 259 {2, false, 8, "in", false},
+        ParkbrakeBehavior = 260 {1, false, 0, "", true},
+        ExtendedDeviceCapabilities = 261 {4, false, 0, "", false},
+        CloudCapabilities = 262 {4, false, 0, "", false},
+        RvMakeId = 263 {2, false, 0, "", true},
+        RvModelId = 264 {2, false, 0, "", true},
+        RvYear = 265 {2, false, 0, "", true},
+        RvFloorplanId = 266 {2, false, 0, "", true},
+        FloorplanPartNum = 267 {4, false, 0, "", true},
+        FloorplanWrittenBy = 268 {4, false, 0, "", true},
+        AssemblyPartNum = 269 {4, false, 0, "", false},
+        AssemblyDateCode = 270 {4, false, 0, "", false},
+        AssemblySerialNum = 271 {4, false, 0, "", false},
+        LevelerAutoProcessSteps1 = 272 {6, false, 0, "", false},
+        LevelerAutoProcessSteps2 = 273 {6, false, 0, "", false},
+        LevelerAutoProcessSteps3 = 274 {6, false, 0, "", false},
+        LevelerAutoProcessSteps4 = 275 {6, false, 0, "", false},
+        LevelerAutoProcessSteps5 = 276 {6, false, 0, "", false},
+        MonitorPanelDeviceId01 = 277 {1, false, 0, "", true},
+        MonitorPanelDeviceId02 = 278 {1, false, 0, "", true},
+        MonitorPanelDeviceId03 = 279 {1, false, 0, "", true},
+        MonitorPanelDeviceId04 = 280 {1, false, 0, "", true},
+        MonitorPanelDeviceId05 = 281 {1, false, 0, "", true},
+        MonitorPanelDeviceId06 = 282 {1, false, 0, "", true},
+        MonitorPanelDeviceId07 = 283 {1, false, 0, "", true},
+        MonitorPanelDeviceId08 = 284 {1, false, 0, "", true},
+        MonitorPanelDeviceId09 = 285 {1, false, 0, "", true},
+        MonitorPanelDeviceId10 = 286 {1, false, 0, "", true},
+        MonitorPanelDeviceId11 = 287 {1, false, 0, "", true},
+        MonitorPanelDeviceId12 = 288 {1, false, 0, "", true},
+        MonitorPanelDeviceId13 = 289 {1, false, 0, "", true},
+        MonitorPanelDeviceId14 = 290 {1, false, 0, "", true},
+        MonitorPanelDeviceId15 = 291 {1, false, 0, "", true},
+        MonitorPanelDeviceId16 = 292 {1, false, 0, "", true},
+        MonitorPanelDeviceId17 = 293 {1, false, 0, "", true},
+        MonitorPanelDeviceId18 = 294 {1, false, 0, "", true},
+        MonitorPanelDeviceId19 = 295 {1, false, 0, "", true},
+        MonitorPanelDeviceId20 = 296 {1, false, 0, "", true},
+        MonitorPanelDeviceId21 = 297 {1, false, 0, "", true},
+        MonitorPanelDeviceId22 = 298 {1, false, 0, "", true},
+        MonitorPanelDeviceId23 = 299 {1, false, 0, "", true},
+        MonitorPanelDeviceId24 = 300 {1, false, 0, "", true},
+        MonitorPanelDeviceId25 = 301 {1, false, 0, "", true},
+        MonitorPanelDeviceId26 = 302 {1, false, 0, "", true},
+        MonitorPanelDeviceId27 = 303 {1, false, 0, "", true},
+        MonitorPanelDeviceId28 = 304 {1, false, 0, "", true},
+        MonitorPanelDeviceId29 = 305 {1, false, 0, "", true},
+        MonitorPanelDeviceId30 = 306 {1, false, 0, "", true},
+        MonitorPanelDeviceId31 = 307 {1, false, 0, "", true},
+        MonitorPanelDeviceId32 = 308 {1, false, 0, "", true},
+        MonitorPanelDeviceId33 = 309 {1, false, 0, "", true},
+        MonitorPanelDeviceId34 = 310 {1, false, 0, "", true},
+        MonitorPanelDeviceId35 = 311 {1, false, 0, "", true},
+        MonitorPanelDeviceId36 = 312 {1, false, 0, "", true},
+        MonitorPanelDeviceId37 = 313 {1, false, 0, "", true},
+        MonitorPanelDeviceId38 = 314 {1, false, 0, "", true},
+        MonitorPanelDeviceId39 = 315 {1, false, 0, "", true},
+        MonitorPanelDeviceId40 = 316 {1, false, 0, "", true},
+        MonitorPanelDeviceId41 = 317 {1, false, 0, "", true},
+        MonitorPanelDeviceId42 = 318 {1, false, 0, "", true},
+        MonitorPanelDeviceId43 = 319 {1, false, 0, "", true},
+        MonitorPanelDeviceId44 = 320 {1, false, 0, "", true},
+        MonitorPanelDeviceId45 = 321 {1, false, 0, "", true},
+        MonitorPanelDeviceId46 = 322 {1, false, 0, "", true},
+        MonitorPanelDeviceId47 = 323 {1, false, 0, "", true},
+        MonitorPanelDeviceId48 = 324 {1, false, 0, "", true},
+        MonitorPanelControlTypeMomentarySwitch = 325 {6, false, 0, "", true},
+        MonitorPanelControlTypeLatchingSwitch = 326 {6, false, 0, "", true},
+        MonitorPanelControlTypeSupplyTank = 327 {6, false, 0, "", true},
+        MonitorPanelControlTypeWasteTank = 328 {6, false, 0, "", true},
+        MonitorPanelConfiguration = 329 {4, false, 0, "", true},
+        BlePairingMode = 330 {1, false, 0, "", true},
+        MonitorPanelCalibrationPartNbr = 331 {4, false, 0, "", false},
+        ReadAddress16bitsData32bits = 332 {4, false, 0, "", false},
+        WriteAddress16bitsData32bits = 333 {4, false, 0, "", true},
+        TempSensorHighTempAlert = 334 {2, true, 8, "°F", true},
+        TempSensorLowTempAlert = 335 {2, true, 8, "°F", true},
+        AccGwAddDeviceMac = 336 {6, false, 0, "", true},
+        AccGwWriteDeviceSwNum = 337 {4, false, 0, "", true},
+        VehicleConfiguration = 338 {4, false, 0, "", true},
+        TpmsSensorPosition = 339 {1, false, 0, "", true},
+        TpmsSensorPresureFaultLimits = 340 {4, false, 0, "", true},
+        TpmsSensorTemperatureFaultLimits = 341 {4, false, 0, "", true},
+        TpmsSensorId = 342 {4, false, 0, "", true},
+        SmartArmWindEventSetting = 343 {1, false, 0, "", true},
+        AccRequestMode = 344 {1, false, 0, "", true},
+        AccessorySetting01 = 345 {4, false, 0, "", true},
+        AccessorySetting02 = 346 {4, false, 0, "", true},
+        AccessorySetting03 = 347 {4, false, 0, "", true},
+        AccessorySetting04 = 348 {4, false, 0, "", true},
+        AccessorySetting05 = 349 {4, false, 0, "", true},
+        AccessorySetting06 = 350 {4, false, 0, "", true},
+        AccessorySetting07 = 351 {4, false, 0, "", true},
+        AccessorySetting08 = 352 {4, false, 0, "", true},
+        AccessorySetting09 = 353 {4, false, 0, "", true},
+        AccessorySetting10 = 354 {4, false, 0, "", true},
+        AccessorySetting11 = 355 {4, false, 0, "", true},
+        AccessorySetting12 = 356 {4, false, 0, "", true},
+        AccessorySetting13 = 357 {4, false, 0, "", true},
+        AccessorySetting14 = 358 {4, false, 0, "", true},
+        AccessorySetting15 = 359 {4, false, 0, "", true},
+        AccessorySetting16 = 360 {4, false, 0, "", true},
+        TireTrackWidth = 361 {2, false, 0, "in", true},
+        TireDiameter = 362 {2, false, 0, "in", true},
+        AbsRimTeethCount = 363 {1, false, 0, "", true},
+        AbsMaintenancePeriod = 364 {4, false, 0, "", true},
+        IlluminationSync = 365 {1, false, 0, "", true},
+        RvCInstance = 366 {1, false, 0, "", true},
+        HvacControlTypeSetting = 367 {1, false, 0, "", true},
+        ActiveHvacControlType = 368 {1, false, 0, "", false},
+        MonitorPanelControlTypeConfigTank = 369 {6, false, 0, "", true},
+        NumberOfAxles = 370 {1, false, 0, "", true},
+        LastMaintenanceOdometer = 371 {4, false, 0, "mi", true},
+        AccGwNumDevices = 372 {1, false, 0, "", false},
+        AccGwMacHigh = 373 {4, false, 0, "", false},
+        AccGwMacLow = 374 {4, false, 0, "", false},
+        DeviceTypeAtIndex = 375 {1, false, 0, "", false},
+        BrakeModuleOrientation = 376 {1, false, 0, "", true},
+        CoreMicrocontollerReset = 377 {1, false, 0, "", true},
+        ProductFwPartNum = 378 {4, false, 0, "", false},
+        CoreVersionInfo = 379 {4, false, 0, "", false},
+        ProductIdNum = 380 {2, false, 0, "", false},
+        ProductIdInConfigBlock = 381 {2, false, 0, "", false},
+        LocapVersionInfo = 382 {4, false, 0, "", false},
+        ProductFwPartNum1 = 383 {4, false, 0, "", false},
+        ProductFwPartNum2 = 384 {4, false, 0, "", false},
+        HbridgeSafetyAlertConfig = 385 {1, false, 0, "", true},
+        AwningAutoProtectionCount = 386 {2, false, 0, "", false},
+        MomentaryHbridgeCircuitRole = 387 {1, false, 0, "", true},
+        SoundsHighestCapable = 388 {1, false, 0, "", false},
+        SmartArmValanceCorrection = 389 {1, false, 0, "", true},
+        JumpToBoot = 390 {1, false, 0, "", true},
+    }
+}
 
-#[allow(dead_code)]
-#[derive(Default, Debug, Display, TryFromPrimitive, IntoPrimitive, PartialEq, Clone, Copy)]
-#[repr(u16)]
-pub enum ParameterID {
-    #[default]
-    Unknown = 0,
-    ProductionBytes = 1,
-    CanAdapterMac = 2,
-    IdsCanCircuitId = 3,
-    IdsCanFunctionName = 4,
-    IdsCanFunctionInstance = 5,
-    IdsCanNumDevicesOnNetwork = 6,
-    IdsCanMaxNetworkHeartbeatTime = 7,
-    SerialNumber = 8,
-    CanBytesTx = 9,
-    CanBytesRx = 10,
-    CanMessagesTx = 11,
-    CanMessagesRx = 12,
-    CanTxBufferOverflowCount = 13,
-    CanRxBufferOverflowCount = 14,
-    CanTxMaxBytesQueued = 15,
-    CanRxMaxBytesQueued = 16,
-    UartBytesTx = 17,
-    UartBytesRx = 18,
-    UartMessagesTx = 19,
-    UartMessagesRx = 20,
-    UartTxBufferOverflowCount = 21,
-    UartRxBufferOverflowCount = 22,
-    UartTxMaxBytesQueued = 23,
-    UartRxMaxBytesQueued = 24,
-    WifiBytesTx = 25,
-    WifiBytesRx = 26,
-    WifiMessagesTx = 27,
-    WifiMessagesRx = 28,
-    WifiTxBufferOverflowCount = 29,
-    WifiRxBufferOverflowCount = 30,
-    WifiTxMaxBytesQueued = 31,
-    WifiRxMaxBytesQueued = 32,
-    WifiRssi = 33,
-    RfBytesTx = 34,
-    RfBytesRx = 35,
-    RfMessagesTx = 36,
-    RfMessagesRx = 37,
-    RfTxBufferOverflowCount = 38,
-    RfRxBufferOverflowCount = 39,
-    RfTxMaxBytesQueued = 40,
-    RfRxMaxBytesQueued = 41,
-    RfRssi = 42,
-    BatteryVoltage = 43,
-    RegulatorVoltage = 44,
-    NumTiltSensorAxes = 45,
-    TiltAxis1Angle = 46,
-    TiltAxis2Angle = 47,
-    TiltAxis3Angle = 48,
-    TiltAxis4Angle = 49,
-    TiltAxis5Angle = 50,
-    TiltAxis6Angle = 51,
-    TiltAxis7Angle = 52,
-    TiltAxis8Angle = 53,
-    IdsCanFixedAddress = 54,
-    FuseSetting1 = 55,
-    FuseSetting2 = 56,
-    FuseSetting3 = 57,
-    FuseSetting4 = 58,
-    FuseSetting5 = 59,
-    FuseSetting6 = 60,
-    FuseSetting7 = 61,
-    FuseSetting8 = 62,
-    FuseSetting9 = 63,
-    FuseSetting10 = 64,
-    FuseSetting11 = 65,
-    FuseSetting12 = 66,
-    FuseSetting13 = 67,
-    FuseSetting14 = 68,
-    FuseSetting15 = 69,
-    FuseSetting16 = 70,
-    ManufacturingPid1 = 71,
-    ManufacturingPid2 = 72,
-    ManufacturingPid3 = 73,
-    ManufacturingPid4 = 74,
-    ManufacturingPid5 = 75,
-    ManufacturingPid6 = 76,
-    ManufacturingPid7 = 77,
-    ManufacturingPid8 = 78,
-    ManufacturingPid9 = 79,
-    ManufacturingPid10 = 80,
-    ManufacturingPid11 = 81,
-    ManufacturingPid12 = 82,
-    ManufacturingPid13 = 83,
-    ManufacturingPid14 = 84,
-    ManufacturingPid15 = 85,
-    ManufacturingPid16 = 86,
-    ManufacturingPid17 = 87,
-    ManufacturingPid18 = 88,
-    ManufacturingPid19 = 89,
-    ManufacturingPid20 = 90,
-    ManufacturingPid21 = 91,
-    ManufacturingPid22 = 92,
-    ManufacturingPid23 = 93,
-    ManufacturingPid24 = 94,
-    ManufacturingPid25 = 95,
-    ManufacturingPid26 = 96,
-    ManufacturingPid27 = 97,
-    ManufacturingPid28 = 98,
-    ManufacturingPid29 = 99,
-    ManufacturingPid30 = 100,
-    ManufacturingPid31 = 101,
-    ManufacturingPid32 = 102,
-    MeteredTimeSec = 103,
-    MaintenancePeriodSec = 104,
-    LastMaintenanceTimeSec = 105,
-    TimeZone = 106,
-    RtcTimeSec = 107,
-    RtcTimeMin = 108,
-    RtcTimeHour = 109,
-    RtcTimeDay = 110,
-    RtcTimeMonth = 111,
-    RtcTimeYear = 112,
-    RtcEpochSec = 113,
-    RtcSetTimeSec = 114,
-    BleDeviceName1 = 115,
-    BleDeviceName2 = 116,
-    BleDeviceName3 = 117,
-    BlePin = 118,
-    SystemUptimeMs = 119,
-    EthAdapterMac = 120,
-    EthBytesTx = 121,
-    EthBytesRx = 122,
-    EthMessagesTx = 123,
-    EthMessagesRx = 124,
-    EthTxBufferOverflowCount = 125,
-    EthRxBufferOverflowCount = 126,
-    EthPacketsTxDiscarded = 127,
-    EthPacketsRxDiscarded = 128,
-    EthPacketsTxError = 129,
-    EthPacketsRxError = 130,
-    EthPacketsTxOverflow = 131,
-    EthPacketsTxLateCollision = 132,
-    EthPacketsTxExcessCollision = 133,
-    EthPacketsTxUnderflow = 134,
-    EthPacketsRxAlignErr = 135,
-    EthPacketsRxCrcErr = 136,
-    EthPacketsRxTruncErr = 137,
-    EthPacketsRxLenErr = 138,
-    EthPacketsRxCollision = 139,
-    IpAddress = 140,
-    IpSubnetmask = 141,
-    IpGateway = 142,
-    TcpNumConnections = 143,
-    AuxBatteryVoltage = 144,
-    RgbLightingGangEnable = 145,
-    InputSwitchType = 146,
-    DoorLockState = 147,
-    GeneratorQuietHoursStartTime = 148,
-    GeneratorQuietHoursEndTime = 149,
-    GeneratorAutoStartLowVoltage = 150,
-    GeneratorAutoStartHiTempC = 151,
-    GeneratorAutoRunDurationMinutes = 152,
-    GeneratorAutoRunMinOffTimeMinutes = 153,
-    SoftwareBuildDateTime = 154,
-    GeneratorQuietHoursEnabled = 155,
-    ShorePowerAmpRating = 156,
-    BatteryCapacityAmpHours = 157,
-    PcbAssemblyPartNumber = 158,
-    UnlockPin = 159,
-    UnlockPinMode = 160,
-    SimulateOnOffStyleLight = 161,
-    FanSpeedControlType = 162,
-    HvacControlType = 163,
-    SoftwareFuseRatingAmps = 164,
-    SoftwareFuseMaxRatingAmps = 165,
-    CumminsOnanGeneratorFaultCode = 166,
-    Motor1CurrentAmps = 167,
-    Motor2CurrentAmps = 168,
-    Motor3CurrentAmps = 169,
-    Motor4CurrentAmps = 170,
-    Motor5CurrentAmps = 171,
-    Motor6CurrentAmps = 172,
-    Motor7CurrentAmps = 173,
-    Motor8CurrentAmps = 174,
-    Motor9CurrentAmps = 175,
-    Motor10CurrentAmps = 176,
-    Motor11CurrentAmps = 177,
-    Motor12CurrentAmps = 178,
-    Motor13CurrentAmps = 179,
-    Motor14CurrentAmps = 180,
-    Motor15CurrentAmps = 181,
-    Motor16CurrentAmps = 182,
-    DeviceType = 183,
-    InMotionLockoutBehavior = 184,
-    RvcDetectedNodes = 185,
-    RvcLostNodes = 186,
-    RvcBytesTx = 187,
-    RvcBytesRx = 188,
-    RvcMessagesTx = 189,
-    RvcMessagesRx = 190,
-    RvcTxBuffersFree = 191,
-    RvcTxBuffersUsed = 192,
-    RvcRxBuffersFree = 193,
-    RvcRxBuffersUsed = 194,
-    RvcTxOutOfBuffersCount = 195,
-    RvcRxOutOfBuffersCount = 196,
-    RvcTxFailureCount = 197,
-    RvcDefaultSrcAddr = 198,
-    RvcDynamicAddr = 199,
-    RvcMake = 200,
-    RvcModel1 = 201,
-    RvcModel2 = 202,
-    RvcModel3 = 203,
-    RvcSerial = 204,
-    RvcIdNumber = 205,
-    CloudGatewayAssetIdPart1 = 206,
-    CloudGatewayAssetIdPart2 = 207,
-    CloudGatewayAssetIdPart3 = 208,
-    HvacZoneCapabilities = 209,
-    IgnitionBehavior = 210,
-    BleNumberOfForwardedCanDevices = 211,
-    BleNumberOfConnects = 212,
-    BleNumberOfDisconnects = 213,
-    BleTotalTraffic = 214,
-    BleWritesFromPhone = 215,
-    BleNotificationsToPhoneSuccessful = 216,
-    BleNotificationsToPhoneFailure = 217,
-    BleMtuSizeCentral = 218,
-    BleMtuSizePeripheral = 219,
-    BleDataLengthTime = 220,
-    BleSecurityUnlocked = 221,
-    BleClientConnected = 222,
-    BleCccdWritten = 223,
-    BleNumBuffersFree = 224,
-    BleLastTxError = 225,
-    BleConnectedDeviceRssi = 226,
-    BleDeadClientCounter = 227,
-    BleLastDisconnectReason = 228,
-    BleSpiRxMsgsDropped = 229,
-    BleSpiTxMsgsDropped = 230,
-    LowVoltageBehavior = 231,
-    DhcpEnabled = 232,
-    UdpDeviceName1 = 233,
-    UdpDeviceName2 = 234,
-    UdpDeviceName3 = 235,
-    TcpBatchSize = 236,
-    TcpBatchTime = 237,
-    OnOffInputPin = 238,
-    ExtendInputPin = 239,
-    RetractInputPin = 240,
-    InputPinCount = 241,
-    DsiFaultInputPin = 242,
-    DeviceActivationTimeout = 243,
-    LevelerUiSupportedFeatures = 244,
-    LevelerSensorTopology = 245,
-    LevelerDriveType = 246,
-    LevelerAutoModeProgress = 247,
-    LeftFrontJackStrokeInches = 248,
-    RightFrontJackStrokeInches = 249,
-    LeftMiddleJackStrokeInches = 250,
-    RightMiddleJackStrokeInches = 251,
-    LeftRearJackStrokeInches = 252,
-    RightRearJackStrokeInches = 253,
-    LeftFrontJackMaxStrokeInches = 254,
-    RightFrontJackMaxStrokeInches = 255,
-    LeftMiddleJackMaxStrokeInches = 256,
-    RightMiddleJackMaxStrokeInches = 257,
-    LeftRearJackMaxStrokeInches = 258,
-    RightRearJackMaxStrokeInches = 259,
-    ParkbrakeBehavior = 260,
-    ExtendedDeviceCapabilities = 261,
-    CloudCapabilities = 262,
-    RvMakeId = 263,
-    RvModelId = 264,
-    RvYear = 265,
-    RvFloorplanId = 266,
-    FloorplanPartNum = 267,
-    FloorplanWrittenBy = 268,
-    AssemblyPartNum = 269,
-    AssemblyDateCode = 270,
-    AssemblySerialNum = 271,
-    LevelerAutoProcessSteps1 = 272,
-    LevelerAutoProcessSteps2 = 273,
-    LevelerAutoProcessSteps3 = 274,
-    LevelerAutoProcessSteps4 = 275,
-    LevelerAutoProcessSteps5 = 276,
-    MonitorPanelDeviceId01 = 277,
-    MonitorPanelDeviceId02 = 278,
-    MonitorPanelDeviceId03 = 279,
-    MonitorPanelDeviceId04 = 280,
-    MonitorPanelDeviceId05 = 281,
-    MonitorPanelDeviceId06 = 282,
-    MonitorPanelDeviceId07 = 283,
-    MonitorPanelDeviceId08 = 284,
-    MonitorPanelDeviceId09 = 285,
-    MonitorPanelDeviceId10 = 286,
-    MonitorPanelDeviceId11 = 287,
-    MonitorPanelDeviceId12 = 288,
-    MonitorPanelDeviceId13 = 289,
-    MonitorPanelDeviceId14 = 290,
-    MonitorPanelDeviceId15 = 291,
-    MonitorPanelDeviceId16 = 292,
-    MonitorPanelDeviceId17 = 293,
-    MonitorPanelDeviceId18 = 294,
-    MonitorPanelDeviceId19 = 295,
-    MonitorPanelDeviceId20 = 296,
-    MonitorPanelDeviceId21 = 297,
-    MonitorPanelDeviceId22 = 298,
-    MonitorPanelDeviceId23 = 299,
-    MonitorPanelDeviceId24 = 300,
-    MonitorPanelDeviceId25 = 301,
-    MonitorPanelDeviceId26 = 302,
-    MonitorPanelDeviceId27 = 303,
-    MonitorPanelDeviceId28 = 304,
-    MonitorPanelDeviceId29 = 305,
-    MonitorPanelDeviceId30 = 306,
-    MonitorPanelDeviceId31 = 307,
-    MonitorPanelDeviceId32 = 308,
-    MonitorPanelDeviceId33 = 309,
-    MonitorPanelDeviceId34 = 310,
-    MonitorPanelDeviceId35 = 311,
-    MonitorPanelDeviceId36 = 312,
-    MonitorPanelDeviceId37 = 313,
-    MonitorPanelDeviceId38 = 314,
-    MonitorPanelDeviceId39 = 315,
-    MonitorPanelDeviceId40 = 316,
-    MonitorPanelDeviceId41 = 317,
-    MonitorPanelDeviceId42 = 318,
-    MonitorPanelDeviceId43 = 319,
-    MonitorPanelDeviceId44 = 320,
-    MonitorPanelDeviceId45 = 321,
-    MonitorPanelDeviceId46 = 322,
-    MonitorPanelDeviceId47 = 323,
-    MonitorPanelDeviceId48 = 324,
-    MonitorPanelControlTypeMomentarySwitch = 325,
-    MonitorPanelControlTypeLatchingSwitch = 326,
-    MonitorPanelControlTypeSupplyTank = 327,
-    MonitorPanelControlTypeWasteTank = 328,
-    MonitorPanelConfiguration = 329,
-    BlePairingMode = 330,
-    MonitorPanelCalibrationPartNbr = 331,
-    ReadAddress16bitsData32bits = 332,
-    WriteAddress16bitsData32bits = 333,
-    TempSensorHighTempAlert = 334,
-    TempSensorLowTempAlert = 335,
-    AccGwAddDeviceMac = 336,
-    AccGwWriteDeviceSwNum = 337,
-    VehicleConfiguration = 338,
-    TpmsSensorPosition = 339,
-    TpmsSensorPresureFaultLimits = 340,
-    TpmsSensorTemperatureFaultLimits = 341,
-    TpmsSensorId = 342,
-    SmartArmWindEventSetting = 343,
-    AccRequestMode = 344,
-    AccessorySetting01 = 345,
-    AccessorySetting02 = 346,
-    AccessorySetting03 = 347,
-    AccessorySetting04 = 348,
-    AccessorySetting05 = 349,
-    AccessorySetting06 = 350,
-    AccessorySetting07 = 351,
-    AccessorySetting08 = 352,
-    AccessorySetting09 = 353,
-    AccessorySetting10 = 354,
-    AccessorySetting11 = 355,
-    AccessorySetting12 = 356,
-    AccessorySetting13 = 357,
-    AccessorySetting14 = 358,
-    AccessorySetting15 = 359,
-    AccessorySetting16 = 360,
-    TireTrackWidth = 361,
-    TireDiameter = 362,
-    AbsRimTeethCount = 363,
-    AbsMaintenancePeriod = 364,
-    IlluminationSync = 365,
-    RvCInstance = 366,
-    HvacControlTypeSetting = 367,
-    ActiveHvacControlType = 368,
-    MonitorPanelControlTypeConfigTank = 369,
-    NumberOfAxles = 370,
-    LastMaintenanceOdometer = 371,
-    AccGwNumDevices = 372,
-    AccGwMacHigh = 373,
-    AccGwMacLow = 374,
-    DeviceTypeAtIndex = 375,
-    BrakeModuleOrientation = 376,
-    CoreMicrocontollerReset = 377,
-    ProductFwPartNum = 378,
-    CoreVersionInfo = 379,
-    ProductIdNum = 380,
-    ProductIdInConfigBlock = 381,
-    LocapVersionInfo = 382,
-    ProductFwPartNum1 = 383,
-    ProductFwPartNum2 = 384,
-    HbridgeSafetyAlertConfig = 385,
-    AwningAutoProtectionCount = 386,
-    MomentaryHbridgeCircuitRole = 387,
-    SoundsHighestCapable = 388,
-    SmartArmValanceCorrection = 389,
-    JumpToBoot = 390,
+impl std::fmt::Display for ParameterID {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
 }
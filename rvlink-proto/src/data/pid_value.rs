@@ -0,0 +1,110 @@
+use super::*;
+
+/// A PID value decoded using the metadata for its `ParameterID`
+#[derive(Debug, Clone, PartialEq)]
+pub enum PidValue {
+    Unsigned(u32),
+    Signed(i32),
+    Fixed(f64),
+    Bytes(Vec<u8>),
+}
+
+define_encodable_struct! {
+    DevicePidEntry [3] {
+        pid: u16 [0],
+        flags: u8 [2],
+    }
+}
+
+#[allow(dead_code)]
+impl DevicePidEntry {
+    /// The known PID for this entry, devices may report PIDs we have no definition for
+    pub fn parameter_id(&self) -> Option<ParameterID> {
+        Some(ParameterID::from(self.pid)).filter(|pid| *pid != ParameterID::Unknown)
+    }
+
+    pub fn is_readable(&self) -> bool {
+        (self.flags & 0x01) == 0x01
+    }
+
+    pub fn is_writable(&self) -> bool {
+        (self.flags & 0x02) == 0x02
+    }
+}
+
+impl std::fmt::Display for PidValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PidValue::Unsigned(v) => write!(f, "{}", v),
+            PidValue::Signed(v) => write!(f, "{}", v),
+            PidValue::Fixed(v) => write!(f, "{:.2}", v),
+            PidValue::Bytes(v) => {
+                for b in v.iter() {
+                    write!(f, "{:02x}", b)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ParameterID {
+    fn is_numeric(&self) -> bool {
+        (1..=4).contains(&self.width())
+    }
+
+    /// Decode the raw value of this PID, numbers are big endian and may be sent in fewer bytes than the width
+    pub fn decode(&self, data: &[u8]) -> Result<PidValue> {
+        if !self.is_numeric() {
+            return Ok(PidValue::Bytes(data.to_vec()));
+        }
+        let width = self.width() as usize;
+        // Longer values are fine as long as only leading zeros get dropped
+        let extra = data.len().saturating_sub(width);
+        if data[..extra].iter().any(|b| *b != 0) {
+            return Err(AppError::IncorrectDataSize);
+        }
+        let data = &data[extra..];
+        let mut raw = [0u8; 4];
+        raw[4 - data.len()..].copy_from_slice(data);
+        let unsigned = u32::from_be_bytes(raw);
+        let shift = 32 - (width as u32 * 8);
+        let signed = ((unsigned << shift) as i32) >> shift;
+        let scale = f64::from(1u32 << self.frac_bits());
+        Ok(match (self.signed(), self.frac_bits()) {
+            (false, 0) => PidValue::Unsigned(unsigned),
+            (true, 0) => PidValue::Signed(signed),
+            (false, _) => PidValue::Fixed(f64::from(unsigned) / scale),
+            (true, _) => PidValue::Fixed(f64::from(signed) / scale),
+        })
+    }
+
+    /// Encode a value for this PID at its full width
+    pub fn encode(&self, value: &PidValue) -> Result<Vec<u8>> {
+        let raw: i64 = match (value, self.is_numeric()) {
+            (PidValue::Bytes(v), false) => return Ok(v.clone()),
+            (PidValue::Unsigned(v), true) => i64::from(*v),
+            (PidValue::Signed(v), true) => i64::from(*v),
+            (PidValue::Fixed(v), true) => (v * f64::from(1u32 << self.frac_bits())).round() as i64,
+            _ => {
+                return Err(AppError::Generic(format!(
+                    "{} cannot be written to {}",
+                    value, self
+                )))
+            }
+        };
+        let bits = u32::from(self.width()) * 8;
+        let (min, max) = if self.signed() {
+            (-(1i64 << (bits - 1)), (1i64 << (bits - 1)) - 1)
+        } else {
+            (0, (1i64 << bits) - 1)
+        };
+        if raw < min || raw > max {
+            return Err(AppError::Generic(format!(
+                "{} is out of range for {}",
+                value, self
+            )));
+        }
+        Ok((raw as u32).to_be_bytes()[4 - self.width() as usize..].to_vec())
+    }
+}
@@ -22,11 +22,180 @@ pub enum RelayDirection {
 	Close = 3,
 }
 
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum DimmableLightMode {
+    #[default]
+    #[display(fmt = "off")]
+    Off = 0,
+    #[display(fmt = "on")]
+    On = 1,
+    #[display(fmt = "blink")]
+    Blink = 2,
+    #[display(fmt = "swell")]
+    Swell = 3,
+}
+
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum DimmableLightCommand {
+    #[default]
+    #[display(fmt = "off")]
+    Off = 0,
+    #[display(fmt = "on")]
+    On = 1,
+    #[display(fmt = "blink")]
+    Blink = 2,
+    #[display(fmt = "swell")]
+    Swell = 3,
+    #[display(fmt = "settings")]
+    Settings = 126,
+    #[display(fmt = "restore")]
+    Restore = 127,
+}
+
+#[derive(Default, Debug, Display, PartialEq, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum RgbLightMode {
+    #[default]
+    #[display(fmt = "off")]
+    Off = 0,
+    #[display(fmt = "on")]
+    On = 1,
+    #[display(fmt = "blink")]
+    Blink = 2,
+    #[display(fmt = "jump3")]
+    Jump3 = 4,
+    #[display(fmt = "jump7")]
+    Jump7 = 5,
+    #[display(fmt = "fade3")]
+    Fade3 = 6,
+    #[display(fmt = "fade7")]
+    Fade7 = 7,
+    #[display(fmt = "rainbow")]
+    Rainbow = 8,
+    #[display(fmt = "restore")]
+    Restore = 127,
+}
+
+/// Command payload for ActionRgb, the size depends on the mode being requested
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[allow(dead_code)]
+pub enum RgbLightCommand {
+    #[default]
+    Off,
+    Solid {
+        red: u8,
+        green: u8,
+        blue: u8,
+        duration: u8,
+    },
+    Blink {
+        red: u8,
+        green: u8,
+        blue: u8,
+        duration: u8,
+        on_interval: u8,
+        off_interval: u8,
+    },
+    Transition {
+        mode: RgbLightMode,
+        duration: u8,
+        interval: u16,
+    },
+    Restore,
+}
+
+impl Encodable for RgbLightCommand {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        if data.is_empty() {
+            return Err(AppError::InvalidPayload);
+        }
+        let mode = RgbLightMode::try_from(data[0])?;
+        match (mode, data.len()) {
+            (RgbLightMode::Off, _) => Ok(Self::Off),
+            (RgbLightMode::Restore, _) => Ok(Self::Restore),
+            (RgbLightMode::On, 5..) => Ok(Self::Solid {
+                red: data[1],
+                green: data[2],
+                blue: data[3],
+                duration: data[4],
+            }),
+            (RgbLightMode::Blink, 7..) => Ok(Self::Blink {
+                red: data[1],
+                green: data[2],
+                blue: data[3],
+                duration: data[4],
+                on_interval: data[5],
+                off_interval: data[6],
+            }),
+            (RgbLightMode::On | RgbLightMode::Blink, _) => Err(AppError::InvalidPayload),
+            (mode, 4..) => Ok(Self::Transition {
+                mode,
+                duration: data[1],
+                interval: <u16>::from_data(&data[2..])?,
+            }),
+            _ => Err(AppError::InvalidPayload),
+        }
+    }
+
+    fn to_data(&self) -> Vec<u8> {
+        match *self {
+            Self::Off => vec![RgbLightMode::Off.into()],
+            Self::Solid {
+                red,
+                green,
+                blue,
+                duration,
+            } => vec![RgbLightMode::On.into(), red, green, blue, duration],
+            Self::Blink {
+                red,
+                green,
+                blue,
+                duration,
+                on_interval,
+                off_interval,
+            } => vec![
+                RgbLightMode::Blink.into(),
+                red,
+                green,
+                blue,
+                duration,
+                on_interval,
+                off_interval,
+            ],
+            Self::Transition {
+                mode,
+                duration,
+                interval,
+            } => {
+                let mut res = vec![mode.into(), duration];
+                res.append(&mut interval.to_data());
+                res
+            }
+            Self::Restore => vec![RgbLightMode::Restore.into()],
+        }
+    }
+
+    fn data_size(&self) -> usize {
+        match self {
+            Self::Off | Self::Restore => 1,
+            Self::Solid { .. } => 5,
+            Self::Blink { .. } => 7,
+            Self::Transition { .. } => 4,
+        }
+    }
+}
+
 define_encodable_struct! {
     TankStatus [2] {
         device_id: u8 [0],
         percentage: u8 [1],
     }
+    RelayStateType1 [2] {
+        device_id: u8 [0],
+        status: u8 [1],
+    }
     RelayStateType2 [7] {
         device_id: u8 [0],
         status: u8 [1],
@@ -34,34 +203,123 @@ define_encodable_struct! {
         amp_draw: u16 [3],
         dtc: u16 [5],
     }
+    DimmableLightState [9] {
+        device_id: u8 [0],
+        mode: u8 [1],
+        max_brightness: u8 [2],
+        duration: u8 [3],
+        brightness: u8 [4],
+        cycle_time_1: u16 [5],
+        cycle_time_2: u16 [7],
+    }
+    RgbLightState [8] {
+        device_id: u8 [0],
+        mode: u8 [1],
+        red: u8 [2],
+        green: u8 [3],
+        blue: u8 [4],
+        duration: u8 [5],
+        interval: u16 [6],
+    }
 }
 
+/// Status bits shared by both generations of latching and H-bridge relay status records
 #[allow(dead_code)]
-impl RelayStateType2 {
-    pub fn is_on(&self) -> bool {
-        (self.status & 0x01) == 0x01
+pub trait RelayState {
+    fn device_id(&self) -> u8;
+    fn status(&self) -> u8;
+
+    /// Trouble code reported alongside the relay state, only newer relays report one
+    fn dtc(&self) -> Option<DTCID> {
+        None
+    }
+
+    fn is_on(&self) -> bool {
+        (self.status() & 0x01) == 0x01
     }
 
-    pub fn is_forward_allowed(&self) -> bool {
-        (self.status & 0x80) == 0x80
+    fn is_forward_allowed(&self) -> bool {
+        (self.status() & 0x80) == 0x80
     }
 
-    pub fn is_forward_active(&self) -> bool {
-        ((self.status & 0x02) == 0x02) && !self.is_reverse_active()
+    fn is_forward_active(&self) -> bool {
+        ((self.status() & 0x02) == 0x02) && !self.is_reverse_active()
     }
 
-    pub fn is_reverse_allowed(&self) -> bool {
-        (self.status & 0x40) == 0x40
+    fn is_reverse_allowed(&self) -> bool {
+        (self.status() & 0x40) == 0x40
     }
 
-    pub fn is_reverse_active(&self) -> bool {
-        (self.status & 0x03) == 0x03
+    fn is_reverse_active(&self) -> bool {
+        (self.status() & 0x03) == 0x03
     }
 
-    pub fn is_stopped(&self) -> bool {
+    fn is_stopped(&self) -> bool {
         !self.is_forward_active() && !self.is_reverse_active()
     }
 
+    fn on_off(&self) -> OnOff {
+        if self.is_on() {
+            OnOff::On
+        } else {
+            OnOff::Off
+        }
+    }
+}
+
+impl RelayState for RelayStateType1 {
+    fn device_id(&self) -> u8 {
+        self.device_id
+    }
+
+    fn status(&self) -> u8 {
+        self.status
+    }
+}
+
+impl RelayState for RelayStateType2 {
+    fn device_id(&self) -> u8 {
+        self.device_id
+    }
+
+    fn status(&self) -> u8 {
+        self.status
+    }
+
+    fn dtc(&self) -> Option<DTCID> {
+        Some(DTCID::from(self.dtc)).filter(|dtc| *dtc != DTCID::Unknown)
+    }
+}
+
+#[allow(dead_code)]
+impl DimmableLightState {
+    pub fn mode(&self) -> DimmableLightMode {
+        DimmableLightMode::try_from(self.mode).unwrap_or_default()
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.mode() != DimmableLightMode::Off
+    }
+
+    pub fn on_off(&self) -> OnOff {
+        if self.is_on() {
+            OnOff::On
+        } else {
+            OnOff::Off
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl RgbLightState {
+    pub fn mode(&self) -> RgbLightMode {
+        RgbLightMode::try_from(self.mode).unwrap_or_default()
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.mode() != RgbLightMode::Off
+    }
+
     pub fn on_off(&self) -> OnOff {
         if self.is_on() {
             OnOff::On
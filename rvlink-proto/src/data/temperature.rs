@@ -0,0 +1,25 @@
+use super::*;
+use fixed::{types::extra::U8, FixedI16};
+
+define_encodable_struct! {
+    TemperatureSensorState [4] {
+        device_id: u8 [0],
+        status: u8 [1],
+        temperature: FixedI16<U8> [2],
+    }
+}
+
+#[allow(dead_code)]
+impl TemperatureSensorState {
+    pub fn is_low_alarm(&self) -> bool {
+        (self.status & 0x01) == 0x01
+    }
+
+    pub fn is_high_alarm(&self) -> bool {
+        (self.status & 0x02) == 0x02
+    }
+
+    pub fn temperature(&self) -> Option<FixedI16<U8>> {
+        valid_temperature(self.temperature)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::*;
-use fixed::{types::extra::U8, FixedU16};
+use fixed::{types::extra::U8, FixedI16, FixedU16};
 use num_enum::IntoPrimitive;
 use rvlink_common::error::*;
 
@@ -144,8 +144,9 @@ events! {
         device_count: u8 [7],
         lockout_status: BitFlags [8], // 1 bit per device indicating lockout
     }
-    RelayBasicLatchingStatusType1 (5; 1..100) {
-        // TODO
+    RelayBasicLatchingStatusType1 (5; 4..384) {
+        device_table_id: u8 [1],
+        << relays: RelayStateType1 [2],
     }
     // data: [6, 1, 8, 128, 255, 0, 0, 0, 0], device_table_id: 1, device_index: 8, status: 128, start_position: 255, amp_draw: 0, dtc: 0 })
     RelayBasicLatchingStatusType2 (6; 9..384) {
@@ -154,33 +155,58 @@ events! {
     }
     RvStatus (7; 6..6) {
         battery_voltage: FixedU16<U8> [1],
-        external_temperature: FixedU16<U8> [3],
+        external_temperature: FixedI16<U8> [3],
         feature_index: u8 [5],
     }
-    DimmableLightStatus (8; 1..100) {}
-    RgbLightStatus (9; 1..100) {}
-    GeneratorGenieStatus (10; 1..100) {}
-    HvacStatus (11; 1..100) {}
+    DimmableLightStatus (8; 11..384) {
+        device_table_id: u8 [1],
+        << lights: DimmableLightState [2],
+    }
+    RgbLightStatus (9; 10..384) {
+        device_table_id: u8 [1],
+        << lights: RgbLightState [2],
+    }
+    GeneratorGenieStatus (10; 12..384) {
+        device_table_id: u8 [1],
+        << generators: GeneratorGenieState [2],
+    }
+    HvacStatus (11; 13..384) {
+        device_table_id: u8 [1],
+        << zones: HvacState [2],
+    }
     TankSensorStatus (12; 2..200) {
         device_table_id: u8 [1],
         << tank_statuses: TankStatus [2],
     }
-    RelayHBridgeMomentaryStatusType1 (13; 1..100) {}
+    RelayHBridgeMomentaryStatusType1 (13; 4..384) {
+        device_table_id: u8 [1],
+        << relays: RelayStateType1 [2],
+    }
     RelayHBridgeMomentaryStatusType2 (14; 9..384) {
         device_table_id: u8 [1],
         << relays: RelayStateType2 [2],
     }
-    HourMeterStatus (15; 1..100) {
-        // TODO
+    HourMeterStatus (15; 8..384) {
+        device_table_id: u8 [1],
+        << meters: HourMeterState [2],
+    }
+    Leveler4DeviceStatus (16; 7..384) {
+        device_table_id: u8 [1],
+        << levelers: Leveler4State [2],
     }
-    Leveler4DeviceStatus (16; 1..100) {}
     LevelerConsoleText (17; 1..100) {
         device_table_id: u8 [1],
         device_count: u8 [2],
         console_text: Vec<u8> [3],
     }
-    Leveler1DeviceStatus (18; 1..100) {}
-    Leveler3DeviceStatus (19; 1..100) {}
+    Leveler1DeviceStatus (18; 5..384) {
+        device_table_id: u8 [1],
+        << levelers: Leveler1State [2],
+    }
+    Leveler3DeviceStatus (19; 6..384) {
+        device_table_id: u8 [1],
+        << levelers: Leveler3State [2],
+    }
     DeviceSessionStatus (26; 3..100) {
         device_table_id: u8 [1],
         device_count: u8 [2],
@@ -191,15 +217,42 @@ events! {
         time_since_start: u16 [5],
         flags: u8 [8],
     }
-    CloudGatewayStatus (33; 1..100) {}
-    TemperatureSensorStatus (34; 1..100) {}
-    JaycoTbbStatus (35; 1..100) {}
-    MonitorPanelStatus (43; 1..100) {}
-    AccessoryGatewayStatus (44; 1..100) {}
-    AwningSensorStatus (47; 1..100) {}
-    BrakingSystemStatus (48; 1..100) {}
-    BatteryMonitorStatus (49; 1..100) {}
-    DoorLockStatus (51; 1..100) {}
+    CloudGatewayStatus (33; 4..384) {
+        device_table_id: u8 [1],
+        << gateways: CloudGatewayState [2],
+    }
+    TemperatureSensorStatus (34; 6..384) {
+        device_table_id: u8 [1],
+        << sensors: TemperatureSensorState [2],
+    }
+    JaycoTbbStatus (35; 13..384) {
+        device_table_id: u8 [1],
+        << systems: JaycoTbbState [2],
+    }
+    MonitorPanelStatus (43; 5..384) {
+        device_table_id: u8 [1],
+        << devices: MonitorPanelDeviceState [2],
+    }
+    AccessoryGatewayStatus (44; 5..384) {
+        device_table_id: u8 [1],
+        << devices: AccessoryGatewayDeviceState [2],
+    }
+    AwningSensorStatus (47; 6..384) {
+        device_table_id: u8 [1],
+        << sensors: AwningSensorState [2],
+    }
+    BrakingSystemStatus (48; 6..384) {
+        device_table_id: u8 [1],
+        << brakes: BrakingSystemState [2],
+    }
+    BatteryMonitorStatus (49; 11..384) {
+        device_table_id: u8 [1],
+        << monitors: BatteryMonitorState [2],
+    }
+    DoorLockStatus (51; 4..384) {
+        device_table_id: u8 [1],
+        << locks: DoorLockState [2],
+    }
     HostDebug (102; 1..100) {}
 }
 
@@ -212,9 +265,9 @@ impl RvStatus {
         }
     }
 
-    pub fn external_temperature(&self) -> Option<FixedU16<U8>> {
+    pub fn external_temperature(&self) -> Option<FixedI16<U8>> {
         if (self.feature_index & 0x02) == 0x02 {
-            Some(self.external_temperature)
+            valid_temperature(self.external_temperature)
         } else {
             None
         }
@@ -251,4 +304,448 @@ mod test {
         println!("Event: {:?}", event);
         Ok(())
     }
+
+    #[test]
+    fn parse_device_online_status() -> Result<()> {
+        let payload = vec![3u8, 1, 10, 0b00000101, 0b00000010];
+        match <Event as EventTrait>::from_payload(payload)? {
+            Event::DeviceOnlineStatus(evt) => {
+                let flags = &evt.online_status;
+                assert!(flags.get_flag(0)?);
+                assert!(!flags.get_flag(1)?);
+                assert!(flags.get_flag(2)?);
+                assert!(!flags.get_flag(8)?);
+                assert!(flags.get_flag(9)?);
+                assert_eq!(flags.to_flags()[..3], [true, false, true]);
+                assert!(flags.get_flag(16).is_err());
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_rv_status() -> Result<()> {
+        // 12.5V battery, -4.25F outside
+        let payload = vec![7u8, 12, 128, 0xFB, 0xC0, 0x03];
+        match <Event as EventTrait>::from_payload(payload)? {
+            Event::RvStatus(evt) => {
+                assert_eq!(evt.battery_voltage(), Some(FixedU16::<U8>::from_num(12.5)));
+                assert_eq!(
+                    evt.external_temperature(),
+                    Some(FixedI16::<U8>::from_num(-4.25))
+                );
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_braking_system_status() -> Result<()> {
+        // Trailer connected and braking at 45% with a gain of 6.5, output shorted
+        let payload = vec![48u8, 1, 8, 0x23, 45, 65];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::BrakingSystemStatus(evt) => {
+                let brake = &evt.brakes[0];
+                assert_eq!(brake.device_id, 8);
+                assert!(brake.is_trailer_connected());
+                assert!(brake.is_braking());
+                assert!(brake.is_short_circuit());
+                assert!(!brake.is_open_circuit() && !brake.is_overloaded());
+                assert!(brake.has_fault());
+                assert_eq!(brake.output_level, 45);
+                assert_eq!(brake.gain(), 6.5);
+                assert_eq!(brake.to_data(), payload[2..]);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_battery_monitor_status() -> Result<()> {
+        // 12.75V, discharging at 15.2A, 80% with 6 hours left
+        let payload = vec![49u8, 1, 3, 0, 80, 12, 192, 0xFF, 0x68, 1, 104];
+        match <Event as EventTrait>::from_payload(payload)? {
+            Event::BatteryMonitorStatus(evt) => {
+                let monitor = &evt.monitors[0];
+                assert_eq!(monitor.voltage, FixedU16::<U8>::from_num(12.75));
+                assert_eq!(monitor.current(), -15.2);
+                assert_eq!(monitor.state_of_charge(), Some(80));
+                assert_eq!(monitor.time_remaining(), Some(360));
+                assert!(!monitor.is_charging());
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_cloud_gateway_status() -> Result<()> {
+        // Registered but not connected
+        let payload = vec![33u8, 1, 2, 0x02];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::CloudGatewayStatus(evt) => {
+                let gateway = &evt.gateways[0];
+                assert_eq!(gateway.device_id, 2);
+                assert!(!gateway.is_connected());
+                assert!(gateway.is_registered());
+                assert_eq!(gateway.to_data(), payload[2..]);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_jayco_tbb_status() -> Result<()> {
+        // 13.5V, charging at 4.2A with 6.5A of solar, 92%, 240V mains
+        let payload = vec![35u8, 1, 7, 0x03, 13, 128, 0, 42, 0, 65, 92, 0, 240];
+        match <Event as EventTrait>::from_payload(payload)? {
+            Event::JaycoTbbStatus(evt) => {
+                let tbb = &evt.systems[0];
+                assert_eq!(tbb.device_id, 7);
+                assert_eq!(tbb.battery_voltage, FixedU16::<U8>::from_num(13.5));
+                assert_eq!(tbb.battery_current(), 4.2);
+                assert_eq!(tbb.solar_current(), 6.5);
+                assert_eq!(tbb.state_of_charge(), Some(92));
+                assert_eq!(tbb.mains_voltage(), Some(240));
+                assert!(tbb.is_mains_connected());
+                assert!(tbb.is_solar_charging());
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_dimmable_light_status() -> Result<()> {
+        let payload = vec![
+            8u8, 1, 4, 1, 200, 0, 180, 0, 0, 0, 0, 5, 0, 128, 0, 0, 0, 0, 0, 0,
+        ];
+        match <Event as EventTrait>::from_payload(payload)? {
+            Event::DimmableLightStatus(evt) => {
+                assert_eq!(evt.device_table_id, 1);
+                assert_eq!(evt.lights.len(), 2);
+                assert_eq!(evt.lights[0].device_id, 4);
+                assert_eq!(evt.lights[0].mode(), DimmableLightMode::On);
+                assert_eq!(evt.lights[0].max_brightness, 200);
+                assert_eq!(evt.lights[0].brightness, 180);
+                assert_eq!(evt.lights[1].on_off(), OnOff::Off);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_real_time_clock() -> Result<()> {
+        let payload = vec![32u8, 0x65, 0x53, 0xF1, 0x00, 0x01, 0x2C, 0, 0];
+        match <Event as EventTrait>::from_payload(payload)? {
+            Event::RealTimeClock(evt) => {
+                assert_eq!(evt.seconds_from_epoch, 1_700_000_000);
+                assert_eq!(evt.time_since_start, 300);
+                assert_eq!(evt.flags, 0);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_rgb_light_status() -> Result<()> {
+        // Orange fading between three colors once a second
+        let payload = vec![9u8, 1, 6, 6, 255, 128, 0, 0, 0x03, 0xE8];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::RgbLightStatus(evt) => {
+                let light = &evt.lights[0];
+                assert_eq!(light.device_id, 6);
+                assert_eq!(light.mode(), RgbLightMode::Fade3);
+                assert_eq!((light.red, light.green, light.blue), (255, 128, 0));
+                assert_eq!(light.interval, 1000);
+                assert_eq!(light.on_off(), OnOff::On);
+                assert_eq!(light.to_data(), payload[2..]);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_generator_genie_status() -> Result<()> {
+        // Running at 13.25V and 95.5F after 100 hours
+        let payload = vec![10u8, 1, 3, 0x03, 13, 64, 95, 128, 0x00, 0x05, 0x7E, 0x40];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::GeneratorGenieStatus(evt) => {
+                let generator = &evt.generators[0];
+                assert_eq!(generator.device_id, 3);
+                assert_eq!(generator.state(), GeneratorState::Running);
+                assert!(generator.is_running());
+                assert_eq!(generator.battery_voltage, FixedU16::<U8>::from_num(13.25));
+                assert_eq!(
+                    generator.temperature(),
+                    Some(FixedI16::<U8>::from_num(95.5))
+                );
+                assert_eq!(generator.run_hours(), 100.0);
+                assert_eq!(generator.to_data(), payload[2..]);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_temperature_sensor_status() -> Result<()> {
+        // Freezer at -3.5F with the low alarm set, then a sensor with no reading
+        let payload = vec![34u8, 1, 9, 0x01, 0xFC, 0x80, 10, 0x00, 0x80, 0x00];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::TemperatureSensorStatus(evt) => {
+                let freezer = &evt.sensors[0];
+                assert_eq!(freezer.device_id, 9);
+                assert_eq!(freezer.temperature(), Some(FixedI16::<U8>::from_num(-3.5)));
+                assert!(freezer.is_low_alarm());
+                assert!(!freezer.is_high_alarm());
+                assert_eq!(freezer.to_data(), payload[2..6]);
+                assert_eq!(evt.sensors[1].temperature(), None);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_awning_sensor_status() -> Result<()> {
+        // Wind alarm at 32mph, then a sensor without readings
+        let payload = vec![47u8, 1, 5, 0x01, 32, 10, 6, 0x00, 0xFF, 0xFF];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::AwningSensorStatus(evt) => {
+                let sensor = &evt.sensors[0];
+                assert_eq!(sensor.device_id, 5);
+                assert!(sensor.is_wind_alarm());
+                assert!(!sensor.is_vibration_alarm());
+                assert_eq!(sensor.wind_speed(), Some(32));
+                assert_eq!(sensor.vibration(), Some(10));
+                assert_eq!(sensor.to_data(), payload[2..6]);
+                assert_eq!(evt.sensors[1].wind_speed(), None);
+                assert_eq!(evt.sensors[1].vibration(), None);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_leveler_status() -> Result<()> {
+        // Type 1 level with the jacks up
+        let payload = vec![18u8, 1, 9, 0x01, 0x00];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::Leveler1DeviceStatus(evt) => {
+                let leveler = &evt.levelers[0];
+                assert_eq!(leveler.device_id(), 9);
+                assert_eq!(leveler.mode(), LevelerMode::Idle);
+                assert!(leveler.is_level());
+                assert!(!leveler.jacks_extended());
+                assert_eq!(leveler.to_data(), payload[2..]);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+
+        // Type 3 auto leveling on screen 2 with all jacks down
+        let payload = vec![19u8, 1, 9, 2, 0x02, 0x0F];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::Leveler3DeviceStatus(evt) => {
+                let leveler = &evt.levelers[0];
+                assert_eq!(leveler.mode(), LevelerMode::AutoLevel);
+                assert_eq!(leveler.screen(), 2);
+                assert!(leveler.jacks_extended());
+                assert_eq!(leveler.to_data(), payload[2..]);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+
+        // Type 4 reports its own mode, manual with an excess angle fault
+        let payload = vec![16u8, 1, 9, 3, 1, 0xC0, 0x03];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::Leveler4DeviceStatus(evt) => {
+                let leveler = &evt.levelers[0];
+                assert_eq!(leveler.mode(), LevelerMode::Manual);
+                assert_eq!(leveler.screen(), 1);
+                assert!(leveler.is_excess_angle());
+                assert!(leveler.has_fault());
+                assert_eq!(leveler.to_data(), payload[2..]);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+
+        let mut payload = vec![17u8, 1, 1];
+        payload.extend_from_slice(b"LEVELING\0DONE");
+        match <Event as EventTrait>::from_payload(payload)? {
+            Event::LevelerConsoleText(evt) => {
+                assert_eq!(evt.device_table_id, 1);
+                assert_eq!(evt.console_text, b"LEVELING\0DONE".to_vec());
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_monitor_panel_status() -> Result<()> {
+        // Waste tank at 66% and a latching switch that is on
+        let payload = vec![43u8, 1, 12, 4, 66, 13, 2, 1];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::MonitorPanelStatus(evt) => {
+                let tank = &evt.devices[0];
+                assert_eq!(tank.control_type(), MonitorPanelControlType::WasteTank);
+                assert!(tank.is_tank());
+                assert_eq!(tank.value, 66);
+                let switch = &evt.devices[1];
+                assert_eq!(
+                    switch.control_type(),
+                    MonitorPanelControlType::LatchingSwitch
+                );
+                assert!(!switch.is_tank() && switch.is_on());
+                assert_eq!(switch.to_data(), payload[5..]);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_accessory_gateway_status() -> Result<()> {
+        // A pump that is on without a value, then an offline tank at 40%
+        let payload = vec![44u8, 1, 14, 0x01, 0xFF, 15, 0x80, 40];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::AccessoryGatewayStatus(evt) => {
+                let pump = &evt.devices[0];
+                assert!(pump.is_on() && pump.is_online());
+                assert_eq!(pump.value(), None);
+                let tank = &evt.devices[1];
+                assert!(!tank.is_online());
+                assert_eq!(tank.value(), Some(40));
+                assert_eq!(tank.to_data(), payload[5..]);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_hvac_status() -> Result<()> {
+        // Zone 2: cooling, prefer heat pump, fan low, 65F-74F, indoor 72.5F, no outdoor sensor
+        let payload = vec![11u8, 1, 2, 0x92, 65, 74, 0x02, 72, 128, 128, 0, 0, 0];
+        match <Event as EventTrait>::from_payload(payload)? {
+            Event::HvacStatus(evt) => {
+                let zone = &evt.zones[0];
+                assert_eq!(zone.command.heat_mode, HvacHeatMode::Cooling);
+                assert_eq!(zone.command.heat_source, HvacHeatSource::PreferHeatPump);
+                assert_eq!(zone.command.fan_mode, HvacFanMode::Low);
+                assert_eq!(zone.command.to_data(), vec![0x92]);
+                assert_eq!(zone.zone_status(), HvacZoneStatus::Cooling);
+                assert_eq!(
+                    zone.indoor_temperature(),
+                    Some(FixedI16::<U8>::from_num(72.5))
+                );
+                assert_eq!(zone.outdoor_temperature(), None);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_hour_meter_status() -> Result<()> {
+        // 1234.5 hours, running with maintenance due
+        let payload = vec![15u8, 1, 3, 0x00, 0x43, 0xD0, 0x28, 0x03];
+        match <Event as EventTrait>::from_payload(payload)? {
+            Event::HourMeterStatus(evt) => {
+                let meter = &evt.meters[0];
+                assert_eq!(meter.device_id, 3);
+                assert_eq!(meter.operating_seconds, 4_444_200);
+                assert_eq!(meter.operating_hours(), 1234.5);
+                assert!(meter.is_running());
+                assert!(meter.is_maintenance_due());
+                assert!(!meter.is_maintenance_past_due());
+                assert!(!meter.has_error());
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_relay_type1_status() -> Result<()> {
+        // Relay 3 on and relay 4 off
+        let payload = vec![5u8, 1, 3, 0x01, 4, 0x00];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::RelayBasicLatchingStatusType1(evt) => {
+                assert_eq!(evt.relays.len(), 2);
+                assert_eq!(evt.relays[0].device_id(), 3);
+                assert_eq!(evt.relays[0].on_off(), OnOff::On);
+                assert_eq!(evt.relays[1].on_off(), OnOff::Off);
+                assert_eq!(evt.relays[0].dtc(), None);
+                assert_eq!(evt.relays[1].to_data(), payload[4..]);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+
+        // Slide 7 extending, either direction allowed
+        let payload = vec![13u8, 1, 7, 0xC2];
+        match <Event as EventTrait>::from_payload(payload.clone())? {
+            Event::RelayHBridgeMomentaryStatusType1(evt) => {
+                let relay = &evt.relays[0];
+                assert!(relay.is_forward_active());
+                assert!(!relay.is_reverse_active());
+                assert!(relay.is_forward_allowed() && relay.is_reverse_allowed());
+                assert_eq!(relay.to_data(), payload[2..]);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_device_lock_status() -> Result<()> {
+        // Lockout level 2 with the park brake set and ignition on, towable at 12.5V and 10V
+        let payload = vec![4u8, 2, 0x06, 0, 200, 160, 1, 10, 0b00000101, 0];
+        match <Event as EventTrait>::from_payload(payload)? {
+            Event::DeviceLockStatus(evt) => {
+                assert_eq!(evt.system_lockout_level, 2);
+                assert!(evt.get_park_brake_engaged());
+                assert!(evt.get_ignition_on());
+                assert_eq!(evt.get_battery_voltage(), 12.5);
+                assert_eq!(evt.get_brake_voltage(), 10.0);
+                assert_eq!(evt.device_table_id, 1);
+                assert!(evt.lockout_status.get_flag(0)?);
+                assert!(!evt.lockout_status.get_flag(1)?);
+                assert!(evt.lockout_status.get_flag(2)?);
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_door_lock_status() -> Result<()> {
+        let payload = vec![51u8, 1, 5, 0x00, 6, 0x01, 7, 0x02, 8, 0x03];
+        match <Event as EventTrait>::from_payload(payload)? {
+            Event::DoorLockStatus(evt) => {
+                let states: Vec<LockState> = evt.locks.iter().map(|l| l.state()).collect();
+                assert_eq!(evt.locks[0].device_id, 5);
+                assert_eq!(
+                    states,
+                    vec![
+                        LockState::Unlocked,
+                        LockState::Locked,
+                        LockState::Jammed,
+                        LockState::Jammed
+                    ]
+                );
+            }
+            other => panic!("Unexpected event: {:?}", other),
+        }
+        Ok(())
+    }
 }
@@ -9,7 +9,7 @@ pub mod events;
 pub use commands::*;
 pub use data::*;
 pub use events::*;
-use fixed::{types::extra::U8, FixedU16};
+use fixed::{types::extra::U8, FixedI16, FixedU16};
 use rvlink_common::error::*;
 
 pub trait Encodable: Sized {
@@ -92,7 +92,7 @@ impl Encodable for Vec<u8> {
 encodable_primitive! {
     u8:1, u16:2, u32:4, u64:8,
     i8:1, i16:2, i32:4, i64:8,
-    FixedU16<U8>:2,
+    FixedU16<U8>:2, FixedI16<U8>:2,
 }
 
 encodable_enum! {
@@ -104,4 +104,6 @@ encodable_enum! {
     FunctionName as u16,
     CommandType as u8,
     RelayDirection as u8,
+    DimmableLightCommand as u8,
+    GeneratorGenieCommand as u8,
 }